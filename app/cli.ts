@@ -303,7 +303,7 @@ program
       for (let i = 0; i < config.contacts.length; i++) {
         const contact = config.contacts[i];
         const alert = await client.getAlertAccount(owner, contact);
-        const status = alert
+        const status = alert?.isActive
           ? alert.hasApproved
             ? "APPROVED"
             : "ALERTED"
@@ -33,6 +33,8 @@ import { Program, AnchorProvider } from "@coral-xyz/anchor";
 import {
   PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
   Keypair,
   Connection,
 } from "@solana/web3.js";
@@ -56,8 +58,31 @@ export const SEEDS = {
   ALERT: "alert",
   ATTACKER: "attacker",
   COMPROMISED: "compromised",
+  FEE_RESERVE: "fee_reserve",
+  PROTOCOL_STATS: "protocol_stats",
+  PROTOCOL_CONFIG: "protocol_config",
+  TREASURY: "treasury",
+  ATTACKER_LINK: "attacker_link",
+  COMPRESSED_REGISTRY: "compressed_registry",
+  PROGRAM_INFO: "program_info",
+  DEPOSIT_LEDGER: "deposit_ledger",
 } as const;
 
+/** Which of a wallet's configs: its index, or the name it was created with */
+export type VaultId = number | string;
+
+/**
+ * The seed a config's index or name adds to its owner's PDAs. Index 0 adds
+ * none, so a wallet's first config keeps its original addresses; a name
+ * adds its SHA-256 hash.
+ */
+export function vaultSeed(vault: VaultId): Buffer {
+  if (typeof vault === "string") {
+    return crypto.createHash("sha256").update("scream:vault-name:").update(vault).digest();
+  }
+  return vault === 0 ? Buffer.alloc(0) : Buffer.from([vault]);
+}
+
 // ────────────────────────────────────────────────────────────
 // Types — on-chain account structures
 // ────────────────────────────────────────────────────────────
@@ -68,6 +93,10 @@ export interface PanicConfig {
   owner: PublicKey;
   /** SHA-256 hash of the duress trigger PIN */
   triggerHash: number[];
+  /** Bumped on each accepted trigger proof; signed trigger messages include it */
+  triggerNonce: anchor.BN;
+  /** Signature counter of the last accepted passkey trigger */
+  passkeySignCount: number;
   /** Emergency contact wallet addresses (max 5) */
   contacts: PublicKey[];
   /** Number of contacts required to approve recovery (M-of-N) */
@@ -76,12 +105,85 @@ export interface PanicConfig {
   timeLockDuration: anchor.BN;
   /** Small decoy amount in lamports sent to attacker to fake compliance */
   decoyLamports: anchor.BN;
+  /** Fixed decoy, or a clamped percentage of the pre-panic wallet balance */
+  decoyMode: DecoyMode;
+  /** Random +/- spread applied to the decoy, in bps (0 = exact) */
+  decoyJitterBps: number;
+  /** Installments the decoy is dripped out in (0 or 1 = all at once) */
+  decoyDripInstallments: number;
+  /** Seconds between decoy installments */
+  decoyDripInterval: anchor.BN;
+  /** Mint of a token decoy sent from the vault (default key = none) */
+  decoyTokenMint: PublicKey;
+  /** Token decoy amount in base units */
+  decoyTokenAmount: anchor.BN;
+  /** Memo attached to the decoy transaction (empty = none) */
+  decoyMemo: string;
+  /** Lamports left in the wallet when panic sweeps it */
+  balanceBuffer: anchor.BN;
+  /** Alert URI template, `{contact}` filled in per contact (empty = none) */
+  alertUriTemplate: string;
+  /** Contacts needed to veto a claim */
+  vetoQuorum: number;
+  /** Seconds a veto pauses claims for */
+  vetoDuration: anchor.BN;
+  /** Lamports claimable before the second lock ends (0 = claims aren't tiered) */
+  earlyClaimLimit: anchor.BN;
+  /** Seconds after the time-lock before the rest of the vault is claimable */
+  secondLockDuration: anchor.BN;
+  /** Lamports from which a single claim waits for the large claim lock (0 = none) */
+  largeClaimThreshold: anchor.BN;
+  /** Seconds after the time-lock before a large claim can go out */
+  largeClaimLockDuration: anchor.BN;
+  /** Mints locked for their own duration instead of `timeLockDuration` */
+  tokenTimeLocks: TokenTimeLock[];
+  /** Approvals that release the hot tranche once the time-lock ends (0 = no tranches) */
+  hotThreshold: number;
+  /** Seconds after the time-lock before the cold tranche is claimable */
+  coldLockDuration: anchor.BN;
+  /** Seconds a recovery round has to reach the threshold (0 = never expires) */
+  recoveryWindow: anchor.BN;
+  /** Lamports paid from the fee reserve to whoever runs a crank (0 = none) */
+  keeperTip: anchor.BN;
+  /** Monitoring key that may trigger panic (default pubkey = none) */
+  watcher: PublicKey;
+  /** Oracle that must co-sign a watcher's panic past a soft lock (default pubkey = none) */
+  attestor: PublicKey;
+  /** Merkle tree holding compressed alerts (default pubkey = alerts are PDAs) */
+  alertTree: PublicKey;
+  /** Cold key that recovers instead of the owner (default key = the owner) */
+  recoveryAuthority: PublicKey;
+  /** Key proposed to take over the config and vault (default key = none) */
+  pendingOwner: PublicKey;
+  /** Further keys sharing the vault; any of them can trigger panic */
+  coOwners: PublicKey[];
+  /** Owners who must sign off before a claim (0 = none) */
+  ownerClaimQuorum: number;
+  /** The owner's other wallets, swept and flagged alongside it at panic */
+  linkedWallets: PublicKey[];
+  /** Token accounts delegated to the vault, pulled in at panic */
+  delegatedTokenAccounts: PublicKey[];
+  /** Owner's name for this config, e.g. "trading wallet" (empty = none) */
+  label: string;
+  /** Which of the owner's configs this is (0 = the first) */
+  vaultIndex: number;
+  /** Name the config is seeded by instead of its index (empty = none) */
+  vaultName: string;
+  /** SHA-256 seed of `vaultName` (zeroes = unnamed) */
+  vaultNameHash: number[];
   /** Whether the panic cascade has been triggered */
   isTriggered: boolean;
+  /** Account layout version (older ones need `migrateAccount`) */
+  version: number;
   /** PDA bump seed */
   bump: number;
 }
 
+/** How the decoy amount is worked out at panic time */
+export type DecoyMode =
+  | { fixed: {} }
+  | { percentage: { bps: number; minLamports: anchor.BN; maxLamports: anchor.BN } };
+
 /** Time-locked vault holding protected funds (on-chain) */
 export interface Vault {
   /** Wallet owner */
@@ -92,20 +194,110 @@ export interface Vault {
   recoveryInitiated: boolean;
   /** Number of contact approvals received */
   approvals: number;
+  /** Bumped each time a recovery starts; approvals count toward one round */
+  recoveryRound: number;
+  /** Unix timestamp the current recovery round started */
+  recoveryInitiatedAt: anchor.BN;
+  /** Rules for routine (non-recovery) withdrawals */
+  withdrawPolicy: WithdrawPolicy;
+  /** Looser policy waiting out the current withdraw delay */
+  pendingPolicy: WithdrawPolicy;
+  /** When `pendingPolicy` takes over (0 if nothing is queued) */
+  pendingPolicyAt: anchor.BN;
+  /** Start of the current `vaultTransfer` spending period */
+  spendPeriodStart: anchor.BN;
+  /** Lamports sent by `vaultTransfer` in the current period */
+  spentInPeriod: anchor.BN;
+  /** Withdrawals counted against the daily cap as of `windowUpdatedAt` */
+  withdrawnInWindow: anchor.BN;
+  windowUpdatedAt: anchor.BN;
+  /** Attacker still owed the rest of a dripped decoy */
+  dripRecipient: PublicKey;
+  /** Decoy lamports still to be dripped */
+  dripRemaining: anchor.BN;
+  /** Installments left in the drip */
+  dripInstallmentsLeft: number;
+  /** Earliest time the next installment can be released */
+  dripNextAt: anchor.BN;
+  /** Bit `i` is set once `contacts[i]` has vetoed the current recovery */
+  vetoVotes: number;
+  /** Unix timestamp until which claims are paused by a veto (0 = not paused) */
+  claimPausedUntil: anchor.BN;
+  /** Bit `i` is set once `contacts[i]` has voted for the pending extension */
+  extensionVotes: number;
+  /** Total seconds guardians have added to the time-lock */
+  lockExtendedBy: anchor.BN;
+  /** Bit `i` is set once `contacts[i]` has voted to fast-track recovery */
+  fastTrackVotes: number;
+  /** Lamports claimed so far, counted against the early claim limit */
+  earlyClaimed: anchor.BN;
+  /** Lamports in the hot tranche; the rest of the vault is cold */
+  hotLamports: anchor.BN;
+  /** Unix timestamp of the last claim (0 = none yet) */
+  lastClaimAt: anchor.BN;
+  /** Bit `i` is set once owner `i` (0 = the owner, then co-owners) signed off on claims */
+  ownerClaimVotes: number;
+  /** Stake pool holding vault SOL (default pubkey = nothing staked) */
+  stakePool: PublicKey;
+  /** Lamports staked into `stakePool`, at cost */
+  stakedLamports: anchor.BN;
+  /** Pool tokens held in the vault's associated token account */
+  stakedPoolTokens: anchor.BN;
+  /** Lending reserve supplied with vault SOL (default pubkey = nothing lent) */
+  lendingReserve: PublicKey;
+  /** Lamports supplied to `lendingReserve`, at cost */
+  lentLamports: anchor.BN;
+  /** Reserve collateral tokens held in the vault's associated token account */
+  lendingCollateral: anchor.BN;
+  /** Whether the owner keeps a deposit ledger, which vault movements must pass */
+  hasDepositLedger: boolean;
+  /** Index of the config this vault belongs to */
+  vaultIndex: number;
+  /** Name hash of the config this vault belongs to (zeroes = unnamed) */
+  vaultNameHash: number[];
+  /** Account layout version (older ones need `migrateAccount`) */
+  version: number;
   /** PDA bump seed */
   bump: number;
 }
 
+export interface WithdrawPolicy {
+  /** Seconds between `requestWithdraw` and `executeWithdraw` */
+  withdrawDelay: anchor.BN;
+  /** Most `vaultTransfer` may send per period (0 disables it) */
+  spendLimit: anchor.BN;
+  /** Length of a spending period in seconds */
+  spendPeriod: anchor.BN;
+  /** Only send to destinations on the owner's withdraw allowlist */
+  requireAllowlist: boolean;
+  /** Most routine withdrawals may move in any rolling day (u64::MAX for no cap) */
+  dailyWithdrawLimit: anchor.BN;
+}
+
 /** Per-contact alert and approval tracking (on-chain) */
 export interface AlertAccount {
   /** Wallet owner who triggered panic */
   owner: PublicKey;
   /** Emergency contact address */
   contact: PublicKey;
-  /** Unix timestamp when alert was created */
+  /** Unix timestamp when the alert fired (0 while pre-created and inactive) */
   alertedAt: anchor.BN;
+  /** Whether the alert has fired; alerts pre-created at initialize start inactive */
+  isActive: boolean;
+  /** Fired by a rehearsal; can be acknowledged but not approved */
+  isRehearsal: boolean;
   /** Whether this contact has approved recovery */
   hasApproved: boolean;
+  /** Vault recovery round the approval was given in */
+  approvedRound: number;
+  /** Unix timestamp when the contact acknowledged the alert (0 = not yet) */
+  acknowledgedAt: anchor.BN;
+  /** Context for the contact, encrypted to them off-chain (empty = none) */
+  message: number[];
+  /** Off-chain encrypted instructions URI (empty = none) */
+  uri: string;
+  /** Account layout version (older ones need `migrateAlert`) */
+  version: number;
   /** PDA bump seed */
   bump: number;
 }
@@ -118,6 +310,59 @@ export interface AttackerFlag {
   reportedBy: PublicKey;
   /** Unix timestamp when flagged */
   flaggedAt: anchor.BN;
+  /** Hash of supporting evidence (all zeroes when none was given) */
+  evidenceHash: number[];
+  /** Unix timestamp after which the flag can be garbage-collected (0 = never) */
+  expiresAt: anchor.BN;
+  /** Panics by other owners that later flagged the same address */
+  corroborations: number;
+  /** Whether the reporter has collected the report bounty */
+  bountyPaid: boolean;
+  /** Community reports filed against the address */
+  reporters: number;
+  /** Bonds locked behind those reports */
+  backingLamports: anchor.BN;
+  /** Disputes the arbiter ruled against the address */
+  disputesUpheld: number;
+  /** Reputation from 0 to 100: "one angry user" scores low, a confirmed serial drainer 100 */
+  score: number;
+  /** PDA bump seed */
+  bump: number;
+}
+
+/** How two linked attacker addresses are related */
+export type LinkKind =
+  | { funding: {} }
+  | { sharedContract: {} }
+  | { other: {} };
+
+/** Undirected edge between two attacker addresses (on-chain) */
+export interface AttackerLink {
+  /** The address that sorts first */
+  addressA: PublicKey;
+  /** The address that sorts second */
+  addressB: PublicKey;
+  kind: LinkKind;
+  /** Who asserted the link */
+  reportedBy: PublicKey;
+  /** Hash of the evidence backing the link */
+  evidenceHash: number[];
+  /** Unix timestamp when linked */
+  linkedAt: anchor.BN;
+  /** PDA bump seed */
+  bump: number;
+}
+
+/** Which deployment this is, as published by the admin (on-chain) */
+export interface ProgramInfo {
+  /** Release the deployed binary was built from */
+  version: string;
+  /** Hash of the audit report covering this release (zeroed = unaudited) */
+  auditHash: number[];
+  /** Where to report vulnerabilities */
+  contact: string;
+  /** Unix timestamp of the last update */
+  updatedAt: anchor.BN;
   /** PDA bump seed */
   bump: number;
 }
@@ -130,8 +375,27 @@ export interface CompromisedFlag {
   flaggedAt: anchor.BN;
   /** PDA bump seed */
   bump: number;
+  /** Why the wallet was flagged, as reported at trigger time */
+  reason: CompromiseReason;
+  /** Reported severity from 1 (low) to 10 (critical), if given */
+  severity: number | null;
+}
+
+/** A mint's own time-lock, in seconds after panic */
+export interface TokenTimeLock {
+  mint: PublicKey;
+  duration: anchor.BN;
 }
 
+/** Why a wallet was flagged compromised */
+export type CompromiseReason =
+  | { physicalCoercion: {} }
+  | { phishing: {} }
+  | { malwareSigner: {} }
+  | { unknown: {} }
+  /** Only given by a rehearsal; a real panic rejects it */
+  | { rehearsal: {} };
+
 /** Parameters for initializing panic protection */
 export interface InitializeConfigParams {
   /** Duress PIN string (hashed before sending on-chain — never stored in plaintext) */
@@ -144,6 +408,14 @@ export interface InitializeConfigParams {
   timeLockDuration: number;
   /** Decoy amount in lamports sent to attacker (e.g. 50_000_000 = 0.05 SOL) */
   decoyLamports: number;
+  /** Memo attached to the decoy transaction, up to 64 bytes (default: none) */
+  decoyMemo?: string;
+  /** Name for this config, up to 32 bytes, e.g. "main hot wallet" (default: none) */
+  label?: string;
+  /** Which of the wallet's configs to create (default: 0, the first) */
+  vaultIndex?: number;
+  /** Seed the config by this name, up to 32 bytes, instead of an index (default: none) */
+  vaultName?: string;
 }
 
 /** Full protection status snapshot for a wallet */
@@ -242,28 +514,33 @@ export class ScreamClient {
   // PDA derivation
   // ──────────────────────────────────────────────────────────
 
-  /** Derive the PanicConfig PDA for a wallet owner */
-  getPanicConfigPda(owner: PublicKey): PublicKey {
+  /** Derive the PanicConfig PDA for one of a wallet owner's configs */
+  getPanicConfigPda(owner: PublicKey, vault: VaultId = 0): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from(SEEDS.PANIC_CONFIG), owner.toBuffer()],
+      [Buffer.from(SEEDS.PANIC_CONFIG), owner.toBuffer(), vaultSeed(vault)],
       this.program.programId
     );
     return pda;
   }
 
-  /** Derive the Vault PDA for a wallet owner */
-  getVaultPda(owner: PublicKey): PublicKey {
+  /** Derive the Vault PDA for one of a wallet owner's configs */
+  getVaultPda(owner: PublicKey, vault: VaultId = 0): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from(SEEDS.VAULT), owner.toBuffer()],
+      [Buffer.from(SEEDS.VAULT), owner.toBuffer(), vaultSeed(vault)],
       this.program.programId
     );
     return pda;
   }
 
   /** Derive the AlertAccount PDA for an owner + contact pair */
-  getAlertPda(owner: PublicKey, contact: PublicKey): PublicKey {
+  getAlertPda(owner: PublicKey, contact: PublicKey, vault: VaultId = 0): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from(SEEDS.ALERT), owner.toBuffer(), contact.toBuffer()],
+      [
+        Buffer.from(SEEDS.ALERT),
+        owner.toBuffer(),
+        contact.toBuffer(),
+        vaultSeed(vault),
+      ],
       this.program.programId
     );
     return pda;
@@ -278,6 +555,34 @@ export class ScreamClient {
     return pda;
   }
 
+  /** Derive the link PDA between two attacker addresses, in either order */
+  getAttackerLinkPda(a: PublicKey, b: PublicKey): PublicKey {
+    const [lo, hi] = a.toBuffer().compare(b.toBuffer()) < 0 ? [a, b] : [b, a];
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.ATTACKER_LINK), lo.toBuffer(), hi.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** Derive the compressed attacker registry PDA, the tree's authority */
+  getCompressedRegistryPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.COMPRESSED_REGISTRY)],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** Derive the ProgramInfo PDA describing this deployment */
+  getProgramInfoPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.PROGRAM_INFO)],
+      this.program.programId
+    );
+    return pda;
+  }
+
   /** Derive the CompromisedFlag PDA for a wallet owner */
   getCompromisedFlagPda(owner: PublicKey): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
@@ -287,6 +592,57 @@ export class ScreamClient {
     return pda;
   }
 
+  /** Derive the fee reserve PDA that pre-pays panic-time rent */
+  getFeeReservePda(owner: PublicKey, vault: VaultId = 0): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.FEE_RESERVE), owner.toBuffer(), vaultSeed(vault)],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** Derive the deposit ledger PDA of one of a wallet owner's configs */
+  getDepositLedgerPda(owner: PublicKey, vault: VaultId = 0): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.DEPOSIT_LEDGER), owner.toBuffer(), vaultSeed(vault)],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** The deposit ledger to pass for `owner`'s vault, or null when it keeps none */
+  async depositLedgerFor(owner: PublicKey): Promise<PublicKey | null> {
+    const vault = await this.getVault(owner);
+    return vault?.hasDepositLedger ? this.getDepositLedgerPda(owner) : null;
+  }
+
+  /** Derive the singleton protocol config PDA */
+  getProtocolConfigPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.PROTOCOL_CONFIG)],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** Derive the protocol fee treasury PDA */
+  getTreasuryPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.TREASURY)],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /** Derive the singleton protocol stats PDA */
+  getProtocolStatsPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.PROTOCOL_STATS)],
+      this.program.programId
+    );
+    return pda;
+  }
+
   // ──────────────────────────────────────────────────────────
   // Helpers
   // ──────────────────────────────────────────────────────────
@@ -314,19 +670,29 @@ export class ScreamClient {
    */
   async initializeConfig(params: InitializeConfigParams): Promise<string> {
     const owner = this.provider.wallet.publicKey;
+    const vaultIndex = params.vaultIndex ?? 0;
+    const vaultName = params.vaultName ?? "";
+    const vault: VaultId = vaultName === "" ? vaultIndex : vaultName;
 
     return await this.program.methods
       .initializeConfig(
+        vaultIndex,
+        vaultName,
         this.hashPin(params.pin),
         params.contacts,
         params.recoveryThreshold,
         new anchor.BN(params.timeLockDuration),
-        new anchor.BN(params.decoyLamports)
+        new anchor.BN(params.decoyLamports),
+        params.decoyMemo ?? "",
+        params.label ?? ""
       )
       .accounts({
         owner,
-        panicConfig: this.getPanicConfigPda(owner),
-        vault: this.getVaultPda(owner),
+        panicConfig: this.getPanicConfigPda(owner, vault),
+        vault: this.getVaultPda(owner, vault),
+        feeReserve: this.getFeeReservePda(owner, vault),
+        protocolConfig: this.getProtocolConfigPda(),
+        protocolStats: this.getProtocolStatsPda(),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -350,6 +716,8 @@ export class ScreamClient {
         owner,
         panicConfig: this.getPanicConfigPda(owner),
         vault: this.getVaultPda(owner),
+        protocolConfig: this.getProtocolConfigPda(),
+        depositLedger: await this.depositLedgerFor(owner),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -372,12 +740,22 @@ export class ScreamClient {
    * @param pin - The duress PIN (plaintext — hashed on-chain)
    * @param attackerAddress - Address to receive decoy and be flagged
    * @param contacts - Emergency contacts (must match config)
+   * @param simulate - Run all checks and emit events, then abort without moving funds
+   * @param extraAttackers - Further drainer addresses to flag; the decoy is split across all attackers
+   * @param evidenceHash - 32-byte hash of supporting evidence recorded on new attacker flags
+   * @param reason - Why the wallet is compromised, recorded on its compromised flag
+   * @param severity - How bad it is, from 1 (low) to 10 (critical)
    * @returns Transaction signature
    */
   async triggerPanic(
     pin: string,
     attackerAddress: PublicKey,
-    contacts: PublicKey[]
+    contacts: PublicKey[],
+    simulate = false,
+    extraAttackers: PublicKey[] = [],
+    evidenceHash: number[] = Array(32).fill(0),
+    reason: CompromiseReason = { unknown: {} },
+    severity: number | null = null
   ): Promise<string> {
     const owner = this.provider.wallet.publicKey;
 
@@ -386,19 +764,43 @@ export class ScreamClient {
       isWritable: true,
       isSigner: false,
     }));
+    const attackerAccounts = extraAttackers.flatMap((attacker) => [
+      { pubkey: attacker, isWritable: true, isSigner: false },
+      { pubkey: this.getAttackerFlagPda(attacker), isWritable: true, isSigner: false },
+    ]);
 
     return await this.program.methods
-      .triggerPanic(Buffer.from(pin))
+      .triggerPanic(
+        Buffer.from(pin),
+        simulate,
+        extraAttackers,
+        evidenceHash,
+        reason,
+        severity
+      )
       .accounts({
+        authority: owner,
         owner,
+        payer: owner,
         panicConfig: this.getPanicConfigPda(owner),
         vault: this.getVaultPda(owner),
         compromisedFlag: this.getCompromisedFlagPda(owner),
         attacker: attackerAddress,
         attackerFlag: this.getAttackerFlagPda(attackerAddress),
+        feeReserve: this.getFeeReservePda(owner),
+        protocolStats: this.getProtocolStatsPda(),
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        decoyTokenMint: null,
+        vaultDecoyToken: null,
+        attackerDecoyToken: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+        memoProgram: null,
+        panicHooks: null,
         systemProgram: SystemProgram.programId,
       })
-      .remainingAccounts(alertAccounts)
+      .remainingAccounts([...alertAccounts, ...attackerAccounts])
       .rpc();
   }
 
@@ -409,14 +811,17 @@ export class ScreamClient {
    * time-lock has expired. Sets recovery_initiated = true and
    * resets the approval counter to 0.
    *
+   * Signed by this wallet, which must be the owner or the config's
+   * recovery authority.
+   *
+   * @param owner - Wallet being recovered; defaults to this wallet
    * @returns Transaction signature
    */
-  async initiateRecovery(): Promise<string> {
-    const owner = this.provider.wallet.publicKey;
-
+  async initiateRecovery(owner: PublicKey = this.provider.wallet.publicKey): Promise<string> {
     return await this.program.methods
       .initiateRecovery()
       .accounts({
+        authority: this.provider.wallet.publicKey,
         owner,
         panicConfig: this.getPanicConfigPda(owner),
         vault: this.getVaultPda(owner),
@@ -458,19 +863,30 @@ export class ScreamClient {
    * Requires: panic triggered, recovery initiated, time-lock
    * expired, and approvals >= recovery_threshold.
    *
-   * Transfers all vault SOL (minus rent) back to the owner.
+   * Transfers all vault SOL (minus rent), or just `amount`, to this wallet,
+   * which must be the owner or the config's recovery authority.
    *
+   * @param simulate - Run all checks and emit events, then abort without moving funds
+   * @param amount - Lamports to claim; everything available when omitted
+   * @param owner - Wallet being recovered; defaults to this wallet
    * @returns Transaction signature
    */
-  async claimFromVault(): Promise<string> {
-    const owner = this.provider.wallet.publicKey;
-
+  async claimFromVault(
+    simulate = false,
+    amount?: anchor.BN,
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): Promise<string> {
     return await this.program.methods
-      .claimFromVault()
+      .claimFromVault(simulate, amount ?? null)
       .accounts({
+        authority: this.provider.wallet.publicKey,
         owner,
         panicConfig: this.getPanicConfigPda(owner),
         vault: this.getVaultPda(owner),
+        protocolConfig: this.getProtocolConfigPda(),
+        treasury: this.getTreasuryPda(),
+        protocolStats: this.getProtocolStatsPda(),
+        depositLedger: await this.depositLedgerFor(owner),
       })
       .rpc();
   }
@@ -584,7 +1000,7 @@ export class ScreamClient {
       config.contacts.map(async (address: PublicKey) => {
         const alert = await this.getAlertAccount(owner, address);
         let status: "standby" | "alerted" | "approved" = "standby";
-        if (alert) {
+        if (alert?.isActive) {
           status = alert.hasApproved ? "approved" : "alerted";
         }
         return { address, status };
@@ -0,0 +1,187 @@
+//! Protocol-wide setup and the admin's parameters.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use crate::build;
+
+/// The program's ProgramData account, which names its upgrade authority.
+fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[crate::ID.as_ref()], &solana_sdk_ids::bpf_loader_upgradeable::ID)
+        .0
+}
+
+/// Signed by the program's upgrade authority.
+pub fn initialize_protocol_config(
+    authority: Pubkey,
+    admin: Pubkey,
+    max_contacts: u8,
+    min_time_lock: i64,
+    max_time_lock: i64,
+    fee_bps: u16,
+) -> Instruction {
+    build(
+        accounts::InitializeProtocolConfig {
+            authority,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            program: crate::ID,
+            program_data: program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeProtocolConfig {
+            admin,
+            max_contacts,
+            min_time_lock,
+            max_time_lock,
+            fee_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn initialize_protocol_stats(payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeProtocolStats {
+            payer,
+            protocol_stats: find_protocol_stats().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeProtocolStats {},
+        vec![],
+    )
+}
+
+/// Signed by the program's upgrade authority.
+pub fn initialize_dispute_config(
+    authority: Pubkey,
+    arbiter: Pubkey,
+    bond_lamports: u64,
+    dispute_window: i64,
+) -> Instruction {
+    build(
+        accounts::InitializeDisputeConfig {
+            authority,
+            dispute_config: find_dispute_config().0,
+            program: crate::ID,
+            program_data: program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeDisputeConfig {
+            arbiter,
+            bond_lamports,
+            dispute_window,
+        },
+        vec![],
+    )
+}
+
+pub fn set_protocol_config(
+    admin: Pubkey,
+    new_admin: Pubkey,
+    max_contacts: u8,
+    min_time_lock: i64,
+    max_time_lock: i64,
+    fee_bps: u16,
+) -> Instruction {
+    build(
+        accounts::SetProtocolConfig {
+            admin,
+            protocol_config: find_protocol_config().0,
+        },
+        instruction::SetProtocolConfig {
+            new_admin,
+            max_contacts,
+            min_time_lock,
+            max_time_lock,
+            fee_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn set_paused(admin: Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetPaused {
+            admin,
+            protocol_config: find_protocol_config().0,
+        },
+        instruction::SetPaused { paused },
+        vec![],
+    )
+}
+
+pub fn set_report_bounty(admin: Pubkey, report_bounty: u64) -> Instruction {
+    build(
+        accounts::SetReportBounty {
+            admin,
+            protocol_config: find_protocol_config().0,
+        },
+        instruction::SetReportBounty { report_bounty },
+        vec![],
+    )
+}
+
+pub fn withdraw_treasury(admin: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            admin,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            destination,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawTreasury { amount },
+        vec![],
+    )
+}
+
+/// `merkle_tree` must already be allocated to the compression program,
+/// zeroed, earlier in the transaction.
+pub fn init_compressed_registry(
+    admin: Pubkey,
+    merkle_tree: Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    build(
+        accounts::InitCompressedRegistry {
+            admin,
+            protocol_config: find_protocol_config().0,
+            compressed_registry: find_compressed_registry().0,
+            merkle_tree,
+            noop: scream::NOOP_PROGRAM_ID,
+            compression_program: scream::ACCOUNT_COMPRESSION_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitCompressedRegistry {
+            max_depth,
+            max_buffer_size,
+        },
+        vec![],
+    )
+}
+
+pub fn set_program_info(
+    admin: Pubkey,
+    version: String,
+    audit_hash: [u8; 32],
+    contact: String,
+) -> Instruction {
+    build(
+        accounts::SetProgramInfo {
+            admin,
+            protocol_config: find_protocol_config().0,
+            program_info: find_program_info().0,
+            system_program: system_program::ID,
+        },
+        instruction::SetProgramInfo {
+            version,
+            audit_hash,
+            contact,
+        },
+        vec![],
+    )
+}
@@ -0,0 +1,582 @@
+//! The owner's protection settings: creating the config and everything the
+//! owner can change before panic fires.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use scream_interface::state::{DecoyMode, TriggerMode, VaultId};
+use crate::{alert_accounts, build};
+
+/// Create the owner's config, vault and fee reserve. Alert PDAs are
+/// pre-created inactive for `precreate_alerts`, any subset of `contacts`,
+/// so panic only has to flip them active.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_config(
+    owner: Pubkey,
+    vault_index: u8,
+    vault_name: String,
+    trigger_hash: [u8; 32],
+    contacts: Vec<Pubkey>,
+    recovery_threshold: u8,
+    time_lock_duration: i64,
+    decoy_lamports: u64,
+    decoy_memo: String,
+    label: String,
+    precreate_alerts: &[Pubkey],
+) -> Instruction {
+    let vault_id = VaultId::new(vault_index, &vault_name);
+    build(
+        accounts::InitializeConfig {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            protocol_stats: find_protocol_stats().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {
+            vault_index,
+            vault_name,
+            trigger_hash,
+            contacts,
+            recovery_threshold,
+            time_lock_duration,
+            decoy_lamports,
+            decoy_memo,
+            label,
+        },
+        alert_accounts(&owner, vault_id, precreate_alerts),
+    )
+}
+
+pub fn update_trigger(
+    owner: Pubkey,
+    vault_id: VaultId,
+    trigger_hash: [u8; 32],
+    trigger_mode: TriggerMode,
+) -> Instruction {
+    build(
+        accounts::UpdateTrigger {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::UpdateTrigger {
+            trigger_hash,
+            trigger_mode,
+        },
+        vec![],
+    )
+}
+
+pub fn set_attempt_limit(
+    owner: Pubkey,
+    vault_id: VaultId,
+    max_failed_attempts: u8,
+    lockout_duration: i64,
+    soft_lock_on_lockout: bool,
+) -> Instruction {
+    build(
+        accounts::SetAttemptLimit {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetAttemptLimit {
+            max_failed_attempts,
+            lockout_duration,
+            soft_lock_on_lockout,
+        },
+        vec![],
+    )
+}
+
+pub fn set_presign_nonce(
+    owner: Pubkey,
+    vault_id: VaultId,
+    nonce_account: Pubkey,
+    nonce_authority: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetPresignNonce {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetPresignNonce {
+            nonce_account,
+            nonce_authority,
+        },
+        vec![],
+    )
+}
+
+pub fn set_recovery_destination(
+    owner: Pubkey,
+    vault_id: VaultId,
+    recovery_destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetRecoveryDestination {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetRecoveryDestination {
+            recovery_destination,
+        },
+        vec![],
+    )
+}
+
+pub fn set_recovery_authority(
+    owner: Pubkey,
+    vault_id: VaultId,
+    recovery_authority: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetRecoveryAuthority {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetRecoveryAuthority { recovery_authority },
+        vec![],
+    )
+}
+
+pub fn set_recovery_window(owner: Pubkey, vault_id: VaultId, recovery_window: i64) -> Instruction {
+    build(
+        accounts::SetRecoveryWindow {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetRecoveryWindow { recovery_window },
+        vec![],
+    )
+}
+
+pub fn set_decoy_mode(
+    owner: Pubkey,
+    vault_id: VaultId,
+    decoy_mode: DecoyMode,
+    jitter_bps: u16,
+) -> Instruction {
+    build(
+        accounts::SetDecoyMode {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetDecoyMode {
+            decoy_mode,
+            jitter_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn set_decoy_drip(
+    owner: Pubkey,
+    vault_id: VaultId,
+    installments: u8,
+    interval: i64,
+) -> Instruction {
+    build(
+        accounts::SetDecoyDrip {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetDecoyDrip {
+            installments,
+            interval,
+        },
+        vec![],
+    )
+}
+
+pub fn set_decoy_token(owner: Pubkey, vault_id: VaultId, mint: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::SetDecoyToken {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetDecoyToken { mint, amount },
+        vec![],
+    )
+}
+
+pub fn set_balance_buffer(owner: Pubkey, vault_id: VaultId, balance_buffer: u64) -> Instruction {
+    build(
+        accounts::SetBalanceBuffer {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetBalanceBuffer { balance_buffer },
+        vec![],
+    )
+}
+
+/// `message` must already be encrypted to `contact`; it is public on-chain.
+pub fn set_alert_message(
+    owner: Pubkey,
+    vault_id: VaultId,
+    contact: Pubkey,
+    message: Vec<u8>,
+) -> Instruction {
+    build(
+        accounts::SetAlertMessage {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            contact,
+            alert_account: find_alert_account(&owner, vault_id, &contact).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetAlertMessage { message },
+        vec![],
+    )
+}
+
+pub fn set_alert_uri_template(owner: Pubkey, vault_id: VaultId, template: String) -> Instruction {
+    build(
+        accounts::SetAlertUriTemplate {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetAlertUriTemplate { template },
+        vec![],
+    )
+}
+
+/// `None` puts `mint` back on the config's time-lock.
+pub fn set_token_time_lock(
+    owner: Pubkey,
+    vault_id: VaultId,
+    mint: Pubkey,
+    duration: Option<i64>,
+) -> Instruction {
+    build(
+        accounts::SetTokenTimeLock {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+        },
+        instruction::SetTokenTimeLock { mint, duration },
+        vec![],
+    )
+}
+
+pub fn set_label(owner: Pubkey, vault_id: VaultId, label: String) -> Instruction {
+    build(
+        accounts::SetLabel {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetLabel { label },
+        vec![],
+    )
+}
+
+pub fn set_veto_policy(
+    owner: Pubkey,
+    vault_id: VaultId,
+    veto_quorum: u8,
+    veto_duration: i64,
+) -> Instruction {
+    build(
+        accounts::SetVetoPolicy {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetVetoPolicy {
+            veto_quorum,
+            veto_duration,
+        },
+        vec![],
+    )
+}
+
+pub fn set_claim_tiers(
+    owner: Pubkey,
+    vault_id: VaultId,
+    early_claim_limit: u64,
+    second_lock_duration: i64,
+) -> Instruction {
+    build(
+        accounts::SetClaimTiers {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetClaimTiers {
+            early_claim_limit,
+            second_lock_duration,
+        },
+        vec![],
+    )
+}
+
+pub fn set_large_claim_lock(
+    owner: Pubkey,
+    vault_id: VaultId,
+    large_claim_threshold: u64,
+    large_claim_lock_duration: i64,
+) -> Instruction {
+    build(
+        accounts::SetLargeClaimLock {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetLargeClaimLock {
+            large_claim_threshold,
+            large_claim_lock_duration,
+        },
+        vec![],
+    )
+}
+
+pub fn set_keeper_tip(owner: Pubkey, vault_id: VaultId, keeper_tip: u64) -> Instruction {
+    build(
+        accounts::SetKeeperTip {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetKeeperTip { keeper_tip },
+        vec![],
+    )
+}
+
+pub fn set_watcher(
+    owner: Pubkey,
+    vault_id: VaultId,
+    watcher: Pubkey,
+    attestor: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetWatcher {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetWatcher { watcher, attestor },
+        vec![],
+    )
+}
+
+pub fn set_co_owners(
+    owner: Pubkey,
+    vault_id: VaultId,
+    co_owners: Vec<Pubkey>,
+    owner_claim_quorum: u8,
+) -> Instruction {
+    build(
+        accounts::SetCoOwners {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetCoOwners {
+            co_owners,
+            owner_claim_quorum,
+        },
+        vec![],
+    )
+}
+
+pub fn propose_owner_transfer(owner: Pubkey, vault_id: VaultId, new_owner: Pubkey) -> Instruction {
+    build(
+        accounts::ProposeOwnerTransfer {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::ProposeOwnerTransfer { new_owner },
+        vec![],
+    )
+}
+
+/// Signed by `new_owner`, who pays rent for the re-seeded accounts.
+pub fn accept_owner_transfer(owner: Pubkey, vault_id: VaultId, new_owner: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptOwnerTransfer {
+            new_owner,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            new_panic_config: find_panic_config(&new_owner, vault_id).0,
+            new_vault: find_vault(&new_owner, vault_id).0,
+            new_fee_reserve: find_fee_reserve(&new_owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::AcceptOwnerTransfer {},
+        vec![],
+    )
+}
+
+/// Signed by both the owner and `wallet`.
+pub fn link_wallet(owner: Pubkey, vault_id: VaultId, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::LinkWallet {
+            owner,
+            wallet,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::LinkWallet {},
+        vec![],
+    )
+}
+
+pub fn unlink_wallet(owner: Pubkey, vault_id: VaultId, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::UnlinkWallet {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::UnlinkWallet { wallet },
+        vec![],
+    )
+}
+
+pub fn register_delegated_token_account(
+    owner: Pubkey,
+    vault_id: VaultId,
+    token_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RegisterDelegatedTokenAccount {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            token_account,
+        },
+        instruction::RegisterDelegatedTokenAccount {},
+        vec![],
+    )
+}
+
+pub fn remove_delegated_token_account(
+    owner: Pubkey,
+    vault_id: VaultId,
+    token_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RemoveDelegatedTokenAccount {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::RemoveDelegatedTokenAccount { token_account },
+        vec![],
+    )
+}
+
+pub fn init_panic_hooks(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::InitPanicHooks {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            panic_hooks: find_panic_hooks(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitPanicHooks {},
+        vec![],
+    )
+}
+
+pub fn register_panic_hook(
+    owner: Pubkey,
+    vault_id: VaultId,
+    program: Pubkey,
+    account_count: u8,
+) -> Instruction {
+    build(
+        accounts::RegisterPanicHook {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            panic_hooks: find_panic_hooks(&owner, vault_id).0,
+        },
+        instruction::RegisterPanicHook {
+            program,
+            account_count,
+        },
+        vec![],
+    )
+}
+
+pub fn remove_panic_hook(owner: Pubkey, vault_id: VaultId, program: Pubkey) -> Instruction {
+    build(
+        accounts::RemovePanicHook {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            panic_hooks: find_panic_hooks(&owner, vault_id).0,
+        },
+        instruction::RemovePanicHook { program },
+        vec![],
+    )
+}
+
+/// `merkle_tree` must already be allocated to the compression program,
+/// zeroed, earlier in the transaction.
+pub fn init_alert_tree(
+    owner: Pubkey,
+    vault_id: VaultId,
+    merkle_tree: Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    build(
+        accounts::InitAlertTree {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            merkle_tree,
+            noop: scream::NOOP_PROGRAM_ID,
+            compression_program: scream::ACCOUNT_COMPRESSION_PROGRAM_ID,
+        },
+        instruction::InitAlertTree {
+            max_depth,
+            max_buffer_size,
+        },
+        vec![],
+    )
+}
+
+pub fn migrate_account(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::MigrateAccount {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAccount {},
+        vec![],
+    )
+}
+
+/// Signed by anyone, e.g. the contact, who pays any extra rent.
+pub fn migrate_alert(payer: Pubkey, owner: Pubkey, contact: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAlert {
+            payer,
+            owner,
+            contact,
+            alert_account: find_alert_account(&owner, VaultId::FIRST, &contact).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAlert {},
+        vec![],
+    )
+}
+
+/// A zero `hot_threshold` turns tranches off.
+pub fn set_tranche_policy(
+    owner: Pubkey,
+    vault_id: VaultId,
+    hot_threshold: u8,
+    cold_lock_duration: i64,
+) -> Instruction {
+    build(
+        accounts::SetTranchePolicy {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+        },
+        instruction::SetTranchePolicy {
+            hot_threshold,
+            cold_lock_duration,
+        },
+        vec![],
+    )
+}
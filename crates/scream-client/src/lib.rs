@@ -0,0 +1,66 @@
+//! Instruction builders for the SCREAM program.
+//!
+//! Every instruction has a builder returning a ready-to-sign `Instruction`,
+//! so bots, keepers and relayers written in Rust don't hand-roll account
+//! metas. PDAs are derived from the owner, contact or attacker they belong
+//! to, plus the `VaultId` of the owner's config for accounts under one
+//! (`VaultId::FIRST` for a wallet's first), and remaining accounts (alert
+//! PDAs, attacker flags, Merkle proofs) are assembled from the keys they
+//! stand for. Builders with many optional parts, like `panic::TriggerPanic`,
+//! are structs; the rest are functions.
+//!
+//! Enable `event-cpi` or `lending` to match a program built with them.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use scream_interface::pda::find_alert_account;
+use scream_interface::state::VaultId;
+
+pub mod admin;
+pub mod config;
+pub mod panic;
+pub mod recovery;
+pub mod registry;
+pub mod vault;
+
+pub use scream::ID;
+pub use scream_interface::{pda, state};
+
+/// Anchor's generated accounts and args for one instruction, followed by
+/// its remaining accounts.
+fn build(
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+    remaining: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining);
+    Instruction {
+        program_id: ID,
+        accounts: metas,
+        data: args.data(),
+    }
+}
+
+/// The PDA that signs self-CPI events.
+#[cfg(feature = "event-cpi")]
+fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[scream::utils::EVENT_AUTHORITY_SEED], &ID).0
+}
+
+/// The owner's alert PDAs for `contacts`, writable, in the order given.
+pub fn alert_accounts(owner: &Pubkey, vault_id: VaultId, contacts: &[Pubkey]) -> Vec<AccountMeta> {
+    contacts
+        .iter()
+        .map(|contact| AccountMeta::new(find_alert_account(owner, vault_id, contact).0, false))
+        .collect()
+}
+
+/// A Merkle proof as remaining accounts, one node per key, leaf to root.
+fn proof_accounts(proof: &[[u8; 32]]) -> Vec<AccountMeta> {
+    proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(*node), false))
+        .collect()
+}
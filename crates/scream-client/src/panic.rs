@@ -0,0 +1,497 @@
+//! Triggering panic, and the cranks and cleanup around it.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::{memo, token};
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use scream_interface::state::{CompromiseReason, VaultId};
+use crate::{alert_accounts, build};
+
+/// Builds `trigger_panic`, or its stealth `transfer` alias. The owner signs
+/// as authority and pays unless told otherwise; every other part is off
+/// until set.
+#[derive(Clone, Debug)]
+pub struct TriggerPanic {
+    owner: Pubkey,
+    vault_id: VaultId,
+    authority: Pubkey,
+    payer: Pubkey,
+    attacker: Pubkey,
+    trigger_proof: Vec<u8>,
+    simulate: bool,
+    evidence_hash: [u8; 32],
+    reason: CompromiseReason,
+    severity: Option<u8>,
+    stealth: bool,
+    alert_contacts: Vec<Pubkey>,
+    extra_attackers: Vec<Pubkey>,
+    linked_wallets: Vec<(Pubkey, bool)>,
+    delegated_token_accounts: Vec<(Pubkey, Pubkey)>,
+    decoy_token_mint: Option<Pubkey>,
+    panic_hooks: Option<Vec<(Pubkey, Vec<AccountMeta>)>>,
+    attestor: Option<Pubkey>,
+}
+
+impl TriggerPanic {
+    pub fn new(owner: Pubkey, attacker: Pubkey, trigger_proof: Vec<u8>) -> Self {
+        Self {
+            owner,
+            vault_id: VaultId::FIRST,
+            authority: owner,
+            payer: owner,
+            attacker,
+            trigger_proof,
+            simulate: false,
+            evidence_hash: [0; 32],
+            reason: CompromiseReason::Unknown,
+            severity: None,
+            stealth: false,
+            alert_contacts: Vec::new(),
+            extra_attackers: Vec::new(),
+            linked_wallets: Vec::new(),
+            delegated_token_accounts: Vec::new(),
+            decoy_token_mint: None,
+            panic_hooks: None,
+            attestor: None,
+        }
+    }
+
+    /// Which of the owner's configs panics; `VaultId::FIRST` unless set.
+    pub fn vault_id(mut self, vault_id: VaultId) -> Self {
+        self.vault_id = vault_id;
+        self
+    }
+
+    /// A linked wallet, co-owner or the watcher signing instead of the owner.
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = authority;
+        self
+    }
+
+    /// A relayer or secondary wallet paying flag and alert rent.
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = payer;
+        self
+    }
+
+    pub fn simulate(mut self, simulate: bool) -> Self {
+        self.simulate = simulate;
+        self
+    }
+
+    pub fn evidence_hash(mut self, evidence_hash: [u8; 32]) -> Self {
+        self.evidence_hash = evidence_hash;
+        self
+    }
+
+    /// Why the owner is panicking, recorded on their compromised flag, with
+    /// a severity from 1 to 10 when they can say. `Unknown` otherwise;
+    /// `Rehearsal` is rejected.
+    pub fn reason(mut self, reason: CompromiseReason, severity: Option<u8>) -> Self {
+        self.reason = reason;
+        self.severity = severity;
+        self
+    }
+
+    /// Send as `transfer`, which reads like a plain transfer to onlookers.
+    pub fn stealth(mut self, stealth: bool) -> Self {
+        self.stealth = stealth;
+        self
+    }
+
+    /// Alert these contacts in the same transaction; the rest can follow
+    /// with `alert_contact`. Leave empty for compressed alerts.
+    pub fn alert(mut self, contacts: &[Pubkey]) -> Self {
+        self.alert_contacts = contacts.to_vec();
+        self
+    }
+
+    /// Further attackers to flag and split the decoy across.
+    pub fn extra_attackers(mut self, attackers: Vec<Pubkey>) -> Self {
+        self.extra_attackers = attackers;
+        self
+    }
+
+    /// Every linked wallet in config order, with whether it signs; a
+    /// signing wallet is swept into the vault.
+    pub fn linked_wallets(mut self, wallets: Vec<(Pubkey, bool)>) -> Self {
+        self.linked_wallets = wallets;
+        self
+    }
+
+    /// Every registered delegated token account in config order, with the
+    /// vault token account of the same mint its balance goes to.
+    pub fn delegated_token_accounts(mut self, pairs: Vec<(Pubkey, Pubkey)>) -> Self {
+        self.delegated_token_accounts = pairs;
+        self
+    }
+
+    /// The config's decoy mint, sent from the vault's associated token
+    /// account to the attacker's.
+    pub fn decoy_token(mut self, mint: Pubkey) -> Self {
+        self.decoy_token_mint = Some(mint);
+        self
+    }
+
+    /// Every registered hook in order, with its extra accounts.
+    pub fn panic_hooks(mut self, hooks: Vec<(Pubkey, Vec<AccountMeta>)>) -> Self {
+        self.panic_hooks = Some(hooks);
+        self
+    }
+
+    /// The config's attestor, co-signing a watcher's panic.
+    pub fn attestor(mut self, attestor: Pubkey) -> Self {
+        self.attestor = Some(attestor);
+        self
+    }
+
+    fn accounts(&self) -> accounts::TriggerPanic {
+        let vault = find_vault(&self.owner, self.vault_id).0;
+        let decoy = self.decoy_token_mint;
+        accounts::TriggerPanic {
+            authority: self.authority,
+            owner: self.owner,
+            payer: self.payer,
+            panic_config: find_panic_config(&self.owner, self.vault_id).0,
+            vault,
+            compromised_flag: find_compromised_flag(&self.owner).0,
+            attacker: self.attacker,
+            attacker_flag: find_attacker_flag(&self.attacker).0,
+            fee_reserve: find_fee_reserve(&self.owner, self.vault_id).0,
+            protocol_stats: find_protocol_stats().0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            slot_hashes: solana_sdk_ids::sysvar::slot_hashes::ID,
+            decoy_token_mint: decoy,
+            vault_decoy_token: decoy.map(|mint| get_associated_token_address(&vault, &mint)),
+            attacker_decoy_token: decoy
+                .map(|mint| get_associated_token_address(&self.attacker, &mint)),
+            token_program: decoy.map(|_| token::ID),
+            associated_token_program: decoy.map(|_| associated_token::ID),
+            memo_program: Some(memo::ID),
+            panic_hooks: self
+                .panic_hooks
+                .as_ref()
+                .map(|_| find_panic_hooks(&self.owner, self.vault_id).0),
+            attestor: self.attestor,
+            system_program: system_program::ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        }
+    }
+
+    pub fn instruction(self) -> Instruction {
+        let mut remaining = alert_accounts(&self.owner, self.vault_id, &self.alert_contacts);
+        for attacker in &self.extra_attackers {
+            remaining.push(AccountMeta::new(*attacker, false));
+            remaining.push(AccountMeta::new(find_attacker_flag(attacker).0, false));
+        }
+        for (wallet, signs) in &self.linked_wallets {
+            remaining.push(AccountMeta::new(*wallet, *signs));
+            remaining.push(AccountMeta::new(find_compromised_flag(wallet).0, false));
+        }
+        for (token_account, vault_token_account) in &self.delegated_token_accounts {
+            remaining.push(AccountMeta::new(*token_account, false));
+            remaining.push(AccountMeta::new(*vault_token_account, false));
+        }
+        for (program, extras) in self.panic_hooks.iter().flatten() {
+            remaining.push(AccountMeta::new_readonly(*program, false));
+            remaining.extend(extras.iter().cloned());
+        }
+
+        let accounts = self.accounts();
+        if self.stealth {
+            build(
+                accounts,
+                instruction::Transfer {
+                    trigger_proof: self.trigger_proof,
+                    simulate: self.simulate,
+                    extra_attackers: self.extra_attackers,
+                    evidence_hash: self.evidence_hash,
+                    reason: self.reason,
+                    severity: self.severity,
+                },
+                remaining,
+            )
+        } else {
+            build(
+                accounts,
+                instruction::TriggerPanic {
+                    trigger_proof: self.trigger_proof,
+                    simulate: self.simulate,
+                    extra_attackers: self.extra_attackers,
+                    evidence_hash: self.evidence_hash,
+                    reason: self.reason,
+                    severity: self.severity,
+                },
+                remaining,
+            )
+        }
+    }
+
+    /// `panic_and_sweep_tokens` instead: the trigger plus up to three
+    /// `(owner token account, vault token account)` sweeps and an optional
+    /// wrapped SOL account to unwrap into the vault. The contacts passed to
+    /// `alert` must be the config's full list, in order; extra attackers,
+    /// linked wallets, delegated accounts and hooks aren't used.
+    pub fn and_sweep_tokens(
+        self,
+        token_accounts: &[(Pubkey, Pubkey)],
+        owner_wsol: Option<Pubkey>,
+    ) -> Instruction {
+        let mut remaining = alert_accounts(&self.owner, self.vault_id, &self.alert_contacts);
+        for (source, destination) in token_accounts {
+            remaining.push(AccountMeta::new(*source, false));
+            remaining.push(AccountMeta::new(*destination, false));
+        }
+        build(
+            accounts::PanicAndSweepTokens {
+                panic: self.accounts(),
+                token_program: token::ID,
+                owner_wsol,
+            },
+            instruction::PanicAndSweepTokens {
+                trigger_proof: self.trigger_proof,
+                simulate: self.simulate,
+                evidence_hash: self.evidence_hash,
+                reason: self.reason,
+                severity: self.severity,
+            },
+            remaining,
+        )
+    }
+}
+
+/// Alert one contact after panic; anyone may pay.
+pub fn alert_contact(
+    payer: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    contact: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AlertContact {
+            payer,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            contact,
+            alert_account: find_alert_account(&owner, vault_id, &contact).0,
+            system_program: system_program::ID,
+        },
+        instruction::AlertContact {},
+        vec![],
+    )
+}
+
+/// Practice run alerting `contacts` with rehearsal alerts.
+pub fn rehearse_panic(owner: Pubkey, vault_id: VaultId, contacts: &[Pubkey]) -> Instruction {
+    build(
+        accounts::RehearsePanic {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::RehearsePanic {},
+        alert_accounts(&owner, vault_id, contacts),
+    )
+}
+
+/// Read-only pre-flight of a panic alerting `contacts`; simulate it and
+/// read the `PanicPreflight` from the return data.
+pub fn simulate_panic(
+    owner: Pubkey,
+    vault_id: VaultId,
+    trigger_proof: Vec<u8>,
+    contacts: &[Pubkey],
+) -> Instruction {
+    let remaining = contacts
+        .iter()
+        .map(|contact| {
+            AccountMeta::new_readonly(find_alert_account(&owner, vault_id, contact).0, false)
+        })
+        .collect();
+    build(
+        accounts::SimulatePanic {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+        },
+        instruction::SimulatePanic { trigger_proof },
+        remaining,
+    )
+}
+
+/// Close the alerts of `contacts`, signed by the owner or by a contact
+/// closing their own.
+pub fn close_alerts(
+    closer: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    contacts: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::CloseAlerts {
+            closer,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::CloseAlerts {},
+        alert_accounts(&owner, vault_id, contacts),
+    )
+}
+
+/// An account `gc` can collect.
+#[derive(Clone, Copy, Debug)]
+pub enum GcTarget {
+    /// A stale alert, its rent going back to the owner
+    StaleAlert {
+        owner: Pubkey,
+        vault_id: VaultId,
+        contact: Pubkey,
+    },
+    /// An expired, undisputed attacker flag, its rent going to the reporter
+    ExpiredFlag { attacker: Pubkey, reporter: Pubkey },
+}
+
+pub fn gc(cranker: Pubkey, targets: &[GcTarget]) -> Instruction {
+    let mut remaining = Vec::new();
+    for target in targets {
+        match target {
+            GcTarget::StaleAlert {
+                owner,
+                vault_id,
+                contact,
+            } => {
+                let alert = find_alert_account(owner, *vault_id, contact).0;
+                remaining.push(AccountMeta::new(alert, false));
+                remaining.push(AccountMeta::new(*owner, false));
+            }
+            GcTarget::ExpiredFlag { attacker, reporter } => {
+                remaining.push(AccountMeta::new(find_attacker_flag(attacker).0, false));
+                remaining.push(AccountMeta::new(*reporter, false));
+                remaining.push(AccountMeta::new_readonly(find_flag_dispute(attacker).0, false));
+            }
+        }
+    }
+    build(accounts::Gc { cranker }, instruction::Gc {}, remaining)
+}
+
+/// Release the next decoy installment to `attacker`, the vault's drip
+/// recipient; the keeper may be tipped.
+pub fn release_decoy_drip(
+    keeper: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    attacker: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ReleaseDecoyDrip {
+            keeper,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            attacker,
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::ReleaseDecoyDrip {},
+        vec![],
+    )
+}
+
+/// Revoke delegates on the owner's `token_accounts` under `token_program`
+/// (Token or Token-2022).
+pub fn revoke_delegates(
+    owner: Pubkey,
+    vault_id: VaultId,
+    token_program: Pubkey,
+    token_accounts: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::RevokeDelegates {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            token_program,
+        },
+        instruction::RevokeDelegates {},
+        token_accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false))
+            .collect(),
+    )
+}
+
+/// Move the authorities of the owner's `stake_accounts` to the vault.
+pub fn secure_stake_accounts(
+    owner: Pubkey,
+    vault_id: VaultId,
+    stake_accounts: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::SecureStakeAccounts {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            stake_program: solana_sdk_ids::stake::ID,
+        },
+        instruction::SecureStakeAccounts {},
+        stake_accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false))
+            .collect(),
+    )
+}
+
+/// Pull a secured, cooled-down stake account's SOL into the vault.
+pub fn withdraw_secured_stake(
+    owner: Pubkey,
+    vault_id: VaultId,
+    stake_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawSecuredStake {
+            owner,
+            vault: find_vault(&owner, vault_id).0,
+            stake_account,
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            stake_history: solana_sdk_ids::sysvar::stake_history::ID,
+            stake_program: solana_sdk_ids::stake::ID,
+        },
+        instruction::WithdrawSecuredStake {},
+        vec![],
+    )
+}
+
+/// Empty `(owner token account, vault token account)` pairs whose close
+/// authority is someone else's into the vault.
+pub fn reset_close_authorities(
+    owner: Pubkey,
+    vault_id: VaultId,
+    pairs: &[(Pubkey,
+    Pubkey)],
+) -> Instruction {
+    let mut remaining = Vec::with_capacity(pairs.len() * 2);
+    for (source, destination) in pairs {
+        remaining.push(AccountMeta::new(*source, false));
+        remaining.push(AccountMeta::new(*destination, false));
+    }
+    build(
+        accounts::ResetCloseAuthorities {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            token_program: token::ID,
+        },
+        instruction::ResetCloseAuthorities {},
+        remaining,
+    )
+}
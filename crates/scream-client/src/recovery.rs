@@ -0,0 +1,314 @@
+//! Getting funds back after a panic: recovery rounds, guardian approvals
+//! and vetoes, and claims.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::token;
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use scream_interface::state::VaultId;
+use crate::{build, proof_accounts};
+
+/// Signed by the owner, or the config's recovery authority when one is set.
+pub fn initiate_recovery(authority: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::InitiateRecovery {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::InitiateRecovery {},
+        vec![],
+    )
+}
+
+pub fn approve_recovery(contact: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::ApproveRecovery {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            alert_account: find_alert_account(&owner, vault_id, &contact).0,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ApproveRecovery {},
+        vec![],
+    )
+}
+
+/// What `contact` signs off-chain for `approve_recovery_with_sig` in the
+/// vault's `recovery_round`.
+pub fn approval_message(
+    owner: &Pubkey,
+    vault_id: VaultId,
+    contact: &Pubkey,
+    recovery_round: u32,
+) -> Vec<u8> {
+    scream::approval_message(&find_alert_account(owner, vault_id, contact).0, recovery_round)
+}
+
+/// Relay a contact's signed approval. The Ed25519 program instruction
+/// carrying their signature over `approval_message` must come earlier in
+/// the transaction.
+pub fn approve_recovery_with_sig(
+    relayer: Pubkey,
+    contact: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+) -> Instruction {
+    build(
+        accounts::ApproveRecoveryWithSig {
+            relayer,
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            alert_account: find_alert_account(&owner, vault_id, &contact).0,
+            instructions_sysvar: solana_sdk_ids::sysvar::instructions::ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ApproveRecoveryWithSig {},
+        vec![],
+    )
+}
+
+/// Approve with a compressed alert: leaf `index` goes from
+/// `previous_round` to the current round under `root`, with `proof` as
+/// served by an indexer, leaf to root.
+#[allow(clippy::too_many_arguments)]
+pub fn approve_recovery_compressed(
+    contact: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    merkle_tree: Pubkey,
+    root: [u8; 32],
+    previous_round: u32,
+    index: u32,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    build(
+        accounts::ApproveRecoveryCompressed {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            merkle_tree,
+            noop: scream::NOOP_PROGRAM_ID,
+            compression_program: scream::ACCOUNT_COMPRESSION_PROGRAM_ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ApproveRecoveryCompressed {
+            root,
+            previous_round,
+            index,
+        },
+        proof_accounts(proof),
+    )
+}
+
+pub fn acknowledge_alert(contact: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::AcknowledgeAlert {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            alert_account: find_alert_account(&owner, vault_id, &contact).0,
+        },
+        instruction::AcknowledgeAlert {},
+        vec![],
+    )
+}
+
+pub fn veto_claim(contact: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::VetoClaim {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::VetoClaim {},
+        vec![],
+    )
+}
+
+pub fn extend_time_lock(contact: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::ExtendTimeLock {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::ExtendTimeLock {},
+        vec![],
+    )
+}
+
+pub fn fast_track_recovery(contact: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::FastTrackRecovery {
+            contact,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::FastTrackRecovery {},
+        vec![],
+    )
+}
+
+/// Signed by the owner or one of the config's co-owners.
+pub fn approve_owner_claim(approver: Pubkey, owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::ApproveOwnerClaim {
+            approver,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::ApproveOwnerClaim {},
+        vec![],
+    )
+}
+
+/// Claim `amount`, or everything available when `None`. Pass
+/// `deposit_ledger` when the owner has opted in to one.
+pub fn claim_from_vault(
+    authority: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    simulate: bool,
+    amount: Option<u64>,
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::ClaimFromVault {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            protocol_stats: find_protocol_stats().0,
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ClaimFromVault { simulate, amount },
+        vec![],
+    )
+}
+
+/// Claim `amount` of `vault_token`'s mint, or all of it when `None`, once
+/// that mint's time-lock is over.
+pub fn claim_tokens(
+    authority: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    vault_token: Pubkey,
+    destination: Pubkey,
+    amount: Option<u64>,
+) -> Instruction {
+    build(
+        accounts::ClaimTokens {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            vault_token,
+            destination,
+            token_program: token::ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ClaimTokens { amount },
+        vec![],
+    )
+}
+
+/// Claim everything, `shares` giving each destination its share in basis
+/// points. Pass `deposit_ledger` when the owner has opted in to one.
+pub fn claim_split(
+    authority: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    shares: &[(Pubkey, u16)],
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::ClaimSplit {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            protocol_stats: find_protocol_stats().0,
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::ClaimSplit {
+            shares_bps: shares.iter().map(|(_, bps)| *bps).collect(),
+        },
+        shares
+            .iter()
+            .map(|(destination, _)| AccountMeta::new(*destination, false))
+            .collect(),
+    )
+}
+
+/// Push a ready claim through to the config's recovery destination, for
+/// the keeper tip.
+pub fn finalize_claim(
+    keeper: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    destination: Pubkey,
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::FinalizeClaim {
+            keeper,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            destination,
+            protocol_stats: find_protocol_stats().0,
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+            fee_reserve: find_fee_reserve(&owner, vault_id).0,
+            system_program: system_program::ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: crate::event_authority(),
+            #[cfg(feature = "event-cpi")]
+            program: crate::ID,
+        },
+        instruction::FinalizeClaim {},
+        vec![],
+    )
+}
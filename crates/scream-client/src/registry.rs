@@ -0,0 +1,274 @@
+//! The shared attacker registry: flags, reports, disputes, links and the
+//! compressed registry, plus the guardian directory.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use scream_interface::state::{AttackerLink, CompressedFlagLeaf, LinkKind, VaultId};
+use crate::{build, proof_accounts};
+
+pub fn report_attacker(reporter: Pubkey, attacker: Pubkey, evidence_hash: [u8; 32]) -> Instruction {
+    build(
+        accounts::ReportAttacker {
+            reporter,
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            dispute_config: find_dispute_config().0,
+            protocol_stats: find_protocol_stats().0,
+            report: find_attacker_report(&attacker, &reporter).0,
+            system_program: system_program::ID,
+        },
+        instruction::ReportAttacker { evidence_hash },
+        vec![],
+    )
+}
+
+/// Signed by the flagged address itself, posting the dispute bond.
+pub fn dispute_flag(attacker: Pubkey) -> Instruction {
+    build(
+        accounts::DisputeFlag {
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            dispute_config: find_dispute_config().0,
+            dispute: find_flag_dispute(&attacker).0,
+            system_program: system_program::ID,
+        },
+        instruction::DisputeFlag {},
+        vec![],
+    )
+}
+
+/// Signed by the reporter, who gets the bond and rent back.
+pub fn reclaim_report(reporter: Pubkey, attacker: Pubkey) -> Instruction {
+    build(
+        accounts::ReclaimReport {
+            reporter,
+            attacker,
+            report: find_attacker_report(&attacker, &reporter).0,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            dispute: find_flag_dispute(&attacker).0,
+        },
+        instruction::ReclaimReport {},
+        vec![],
+    )
+}
+
+/// Rule on a dispute, or close a lapsed one. `reporter` is the flag's
+/// `reported_by`; `community_reporters` are those whose reports get
+/// slashed when the flag is cleared, which must be all of them.
+pub fn resolve_dispute(
+    authority: Pubkey,
+    attacker: Pubkey,
+    reporter: Pubkey,
+    cleared: bool,
+    community_reporters: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::ResolveDispute {
+            authority,
+            dispute_config: find_dispute_config().0,
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            reporter,
+            dispute: find_flag_dispute(&attacker).0,
+        },
+        instruction::ResolveDispute { cleared },
+        community_reporters
+            .iter()
+            .map(|community| AccountMeta::new(find_attacker_report(&attacker, community).0, false))
+            .collect(),
+    )
+}
+
+/// `reporter` is the flag's `reported_by`, who gets its rent back.
+pub fn expire_attacker_flag(cranker: Pubkey, attacker: Pubkey, reporter: Pubkey) -> Instruction {
+    build(
+        accounts::ExpireAttackerFlag {
+            cranker,
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            reporter,
+            dispute: find_flag_dispute(&attacker).0,
+        },
+        instruction::ExpireAttackerFlag {},
+        vec![],
+    )
+}
+
+/// `reporter` is the flag's `reported_by`, who gets its rent back.
+pub fn close_attacker_flag(authority: Pubkey, attacker: Pubkey, reporter: Pubkey) -> Instruction {
+    build(
+        accounts::CloseAttackerFlag {
+            authority,
+            dispute_config: find_dispute_config().0,
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            reporter,
+            dispute: find_flag_dispute(&attacker).0,
+        },
+        instruction::CloseAttackerFlag {},
+        vec![],
+    )
+}
+
+/// Read-only; returns an `AttackerStatus`.
+pub fn check_attacker(attacker: Pubkey) -> Instruction {
+    build(
+        accounts::CheckAttacker {
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+        },
+        instruction::CheckAttacker {},
+        vec![],
+    )
+}
+
+/// Read-only; returns a `FlagStatus`.
+pub fn is_flagged(attacker: Pubkey) -> Instruction {
+    build(
+        accounts::IsFlagged {
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+        },
+        instruction::IsFlagged {},
+        vec![],
+    )
+}
+
+/// Fails when `owner` has been flagged compromised.
+pub fn assert_not_compromised(owner: Pubkey) -> Instruction {
+    build(
+        accounts::AssertNotCompromised {
+            compromised_flag: find_compromised_flag(&owner).0,
+        },
+        instruction::AssertNotCompromised { owner },
+        vec![],
+    )
+}
+
+pub fn claim_report_bounty(reporter: Pubkey, attacker: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimReportBounty {
+            reporter,
+            attacker,
+            attacker_flag: find_attacker_flag(&attacker).0,
+            dispute: find_flag_dispute(&attacker).0,
+            protocol_config: find_protocol_config().0,
+            treasury: find_treasury().0,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimReportBounty {},
+        vec![],
+    )
+}
+
+/// Link two addresses, in either order, on the strength of `flagged`'s
+/// flag; `flagged` must be one of them.
+pub fn link_attackers(
+    reporter: Pubkey,
+    a: Pubkey,
+    b: Pubkey,
+    flagged: Pubkey,
+    kind: LinkKind,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    let (address_a, address_b) = AttackerLink::ordered(a, b);
+    build(
+        accounts::LinkAttackers {
+            reporter,
+            address_a,
+            address_b,
+            attacker_flag: find_attacker_flag(&flagged).0,
+            attacker_link: find_attacker_link(&a, &b).0,
+            system_program: system_program::ID,
+        },
+        instruction::LinkAttackers {
+            kind,
+            evidence_hash,
+        },
+        vec![],
+    )
+}
+
+/// Signed by the link's reporter or the arbiter; `reporter` gets the rent.
+pub fn unlink_attackers(authority: Pubkey, a: Pubkey, b: Pubkey, reporter: Pubkey) -> Instruction {
+    build(
+        accounts::UnlinkAttackers {
+            authority,
+            dispute_config: find_dispute_config().0,
+            attacker_link: find_attacker_link(&a, &b).0,
+            reporter,
+        },
+        instruction::UnlinkAttackers {},
+        vec![],
+    )
+}
+
+/// `merkle_tree` is the compressed registry's tree.
+pub fn append_compressed_flag(
+    reporter: Pubkey,
+    attacker: Pubkey,
+    merkle_tree: Pubkey,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::AppendCompressedFlag {
+            reporter,
+            attacker,
+            compressed_registry: find_compressed_registry().0,
+            merkle_tree,
+            noop: scream::NOOP_PROGRAM_ID,
+            compression_program: scream::ACCOUNT_COMPRESSION_PROGRAM_ID,
+        },
+        instruction::AppendCompressedFlag { evidence_hash },
+        vec![],
+    )
+}
+
+/// Prove `flag` is leaf `index` of the registry's tree under `root`, with
+/// `proof` as served by an indexer, leaf to root.
+pub fn verify_compressed_flag(
+    merkle_tree: Pubkey,
+    root: [u8; 32],
+    flag: CompressedFlagLeaf,
+    index: u32,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    build(
+        accounts::VerifyCompressedFlag {
+            compressed_registry: find_compressed_registry().0,
+            merkle_tree,
+            compression_program: scream::ACCOUNT_COMPRESSION_PROGRAM_ID,
+        },
+        instruction::VerifyCompressedFlag { root, flag, index },
+        proof_accounts(proof),
+    )
+}
+
+pub fn init_guardian_registry(contact: Pubkey) -> Instruction {
+    build(
+        accounts::InitGuardianRegistry {
+            contact,
+            guardian_registry: find_guardian_registry(&contact).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitGuardianRegistry {},
+        vec![],
+    )
+}
+
+/// List the owner in `contact`'s guardian registry.
+pub fn register_guardian(owner: Pubkey, vault_id: VaultId, contact: Pubkey) -> Instruction {
+    build(
+        accounts::RegisterGuardian {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            contact,
+            guardian_registry: find_guardian_registry(&contact).0,
+        },
+        instruction::RegisterGuardian {},
+        vec![],
+    )
+}
@@ -0,0 +1,451 @@
+//! Funding the vault, routine withdrawals, and putting vault SOL to work.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token;
+use scream::{accounts, instruction};
+use scream_interface::pda::*;
+use scream_interface::state::{VaultId, WithdrawPolicy};
+use crate::build;
+
+/// Pass `deposit_ledger` when the owner has opted in to one.
+pub fn deposit(owner: Pubkey, vault_id: VaultId, amount: u64, deposit_ledger: bool) -> Instruction {
+    build(
+        accounts::Deposit {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+            system_program: system_program::ID,
+        },
+        instruction::Deposit { amount },
+        vec![],
+    )
+}
+
+/// Fund someone else's vault. Pass `deposit_ledger` when they have opted
+/// in to one.
+pub fn deposit_for(
+    depositor: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    amount: u64,
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::DepositFor {
+            depositor,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            protocol_config: find_protocol_config().0,
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+            system_program: system_program::ID,
+        },
+        instruction::DepositFor { amount },
+        vec![],
+    )
+}
+
+pub fn init_deposit_ledger(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::InitDepositLedger {
+            owner,
+            vault: find_vault(&owner, vault_id).0,
+            deposit_ledger: find_deposit_ledger(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitDepositLedger {},
+        vec![],
+    )
+}
+
+/// Read-only; simulate it and read the `VaultStatus` from the return data.
+pub fn vault_status(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::GetVaultStatus {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::VaultStatus {},
+        vec![],
+    )
+}
+
+/// Read-only; simulate it and read the `ConfigView` from the return data.
+pub fn get_config(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::GetVaultStatus {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::GetConfig {},
+        vec![],
+    )
+}
+
+pub fn set_withdraw_policy(
+    owner: Pubkey,
+    vault_id: VaultId,
+    policy: WithdrawPolicy,
+) -> Instruction {
+    build(
+        accounts::SetWithdrawPolicy {
+            owner,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::SetWithdrawPolicy { policy },
+        vec![],
+    )
+}
+
+pub fn request_withdraw(
+    owner: Pubkey,
+    vault_id: VaultId,
+    amount: u64,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RequestWithdraw {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            withdraw_request: find_withdraw_request(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::RequestWithdraw {
+            amount,
+            destination,
+        },
+        vec![],
+    )
+}
+
+/// Pay out the pending request to its `destination`. Pass
+/// `withdraw_allowlist` when the policy requires one and `deposit_ledger`
+/// when the owner has opted in to one.
+pub fn execute_withdraw(
+    owner: Pubkey,
+    vault_id: VaultId,
+    destination: Pubkey,
+    withdraw_allowlist: bool,
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::ExecuteWithdraw {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            withdraw_request: find_withdraw_request(&owner, vault_id).0,
+            destination,
+            withdraw_allowlist: withdraw_allowlist
+                .then(|| find_withdraw_allowlist(&owner, vault_id).0),
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+        },
+        instruction::ExecuteWithdraw {},
+        vec![],
+    )
+}
+
+pub fn cancel_withdraw(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::CancelWithdraw {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            withdraw_request: find_withdraw_request(&owner, vault_id).0,
+        },
+        instruction::CancelWithdraw {},
+        vec![],
+    )
+}
+
+/// Spend from the vault within its policy. Pass `withdraw_allowlist` when
+/// the policy requires one and `deposit_ledger` when the owner has opted
+/// in to one.
+pub fn vault_transfer(
+    owner: Pubkey,
+    vault_id: VaultId,
+    recipient: Pubkey,
+    amount: u64,
+    withdraw_allowlist: bool,
+    deposit_ledger: bool,
+) -> Instruction {
+    build(
+        accounts::VaultTransfer {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            recipient,
+            withdraw_allowlist: withdraw_allowlist
+                .then(|| find_withdraw_allowlist(&owner, vault_id).0),
+            deposit_ledger: deposit_ledger.then(|| find_deposit_ledger(&owner, vault_id).0),
+        },
+        instruction::VaultTransfer { amount },
+        vec![],
+    )
+}
+
+pub fn init_withdraw_allowlist(owner: Pubkey, vault_id: VaultId) -> Instruction {
+    build(
+        accounts::InitWithdrawAllowlist {
+            owner,
+            vault: find_vault(&owner, vault_id).0,
+            withdraw_allowlist: find_withdraw_allowlist(&owner, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitWithdrawAllowlist {},
+        vec![],
+    )
+}
+
+pub fn add_withdraw_destination(
+    owner: Pubkey,
+    vault_id: VaultId,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AddWithdrawDestination {
+            owner,
+            vault: find_vault(&owner, vault_id).0,
+            withdraw_allowlist: find_withdraw_allowlist(&owner, vault_id).0,
+        },
+        instruction::AddWithdrawDestination { destination },
+        vec![],
+    )
+}
+
+pub fn remove_withdraw_destination(
+    owner: Pubkey,
+    vault_id: VaultId,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RemoveWithdrawDestination {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            withdraw_allowlist: find_withdraw_allowlist(&owner, vault_id).0,
+        },
+        instruction::RemoveWithdrawDestination { destination },
+        vec![],
+    )
+}
+
+/// The accounts of an SPL stake pool, as read from the pool account.
+#[derive(Clone, Copy, Debug)]
+pub struct StakePool {
+    pub stake_pool: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub pool_mint: Pubkey,
+}
+
+impl StakePool {
+    /// The pool's withdraw authority PDA.
+    pub fn withdraw_authority(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[self.stake_pool.as_ref(), b"withdraw"],
+            &scream::SPL_STAKE_POOL_PROGRAM_ID,
+        )
+        .0
+    }
+}
+
+/// Deposit vault SOL into `pool`; the LST lands in the vault's associated
+/// token account, which must exist.
+pub fn stake_vault_sol(
+    owner: Pubkey,
+    vault_id: VaultId,
+    pool: &StakePool,
+    lamports: u64,
+) -> Instruction {
+    let vault = find_vault(&owner, vault_id).0;
+    build(
+        accounts::StakeVaultSol {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault,
+            stake_funder: find_stake_funder(&owner, vault_id).0,
+            vault_pool_tokens: get_associated_token_address(&vault, &pool.pool_mint),
+            stake_pool: pool.stake_pool,
+            pool_withdraw_authority: pool.withdraw_authority(),
+            reserve_stake: pool.reserve_stake,
+            manager_fee_account: pool.manager_fee_account,
+            pool_mint: pool.pool_mint,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            stake_pool_program: scream::SPL_STAKE_POOL_PROGRAM_ID,
+        },
+        instruction::StakeVaultSol { lamports },
+        vec![],
+    )
+}
+
+/// Signed by the owner before panic, or the recovery signer once recovery
+/// has been initiated.
+pub fn unstake_vault_sol(
+    authority: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    pool: &StakePool,
+    pool_tokens: u64,
+) -> Instruction {
+    let vault = find_vault(&owner, vault_id).0;
+    build(
+        accounts::UnstakeVaultSol {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault,
+            vault_pool_tokens: get_associated_token_address(&vault, &pool.pool_mint),
+            stake_pool: pool.stake_pool,
+            pool_withdraw_authority: pool.withdraw_authority(),
+            reserve_stake: pool.reserve_stake,
+            manager_fee_account: pool.manager_fee_account,
+            pool_mint: pool.pool_mint,
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            stake_history: solana_sdk_ids::sysvar::stake_history::ID,
+            stake_program: solana_sdk_ids::stake::ID,
+            token_program: token::ID,
+            stake_pool_program: scream::SPL_STAKE_POOL_PROGRAM_ID,
+        },
+        instruction::UnstakeVaultSol { pool_tokens },
+        vec![],
+    )
+}
+
+/// Redeem the owner's own LST from `owner_pool_tokens` into the vault
+/// after panic.
+pub fn unstake_pool_tokens(
+    owner: Pubkey,
+    vault_id: VaultId,
+    owner_pool_tokens: Pubkey,
+    pool: &StakePool,
+    pool_tokens: u64,
+) -> Instruction {
+    build(
+        accounts::UnstakePoolTokens {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+            owner_pool_tokens,
+            stake_pool: pool.stake_pool,
+            pool_withdraw_authority: pool.withdraw_authority(),
+            reserve_stake: pool.reserve_stake,
+            manager_fee_account: pool.manager_fee_account,
+            pool_mint: pool.pool_mint,
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            stake_history: solana_sdk_ids::sysvar::stake_history::ID,
+            stake_program: solana_sdk_ids::stake::ID,
+            token_program: token::ID,
+            stake_pool_program: scream::SPL_STAKE_POOL_PROGRAM_ID,
+        },
+        instruction::UnstakePoolTokens { pool_tokens },
+        vec![],
+    )
+}
+
+/// The accounts of a token-lending SOL reserve, as read from the reserve.
+#[cfg(feature = "lending")]
+#[derive(Clone, Copy, Debug)]
+pub struct LendingReserve {
+    pub reserve: Pubkey,
+    pub liquidity_supply: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub lending_market: Pubkey,
+}
+
+#[cfg(feature = "lending")]
+impl LendingReserve {
+    /// The lending market's authority PDA.
+    pub fn market_authority(&self) -> Pubkey {
+        Pubkey::find_program_address(&[self.lending_market.as_ref()], &scream::LENDING_PROGRAM_ID)
+            .0
+    }
+}
+
+/// Lend vault SOL to `reserve`; the collateral lands in the vault's
+/// associated token account, which must exist.
+#[cfg(feature = "lending")]
+pub fn lend_vault_sol(
+    owner: Pubkey,
+    vault_id: VaultId,
+    reserve: &LendingReserve,
+    lamports: u64,
+) -> Instruction {
+    let vault = find_vault(&owner, vault_id).0;
+    build(
+        accounts::LendVaultSol {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault,
+            vault_wsol: get_associated_token_address(&vault, &token::spl_token::native_mint::ID),
+            native_mint: token::spl_token::native_mint::ID,
+            vault_collateral: get_associated_token_address(&vault, &reserve.collateral_mint),
+            reserve: reserve.reserve,
+            reserve_liquidity_supply: reserve.liquidity_supply,
+            reserve_collateral_mint: reserve.collateral_mint,
+            lending_market: reserve.lending_market,
+            lending_market_authority: reserve.market_authority(),
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            token_program: token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            lending_program: scream::LENDING_PROGRAM_ID,
+        },
+        instruction::LendVaultSol { lamports },
+        vec![],
+    )
+}
+
+/// Signed by the owner before panic, or the recovery signer once recovery
+/// has been initiated.
+#[cfg(feature = "lending")]
+pub fn redeem_vault_sol(
+    authority: Pubkey,
+    owner: Pubkey,
+    vault_id: VaultId,
+    reserve: &LendingReserve,
+    collateral: u64,
+) -> Instruction {
+    let vault = find_vault(&owner, vault_id).0;
+    build(
+        accounts::RedeemVaultSol {
+            authority,
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault,
+            vault_wsol: get_associated_token_address(&vault, &token::spl_token::native_mint::ID),
+            native_mint: token::spl_token::native_mint::ID,
+            vault_collateral: get_associated_token_address(&vault, &reserve.collateral_mint),
+            reserve: reserve.reserve,
+            reserve_collateral_mint: reserve.collateral_mint,
+            reserve_liquidity_supply: reserve.liquidity_supply,
+            lending_market: reserve.lending_market,
+            lending_market_authority: reserve.market_authority(),
+            clock: solana_sdk_ids::sysvar::clock::ID,
+            token_program: token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            lending_program: scream::LENDING_PROGRAM_ID,
+        },
+        instruction::RedeemVaultSol { collateral },
+        vec![],
+    )
+}
+
+pub fn rebalance_tranches(owner: Pubkey, vault_id: VaultId, hot_lamports: u64) -> Instruction {
+    build(
+        accounts::RebalanceTranches {
+            owner,
+            panic_config: find_panic_config(&owner, vault_id).0,
+            vault: find_vault(&owner, vault_id).0,
+        },
+        instruction::RebalanceTranches { hot_lamports },
+        vec![],
+    )
+}
@@ -0,0 +1,12 @@
+//! Types shared between the SCREAM program and its integrators.
+//!
+//! This crate carries the on-chain account layouts, PDA seeds and
+//! instruction argument types without the program entrypoint, so other
+//! Anchor programs and off-chain clients can depend on it cheaply.
+
+use anchor_lang::prelude::*;
+
+pub mod state;
+pub mod pda;
+
+declare_id!("5zPdLCuRqcPqN5TZxR6yUcfTJ9ufLhoZAMVn6pEFXnyc");
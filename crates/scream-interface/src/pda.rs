@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+pub fn find_panic_config(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PanicConfig::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_vault(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Vault::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_compromised_flag(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CompromisedFlag::SEED_PREFIX, owner.as_ref()], &crate::ID)
+}
+
+pub fn find_attacker_flag(attacker: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AttackerFlag::SEED_PREFIX, attacker.as_ref()], &crate::ID)
+}
+
+pub fn find_alert_account(owner: &Pubkey, vault: VaultId, contact: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AlertAccount::SEED_PREFIX,
+            owner.as_ref(),
+            contact.as_ref(),
+            vault.seed(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_fee_reserve(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FeeReserve::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_dispute_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DisputeConfig::SEED_PREFIX], &crate::ID)
+}
+
+pub fn find_flag_dispute(attacker: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FlagDispute::SEED_PREFIX, attacker.as_ref()], &crate::ID)
+}
+
+pub fn find_attacker_report(attacker: &Pubkey, reporter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AttackerReport::SEED_PREFIX, attacker.as_ref(), reporter.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_guardian_registry(contact: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GuardianRegistry::SEED_PREFIX, contact.as_ref()], &crate::ID)
+}
+
+pub fn find_protocol_stats() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ProtocolStats::SEED_PREFIX], &crate::ID)
+}
+
+pub fn find_protocol_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ProtocolConfig::SEED_PREFIX], &crate::ID)
+}
+
+pub fn find_treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Treasury::SEED_PREFIX], &crate::ID)
+}
+
+pub fn find_withdraw_request(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WithdrawRequest::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_withdraw_allowlist(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WithdrawAllowlist::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_deposit_ledger(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DepositLedger::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_stake_funder(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Vault::STAKE_FUNDER_SEED, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_panic_hooks(owner: &Pubkey, vault: VaultId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PanicHooks::SEED_PREFIX, owner.as_ref(), vault.seed()],
+        &crate::ID,
+    )
+}
+
+pub fn find_attacker_link(a: &Pubkey, b: &Pubkey) -> (Pubkey, u8) {
+    let (address_a, address_b) = AttackerLink::ordered(*a, *b);
+    Pubkey::find_program_address(
+        &[AttackerLink::SEED_PREFIX, address_a.as_ref(), address_b.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_compressed_registry() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CompressedRegistry::SEED_PREFIX], &crate::ID)
+}
+
+pub fn find_program_info() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ProgramInfo::SEED_PREFIX], &crate::ID)
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AlertAccount {
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    /// Timestamp when the alert fired; zero while a pre-created alert is inactive
+    pub alerted_at: i64,
+    /// Set once panic fires; alerts pre-created at initialize start inactive
+    pub is_active: bool,
+    /// Set by `rehearse_panic`, cleared when a real panic fires; a
+    /// rehearsal alert can be acknowledged but never approves recovery
+    pub is_rehearsal: bool,
+    /// Whether this contact has approved recovery in `approved_round`
+    pub has_approved: bool,
+    /// Vault `recovery_round` the approval was given in
+    pub approved_round: u32,
+    /// When the contact acknowledged the alert (0 = not yet)
+    pub acknowledged_at: i64,
+    /// Context for the contact, encrypted to them off-chain (empty = none)
+    #[max_len(128)]
+    pub message: Vec<u8>,
+    /// Off-chain encrypted instructions (IPFS/Arweave), from the config's
+    /// template (empty = none)
+    #[max_len(160)]
+    pub uri: String,
+    /// Layout version; `migrate_alert` upgrades older ones
+    pub version: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AlertAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"alert";
+    /// See `PanicConfig::CURRENT_VERSION`.
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Prefix of the message a contact signs off-chain to approve recovery,
+    /// followed by the alert account address and the vault's
+    /// `recovery_round` (u32 LE)
+    pub const APPROVAL_SIGNATURE_DOMAIN: &'static [u8] = b"scream:approve:";
+    /// Domain prefix of a compressed alert leaf, followed by the owner, the
+    /// contact and the round they last approved in (u32 LE, 0 = never)
+    pub const COMPRESSED_LEAF_DOMAIN: &'static [u8] = b"scream:alert-leaf";
+    /// Matches `max_len` on `message`
+    pub const MAX_MESSAGE_LEN: usize = 128;
+    /// Age after which a fired alert may be garbage-collected
+    pub const STALE_AFTER: i64 = 365 * 24 * 60 * 60;
+    /// Age after which a rehearsal alert may be garbage-collected
+    pub const REHEARSAL_STALE_AFTER: i64 = 30 * 24 * 60 * 60;
+
+    /// Whether `gc` may close this alert at `now`: a rehearsal nobody
+    /// followed up on, or a real alert long past its incident.
+    pub fn is_stale(&self, now: i64) -> bool {
+        if self.is_active {
+            now >= self.alerted_at + Self::STALE_AFTER
+        } else {
+            self.is_rehearsal && now >= self.alerted_at + Self::REHEARSAL_STALE_AFTER
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AttackerFlag {
+    pub attacker: Pubkey,
+    /// Who flagged this attacker
+    pub reported_by: Pubkey,
+    /// Timestamp when flagged
+    pub flagged_at: i64,
+    /// Hash of supporting evidence, e.g. the drain transaction signature or
+    /// a report document; all zeroes when none was given
+    pub evidence_hash: [u8; 32],
+    /// When the flag may be garbage-collected; zero means it never expires
+    pub expires_at: i64,
+    /// Panics by other owners that later flagged the same address
+    pub corroborations: u8,
+    /// Whether the reporter has collected the report bounty
+    pub bounty_paid: bool,
+    /// Community reports filed against the address
+    pub reporters: u16,
+    /// Bonds locked behind those reports
+    pub backing_lamports: u64,
+    /// Disputes the arbiter ruled against the address
+    pub disputes_upheld: u8,
+    /// Reputation from 0 to `MAX_SCORE`, kept up to date by `refresh_score`
+    pub score: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AttackerFlag {
+    pub const SEED_PREFIX: &'static [u8] = b"attacker";
+    /// Lifetime given to new flags (two years)
+    pub const DEFAULT_TTL: i64 = 2 * 365 * 24 * 60 * 60;
+    /// Weight of a fresh flag, in basis points
+    pub const FULL_WEIGHT_BPS: u16 = 10_000;
+    /// Corroborations a flag needs before its reporter can claim the bounty
+    pub const BOUNTY_CORROBORATIONS: u8 = 2;
+    pub const MAX_SCORE: u8 = 100;
+
+    /// Recompute `score`: 10 for the flag itself, 20 per corroborating victim
+    /// (up to 60), 5 per community report (up to 20), 1 per 0.01 SOL of
+    /// bonds (up to 10) and 20 for a dispute upheld, capped at `MAX_SCORE`.
+    /// One angry user scores 15 to 25; a confirmed serial drainer hits 100.
+    pub fn refresh_score(&mut self) {
+        let score = 10
+            + (self.corroborations as u64 * 20).min(60)
+            + (self.reporters as u64 * 5).min(20)
+            + (self.backing_lamports / 10_000_000).min(10)
+            + (self.disputes_upheld as u64 * 20).min(20);
+        self.score = score.min(Self::MAX_SCORE as u64) as u8;
+    }
+
+    /// How much the flag still counts at `now`, in basis points: full weight
+    /// when flagged, decaying linearly to zero at `expires_at`. Flags that
+    /// never expire keep full weight.
+    pub fn weight_bps(&self, now: i64) -> u16 {
+        if self.expires_at == 0 {
+            return Self::FULL_WEIGHT_BPS;
+        }
+        if now >= self.expires_at {
+            return 0;
+        }
+        let lifetime = (self.expires_at - self.flagged_at).max(1) as i128;
+        let remaining = (self.expires_at - now.max(self.flagged_at)) as i128;
+        (remaining * Self::FULL_WEIGHT_BPS as i128 / lifetime) as u16
+    }
+}
+
+/// Screening result returned by `check_attacker`, so integrators can CPI in
+/// without decoding AttackerFlag themselves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AttackerStatus {
+    /// Whether the address carries a live (unexpired) flag
+    pub flagged: bool,
+    /// Current weight of the flag in basis points; zero when not flagged
+    pub weight_bps: u16,
+    /// Reputation of the flag, see `AttackerFlag::refresh_score`; zero when
+    /// not flagged
+    pub score: u8,
+}
+
+/// Compact flag record returned by `is_flagged` for clients to simulate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FlagStatus {
+    /// Whether the address carries a live (unexpired) flag
+    pub flagged: bool,
+    /// Who filed the flag; default when no flag account exists
+    pub reported_by: Pubkey,
+    /// Timestamp when flagged; zero when no flag account exists
+    pub flagged_at: i64,
+}
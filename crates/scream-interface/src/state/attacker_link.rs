@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// An asserted link between two attacker addresses, one edge of the
+/// registry's cluster graph. Edges are undirected: `address_a` always sorts
+/// before `address_b`, so each pair has exactly one PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct AttackerLink {
+    pub address_a: Pubkey,
+    pub address_b: Pubkey,
+    pub kind: LinkKind,
+    /// Who asserted the link and paid its rent
+    pub reported_by: Pubkey,
+    /// Hash of the evidence, e.g. the funding transaction signature
+    pub evidence_hash: [u8; 32],
+    /// Timestamp when linked
+    pub linked_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AttackerLink {
+    pub const SEED_PREFIX: &'static [u8] = b"attacker_link";
+
+    /// The pair in seed order.
+    pub fn ordered(a: Pubkey, b: Pubkey) -> (Pubkey, Pubkey) {
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum LinkKind {
+    /// One address funded the other
+    Funding,
+    /// Both interacted with the same drainer contract
+    SharedContract,
+    /// Anything else, described by the evidence
+    Other,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// A community report against an attacker address, filed without a scream
+/// config. Holds the reporter's bond on top of its own rent; the bond is
+/// slashed to the attacker if a dispute clears the flag, and otherwise comes
+/// back through `reclaim_report` once the flag is upheld, expires or closes.
+#[account]
+#[derive(InitSpace)]
+pub struct AttackerReport {
+    pub attacker: Pubkey,
+    pub reporter: Pubkey,
+    /// Hash of the evidence backing the report
+    pub evidence_hash: [u8; 32],
+    /// Bond locked in this account
+    pub bond_lamports: u64,
+    /// Timestamp when reported
+    pub reported_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AttackerReport {
+    pub const SEED_PREFIX: &'static [u8] = b"report";
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Singleton pointing at the concurrent Merkle tree of the compressed
+/// attacker registry. The PDA is the tree's authority, so leaves only get in
+/// through `append_compressed_flag`.
+#[account]
+#[derive(InitSpace)]
+pub struct CompressedRegistry {
+    /// The spl-account-compression tree holding the flags
+    pub merkle_tree: Pubkey,
+    /// Leaves appended so far; the next leaf's index
+    pub leaves: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CompressedRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"compressed_registry";
+    /// Prefix of every leaf hash, keeping leaves apart from other trees'
+    pub const LEAF_DOMAIN: &'static [u8] = b"scream:flag-leaf";
+}
+
+/// A flag in the compressed registry. Only its hash is stored on-chain; the
+/// fields are in the `CompressedFlagAppended` event for indexers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CompressedFlagLeaf {
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub flagged_at: i64,
+    pub evidence_hash: [u8; 32],
+}
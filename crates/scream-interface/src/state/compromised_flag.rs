@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CompromisedFlag {
+    pub owner: Pubkey,
+    /// Timestamp when the wallet was flagged compromised
+    pub flagged_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Why the wallet was flagged, as reported by whoever triggered panic
+    pub reason: CompromiseReason,
+    /// The triggerer's estimate from 1 (low) to `MAX_SEVERITY`, when given
+    pub severity: Option<u8>,
+}
+
+impl CompromisedFlag {
+    pub const SEED_PREFIX: &'static [u8] = b"compromised";
+    pub const MAX_SEVERITY: u8 = 10;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CompromiseReason {
+    /// The owner is being forced in person, e.g. a wrench attack
+    PhysicalCoercion,
+    /// The owner was tricked into signing or giving up a key
+    Phishing,
+    /// Malware holds or drives one of the owner's signers
+    MalwareSigner,
+    Unknown,
+    /// A drill; nothing is actually wrong. Only `rehearse_panic` gives it,
+    /// and a real panic rejects it
+    Rehearsal,
+}
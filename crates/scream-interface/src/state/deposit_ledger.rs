@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Zero-copy ring buffer of vault inflows and outflows, one per vault
+#[account(zero_copy)]
+pub struct DepositLedger {
+    pub owner: Pubkey,
+    /// Slot the next entry is written to
+    pub head: u32,
+    /// Entries written so far, saturating at `CAPACITY`
+    pub len: u32,
+    pub entries: [LedgerEntry; DepositLedger::CAPACITY],
+}
+
+#[zero_copy]
+pub struct LedgerEntry {
+    /// Depositor, or the claim's recipient
+    pub who: Pubkey,
+    pub timestamp: i64,
+    /// Lamports in (positive) or out (negative)
+    pub amount: i64,
+}
+
+impl DepositLedger {
+    pub const SEED_PREFIX: &'static [u8] = b"deposit_ledger";
+    pub const CAPACITY: usize = 200;
+
+    /// Append an entry, overwriting the oldest once full.
+    pub fn push(&mut self, who: Pubkey, timestamp: i64, amount: i64) {
+        self.entries[self.head as usize] = LedgerEntry {
+            who,
+            timestamp,
+            amount,
+        };
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u32;
+        self.len = (self.len + 1).min(Self::CAPACITY as u32);
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Singleton settings for disputing attacker flags
+#[account]
+#[derive(InitSpace)]
+pub struct DisputeConfig {
+    /// Rules on disputes while they are inside the window
+    pub arbiter: Pubkey,
+    /// Lamports locked to open a dispute or file a community report
+    pub bond_lamports: u64,
+    /// Seconds the arbiter has to rule once a dispute is opened
+    pub dispute_window: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DisputeConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"dispute_config";
+}
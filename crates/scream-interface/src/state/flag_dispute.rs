@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// An open challenge to an AttackerFlag, holding the disputer's bond on top
+/// of its own rent
+#[account]
+#[derive(InitSpace)]
+pub struct FlagDispute {
+    /// The flagged address, which opened the dispute
+    pub attacker: Pubkey,
+    /// Bond locked in this account, returned or slashed on resolution
+    pub bond_lamports: u64,
+    /// Timestamp when the dispute was opened
+    pub opened_at: i64,
+    /// Last moment the arbiter can rule; afterwards the dispute lapses
+    pub deadline: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FlagDispute {
+    pub const SEED_PREFIX: &'static [u8] = b"dispute";
+}
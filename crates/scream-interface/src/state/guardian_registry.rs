@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Opt-in reverse index from a contact to the owners listing them, so a
+/// guardian can enumerate every config they're responsible for
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianRegistry {
+    pub contact: Pubkey,
+    /// Owners that list this contact and registered here
+    #[max_len(32)]
+    pub owners: Vec<Pubkey>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GuardianRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"guardian_registry";
+    pub const MAX_OWNERS: usize = 32;
+}
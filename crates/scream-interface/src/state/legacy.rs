@@ -0,0 +1,40 @@
+//! Account layouts that predate the `version` field, kept only so
+//! `migrate_account` and `migrate_alert` can read them. Nothing writes
+//! these anymore. Layouts are told apart by size, so a future version
+//! added here must differ in size from every other.
+
+use anchor_lang::prelude::*;
+
+/// PanicConfig as first deployed (version 0)
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PanicConfigV0 {
+    pub owner: Pubkey,
+    pub trigger_hash: [u8; 32],
+    #[max_len(5)]
+    pub contacts: Vec<Pubkey>,
+    pub recovery_threshold: u8,
+    pub time_lock_duration: i64,
+    pub decoy_lamports: u64,
+    pub is_triggered: bool,
+    pub bump: u8,
+}
+
+/// Vault as first deployed (version 0)
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct VaultV0 {
+    pub owner: Pubkey,
+    pub locked_until: i64,
+    pub recovery_initiated: bool,
+    pub approvals: u8,
+    pub bump: u8,
+}
+
+/// AlertAccount as first deployed (version 0), created when panic fired
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct AlertAccountV0 {
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub alerted_at: i64,
+    pub has_approved: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,43 @@
+pub mod panic_config;
+pub mod vault;
+pub mod compromised_flag;
+pub mod attacker_flag;
+pub mod alert_account;
+pub mod fee_reserve;
+pub mod dispute_config;
+pub mod flag_dispute;
+pub mod attacker_report;
+pub mod guardian_registry;
+pub mod protocol_stats;
+pub mod protocol_config;
+pub mod treasury;
+pub mod legacy;
+pub mod deposit_ledger;
+pub mod withdraw_request;
+pub mod withdraw_allowlist;
+pub mod panic_hooks;
+pub mod attacker_link;
+pub mod compressed_registry;
+pub mod program_info;
+
+pub use panic_config::*;
+pub use vault::*;
+pub use compromised_flag::*;
+pub use attacker_flag::*;
+pub use alert_account::*;
+pub use fee_reserve::*;
+pub use dispute_config::*;
+pub use flag_dispute::*;
+pub use attacker_report::*;
+pub use guardian_registry::*;
+pub use protocol_stats::*;
+pub use protocol_config::*;
+pub use treasury::*;
+pub use legacy::*;
+pub use deposit_ledger::*;
+pub use withdraw_request::*;
+pub use withdraw_allowlist::*;
+pub use panic_hooks::*;
+pub use attacker_link::*;
+pub use compressed_registry::*;
+pub use program_info::*;
@@ -0,0 +1,473 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PanicConfig {
+    pub owner: Pubkey,
+    /// SHA-256 hash of the duress trigger (e.g., PIN), or the Merkle root
+    /// of time-window codes when `trigger_mode` is `Totp`
+    pub trigger_hash: [u8; 32],
+    /// How trigger proofs are verified against `trigger_hash`
+    pub trigger_mode: TriggerMode,
+    /// Bumped whenever a trigger proof is accepted; signed trigger messages
+    /// include it, so a seen signature can't be replayed
+    pub trigger_nonce: u64,
+    /// Signature counter of the last accepted passkey assertion
+    pub passkey_sign_count: u32,
+    /// Emergency contacts who can approve recovery
+    #[max_len(5)]
+    pub contacts: Vec<Pubkey>,
+    /// Number of contacts required to approve recovery
+    pub recovery_threshold: u8,
+    /// Time-lock duration in seconds
+    pub time_lock_duration: i64,
+    /// Decoy amount in lamports to send to attacker
+    pub decoy_lamports: u64,
+    /// How the decoy amount is worked out at panic time
+    pub decoy_mode: DecoyMode,
+    /// Random spread applied to the decoy, +/- this many bps (0 = exact)
+    pub decoy_jitter_bps: u16,
+    /// Installments the decoy is paid out in (0 or 1 = all at once); the
+    /// first goes out with the panic, the rest via `release_decoy_drip`
+    pub decoy_drip_installments: u8,
+    /// Seconds between decoy installments
+    pub decoy_drip_interval: i64,
+    /// Mint of a token decoy sent from the vault's token account alongside
+    /// the SOL decoy (default = no token decoy)
+    pub decoy_token_mint: Pubkey,
+    /// Token decoy amount in base units
+    pub decoy_token_amount: u64,
+    /// Memo attached to the panic transaction alongside the decoy (empty = none)
+    #[max_len(64)]
+    pub decoy_memo: String,
+    /// Lamports left in the owner's wallet when panic sweeps it, for fees
+    /// and token account rent
+    pub balance_buffer: u64,
+    /// Off-chain instructions URI copied onto each alert at panic, with
+    /// `{contact}` replaced by the contact's address (empty = none)
+    #[max_len(112)]
+    pub alert_uri_template: String,
+    /// Contacts needed to veto a claim
+    pub veto_quorum: u8,
+    /// Seconds a veto pauses claims for
+    pub veto_duration: i64,
+    /// Lamports claimable in total before the second lock ends (0 = claims
+    /// aren't tiered)
+    pub early_claim_limit: u64,
+    /// Seconds after `locked_until` before the rest of the vault is claimable
+    pub second_lock_duration: i64,
+    /// Lamports from which a single claim counts as large (0 = no large
+    /// claim lock)
+    pub large_claim_threshold: u64,
+    /// Seconds after `locked_until` before a large claim can go out
+    pub large_claim_lock_duration: i64,
+    /// Mints whose tokens lock for their own duration instead of
+    /// `time_lock_duration`, which SOL and unlisted mints follow
+    #[max_len(4)]
+    pub token_time_locks: Vec<TokenTimeLock>,
+    /// Approvals that release the vault's hot tranche once the time-lock
+    /// ends (0 = no tranches; the whole vault follows `recovery_threshold`)
+    pub hot_threshold: u8,
+    /// Seconds after `locked_until` before the cold tranche is claimable,
+    /// and then only with `recovery_threshold` approvals
+    pub cold_lock_duration: i64,
+    /// Seconds a recovery round has to reach the threshold before it
+    /// expires and can be restarted (0 = rounds never expire)
+    pub recovery_window: i64,
+    /// Lamports paid from the fee reserve to whoever runs a crank for this
+    /// config (0 = no tip)
+    pub keeper_tip: u64,
+    /// Monitoring key that may trigger panic with the trigger proof, without
+    /// its own wallet being swept (default = none)
+    pub watcher: Pubkey,
+    /// Oracle that must co-sign a watcher's panic before it goes past a soft
+    /// lock of the vault (default = watcher panics need no attestation)
+    pub attestor: Pubkey,
+    /// Concurrent Merkle tree holding one alert leaf per contact, in place of
+    /// AlertAccounts (default = alerts are PDAs)
+    pub alert_tree: Pubkey,
+    /// Whether panic has been triggered
+    pub is_triggered: bool,
+    /// Bit `i` is set once `contacts[i]` has been alerted
+    pub alerted_contacts: u8,
+    /// Consecutive wrong proofs before trigger attempts are locked out (0 = off)
+    pub max_failed_attempts: u8,
+    /// Seconds trigger attempts stay locked out after too many failures
+    pub lockout_duration: i64,
+    /// Whether a lockout also extends the vault's `locked_until`
+    pub soft_lock_on_lockout: bool,
+    /// Wrong proofs since the last lockout
+    pub failed_attempts: u8,
+    /// Timestamp until which trigger attempts are rejected
+    pub trigger_locked_until: i64,
+    /// Nonce account a pre-signed durable-nonce trigger must advance
+    /// (default = durable-nonce triggers rejected)
+    pub presign_nonce_account: Pubkey,
+    /// Authority that must advance `presign_nonce_account`
+    pub presign_nonce_authority: Pubkey,
+    /// Where `finalize_claim` pushes recovered funds (default = crank disabled)
+    pub recovery_destination: Pubkey,
+    /// Cold key that initiates recovery and claims instead of the owner
+    /// once panic fires (default = the owner)
+    pub recovery_authority: Pubkey,
+    /// Key proposed to take over the config and vault (default = none)
+    pub pending_owner: Pubkey,
+    /// Further keys sharing the vault, e.g. a partner; any of them can
+    /// trigger panic
+    #[max_len(3)]
+    pub co_owners: Vec<Pubkey>,
+    /// Owners, the owner and co-owners alike, who must sign off before
+    /// funds can be claimed (0 = none beyond the claim signer)
+    pub owner_claim_quorum: u8,
+    /// The owner's other wallets: any of them can trigger panic, and a panic
+    /// flags them all compromised and sweeps those that sign it
+    #[max_len(3)]
+    pub linked_wallets: Vec<Pubkey>,
+    /// Token accounts of other wallets that approved the vault as delegate;
+    /// panic pulls their delegated balances into the vault
+    #[max_len(4)]
+    pub delegated_token_accounts: Vec<Pubkey>,
+    /// The owner's name for this config, e.g. "trading wallet", to tell
+    /// apart the wallets one entity protects (empty = none)
+    #[max_len(32)]
+    pub label: String,
+    /// Which of the owner's configs this is; part of the seeds of the
+    /// config, its vault and their per-config accounts
+    pub vault_index: u8,
+    /// The name the config is seeded by instead of its index, e.g.
+    /// "savings" (empty = seeded by `vault_index`). Unlike `label`, fixed
+    #[max_len(32)]
+    pub vault_name: String,
+    /// `vault_name_hash(vault_name)`, kept so seeds can borrow it (zeroes
+    /// when unnamed)
+    pub vault_name_hash: [u8; 32],
+    /// Layout version; `migrate_account` upgrades older ones
+    pub version: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// The seed `vault_index` adds to an owner's PDAs. Index 0 adds none, so
+/// the first config and everything under it keep the addresses they had
+/// before owners could run several.
+pub fn vault_index_seed(vault_index: &u8) -> &[u8] {
+    if *vault_index == 0 {
+        &[]
+    } else {
+        std::slice::from_ref(vault_index)
+    }
+}
+
+/// The seed a config named `name` adds to its owner's PDAs in place of an
+/// index.
+pub fn vault_name_hash(name: &str) -> [u8; 32] {
+    hashv(&[PanicConfig::VAULT_NAME_DOMAIN, name.as_bytes()]).to_bytes()
+}
+
+/// The seed an account's stored index and name hash add: the name's when
+/// it has one, the index's otherwise.
+pub fn vault_seed<'a>(vault_index: &'a u8, vault_name_hash: &'a [u8; 32]) -> &'a [u8] {
+    if *vault_name_hash == [0; 32] {
+        vault_index_seed(vault_index)
+    } else {
+        vault_name_hash
+    }
+}
+
+/// Which of an owner's configs a client means: by index, or by name.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VaultId {
+    Index(u8),
+    /// A `vault_name_hash`; build with `VaultId::named`
+    Name([u8; 32]),
+}
+
+impl VaultId {
+    /// The config every owner had before they could run several.
+    pub const FIRST: VaultId = VaultId::Index(0);
+
+    pub fn named(name: &str) -> Self {
+        Self::Name(vault_name_hash(name))
+    }
+
+    /// The config `initialize_config` creates from these arguments.
+    pub fn new(vault_index: u8, vault_name: &str) -> Self {
+        if vault_name.is_empty() {
+            Self::Index(vault_index)
+        } else {
+            Self::named(vault_name)
+        }
+    }
+
+    /// What this adds to the owner's PDA seeds.
+    pub fn seed(&self) -> &[u8] {
+        match self {
+            Self::Index(vault_index) => vault_index_seed(vault_index),
+            Self::Name(hash) => hash,
+        }
+    }
+}
+
+impl Default for VaultId {
+    fn default() -> Self {
+        Self::FIRST
+    }
+}
+
+impl PanicConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"panic_config";
+    /// Prefix hashed with a config's name into its seed
+    pub const VAULT_NAME_DOMAIN: &'static [u8] = b"scream:vault-name:";
+    /// Matches `max_len` on `vault_name`
+    pub const MAX_VAULT_NAME_LEN: usize = 32;
+    /// Version 1 is the layout as released; fields added before release
+    /// never shipped in between. Any later layout change must bump this and
+    /// keep the old layout in `legacy` for `migrate_account` to read.
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Room reserved for contacts (matches `max_len` on `contacts`); the
+    /// live limit is in ProtocolConfig
+    pub const MAX_CONTACTS: usize = 5;
+    /// Prefix of the message a duress key signs, followed by the config
+    /// address and `trigger_nonce`
+    pub const TRIGGER_SIGNATURE_DOMAIN: &'static [u8] = b"scream:trigger:";
+    pub const DEFAULT_MAX_FAILED_ATTEMPTS: u8 = 5;
+    pub const DEFAULT_LOCKOUT_DURATION: i64 = 3600;
+    pub const MAX_DECOY_DRIP_INSTALLMENTS: u8 = 20;
+    /// Ceiling on a fixed decoy; anything above reads as a typo, not a decoy
+    pub const MAX_DECOY_LAMPORTS: u64 = 100_000_000_000;
+    /// Matches `max_len` on `decoy_memo`
+    pub const MAX_DECOY_MEMO_LEN: usize = 64;
+    pub const DEFAULT_BALANCE_BUFFER: u64 = 10_000_000;
+    /// At least one transaction fee must stay behind
+    pub const MIN_BALANCE_BUFFER: u64 = 5_000;
+    pub const MAX_BALANCE_BUFFER: u64 = 1_000_000_000;
+    /// Matches `max_len` on `alert_uri_template`
+    pub const MAX_ALERT_URI_TEMPLATE_LEN: usize = 112;
+    pub const ALERT_URI_CONTACT_PLACEHOLDER: &'static str = "{contact}";
+    pub const DEFAULT_VETO_QUORUM: u8 = 1;
+    pub const DEFAULT_VETO_DURATION: i64 = 3 * 24 * 60 * 60;
+    pub const MAX_VETO_DURATION: i64 = 30 * 24 * 60 * 60;
+    pub const MAX_SECOND_LOCK_DURATION: i64 = 90 * 24 * 60 * 60;
+    pub const MAX_LARGE_CLAIM_LOCK_DURATION: i64 = 90 * 24 * 60 * 60;
+    pub const MAX_RECOVERY_WINDOW: i64 = 90 * 24 * 60 * 60;
+    pub const MAX_KEEPER_TIP: u64 = 1_000_000;
+    /// Matches `max_len` on `co_owners`
+    pub const MAX_CO_OWNERS: usize = 3;
+    /// Matches `max_len` on `linked_wallets`
+    pub const MAX_LINKED_WALLETS: usize = 3;
+    /// Matches `max_len` on `delegated_token_accounts`
+    pub const MAX_DELEGATED_TOKEN_ACCOUNTS: usize = 4;
+    pub const MAX_COLD_LOCK_DURATION: i64 = 90 * 24 * 60 * 60;
+    /// Matches `max_len` on `token_time_locks`
+    pub const MAX_TOKEN_TIME_LOCKS: usize = 4;
+    /// Matches `max_len` on `label`
+    pub const MAX_LABEL_LEN: usize = 32;
+
+    /// Who must sign `initiate_recovery` and claims.
+    pub fn recovery_signer(&self) -> Pubkey {
+        if self.recovery_authority == Pubkey::default() {
+            self.owner
+        } else {
+            self.recovery_authority
+        }
+    }
+
+    /// Position of `key` among the owners: 0 for the owner, `i + 1` for
+    /// `co_owners[i]`.
+    pub fn owner_index(&self, key: &Pubkey) -> Option<usize> {
+        if *key == self.owner {
+            return Some(0);
+        }
+        self.co_owners.iter().position(|k| k == key).map(|i| i + 1)
+    }
+
+    /// Whether `key` may sign `trigger_panic`: an owner or a linked wallet.
+    pub fn can_trigger(&self, key: &Pubkey) -> bool {
+        self.owner_index(key).is_some() || self.linked_wallets.contains(key)
+    }
+
+    /// Whether `key` is the configured watcher.
+    pub fn is_watcher(&self, key: &Pubkey) -> bool {
+        self.watcher != Pubkey::default() && *key == self.watcher
+    }
+
+    /// Seed of this config's name or index; see `vault_seed`.
+    pub fn vault_seed(&self) -> &[u8] {
+        vault_seed(&self.vault_index, &self.vault_name_hash)
+    }
+
+    pub fn vault_id(&self) -> VaultId {
+        if self.vault_name.is_empty() {
+            VaultId::Index(self.vault_index)
+        } else {
+            VaultId::Name(self.vault_name_hash)
+        }
+    }
+
+    /// Fewest approvals any SOL claim can go out with: `hot_threshold`
+    /// when tranches are on.
+    pub fn claim_threshold(&self) -> u8 {
+        if self.hot_threshold == 0 {
+            self.recovery_threshold
+        } else {
+            self.hot_threshold.min(self.recovery_threshold)
+        }
+    }
+
+    /// Time-lock duration for tokens of `mint`.
+    pub fn token_time_lock(&self, mint: &Pubkey) -> i64 {
+        self.token_time_locks
+            .iter()
+            .find(|lock| lock.mint == *mint)
+            .map_or(self.time_lock_duration, |lock| lock.duration)
+    }
+
+    /// The alert URI for `contact`, filled in from the template.
+    pub fn alert_uri(&self, contact: &Pubkey) -> String {
+        self.alert_uri_template
+            .replace(Self::ALERT_URI_CONTACT_PLACEHOLDER, &contact.to_string())
+    }
+}
+
+/// Pre-flight report returned by `simulate_panic`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PanicPreflight {
+    /// The trigger proof checks out
+    pub proof_valid: bool,
+    /// Panic hasn't fired yet and triggers aren't locked out
+    pub triggerable: bool,
+    /// The vault plus the swept wallet can pay at least a one-lamport decoy
+    pub decoy_affordable: bool,
+    /// Decoy lamports the panic would aim for, before jitter
+    pub decoy_lamports: u64,
+    /// Every passed account is the alert PDA of a distinct contact
+    pub contacts_valid: bool,
+    /// All of the above hold
+    pub would_succeed: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct TokenTimeLock {
+    pub mint: Pubkey,
+    /// Seconds the mint's tokens stay locked after panic
+    pub duration: i64,
+}
+
+/// `PanicConfig` as returned by `get_config`, plus what a wallet would
+/// otherwise work out from the vault and clock. Fields are the config's,
+/// less `trigger_hash`, the strings and `token_time_locks`, which would push
+/// it past the 1 KiB return data limit; read those from the account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigView {
+    pub owner: Pubkey,
+    pub trigger_mode: TriggerMode,
+    pub contacts: Vec<Pubkey>,
+    pub recovery_threshold: u8,
+    pub time_lock_duration: i64,
+    pub decoy_lamports: u64,
+    pub decoy_mode: DecoyMode,
+    pub decoy_jitter_bps: u16,
+    pub decoy_drip_installments: u8,
+    pub decoy_drip_interval: i64,
+    pub decoy_token_mint: Pubkey,
+    pub decoy_token_amount: u64,
+    pub balance_buffer: u64,
+    pub veto_quorum: u8,
+    pub veto_duration: i64,
+    pub early_claim_limit: u64,
+    pub second_lock_duration: i64,
+    pub large_claim_threshold: u64,
+    pub large_claim_lock_duration: i64,
+    pub hot_threshold: u8,
+    pub cold_lock_duration: i64,
+    pub recovery_window: i64,
+    pub keeper_tip: u64,
+    pub watcher: Pubkey,
+    pub attestor: Pubkey,
+    pub alert_tree: Pubkey,
+    pub is_triggered: bool,
+    pub alerted_contacts: u8,
+    pub max_failed_attempts: u8,
+    pub lockout_duration: i64,
+    pub soft_lock_on_lockout: bool,
+    pub failed_attempts: u8,
+    pub trigger_locked_until: i64,
+    pub presign_nonce_account: Pubkey,
+    pub presign_nonce_authority: Pubkey,
+    pub recovery_destination: Pubkey,
+    pub recovery_authority: Pubkey,
+    pub pending_owner: Pubkey,
+    pub co_owners: Vec<Pubkey>,
+    pub owner_claim_quorum: u8,
+    pub linked_wallets: Vec<Pubkey>,
+    pub delegated_token_accounts: Vec<Pubkey>,
+    pub vault_index: u8,
+    pub version: u8,
+    /// Lamports above the vault's rent-exempt minimum
+    pub claimable_lamports: u64,
+    /// Seconds until the vault's time-lock ends (0 = not locked)
+    pub time_lock_remaining: i64,
+    /// Seconds until trigger attempts are accepted again (0 = not locked out)
+    pub trigger_lockout_remaining: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum TriggerMode {
+    /// Proof is the raw trigger secret; `sha256(proof) == trigger_hash`.
+    Preimage,
+    /// Proof is `code || siblings`, where `code = sha256(secret || window)`
+    /// for the current clock window and `siblings` is the Merkle path of
+    /// `sha256(code)` at leaf index `window` up to `trigger_hash`.
+    /// `window = (now - start) / period`.
+    Totp { start: i64, period: u32 },
+    /// Proof is an Ed25519 precompile instruction in the same transaction in
+    /// which `duress_key` signs
+    /// `TRIGGER_SIGNATURE_DOMAIN || config address || u64 LE trigger_nonce`.
+    /// `trigger_hash` is unused.
+    Ed25519 { duress_key: Pubkey },
+    /// Proof is a WebAuthn assertion from a passkey / security key:
+    /// `u16 LE auth_data_len || authenticator_data || client_data_json`.
+    /// A secp256r1 precompile instruction in the same transaction must verify
+    /// `pubkey` (compressed SEC1) over `authenticator_data || sha256(client_data_json)`,
+    /// and the client data challenge must be `base64url(sha256(m))` for the
+    /// Ed25519 mode's message `m`, which includes `trigger_nonce`.
+    /// The authenticator's signature counter must exceed
+    /// `passkey_sign_count`, unless both are 0 (an authenticator without one).
+    /// `trigger_hash` is unused.
+    Secp256r1 { pubkey: [u8; 33] },
+    /// Proof is `threshold` Shamir shares of the trigger secret over GF(2^8),
+    /// each encoded as `x || y` (1 + secret length bytes, `x` non-zero and
+    /// distinct). The secret is reconstructed on-chain and
+    /// `sha256(secret) == trigger_hash`.
+    Shamir { threshold: u8 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DecoyMode {
+    /// Send `decoy_lamports`.
+    Fixed,
+    /// Send `bps` of the owner's wallet balance before the panic sweep,
+    /// clamped to `[min_lamports, max_lamports]`, so the decoy scales like a
+    /// plausible partial drain. `decoy_lamports` is unused.
+    Percentage {
+        bps: u16,
+        min_lamports: u64,
+        max_lamports: u64,
+    },
+}
+
+impl DecoyMode {
+    /// Lamports the decoy aims for, before capping at what the vault holds.
+    pub fn target(&self, decoy_lamports: u64, owner_balance: u64) -> u64 {
+        match *self {
+            DecoyMode::Fixed => decoy_lamports,
+            DecoyMode::Percentage {
+                bps,
+                min_lamports,
+                max_lamports,
+            } => ((owner_balance as u128 * bps as u128 / 10_000) as u64)
+                .clamp(min_lamports, max_lamports),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// External programs `trigger_panic` calls once panic fires, so protocols
+/// can unwind the owner's positions
+#[account]
+#[derive(InitSpace)]
+pub struct PanicHooks {
+    pub owner: Pubkey,
+    #[max_len(4)]
+    pub hooks: Vec<PanicHook>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PanicHook {
+    pub program: Pubkey,
+    /// Extra accounts the hook expects after the fixed ones
+    pub account_count: u8,
+}
+
+impl PanicHooks {
+    pub const SEED_PREFIX: &'static [u8] = b"panic_hooks";
+    pub const MAX_HOOKS: usize = 4;
+    /// Instruction data prefix of the hook call, `sha256("global:on_scream_panic")[..8]`,
+    /// followed by the owner and primary attacker pubkeys. Accounts are the
+    /// owner's PanicConfig, the owner, then the hook's extra accounts, none
+    /// of them signers.
+    pub const ON_PANIC_DISCRIMINATOR: [u8; 8] = [126, 71, 98, 90, 124, 132, 164, 83];
+
+    /// Extra accounts every hook takes, each hook's program included.
+    pub fn accounts_len(&self) -> usize {
+        self.hooks
+            .iter()
+            .map(|hook| 1 + hook.account_count as usize)
+            .sum()
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Singleton describing this deployment, written by the admin, so explorers
+/// and integrators can check which build and audit they're talking to. The
+/// binary's embedded security.txt carries the same contact.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramInfo {
+    /// Release the deployed binary was built from, e.g. `0.1.0`
+    #[max_len(32)]
+    pub version: String,
+    /// Hash of the audit report covering this release (zeroed = unaudited)
+    pub audit_hash: [u8; 32],
+    /// Where to report vulnerabilities, e.g. an email or URL
+    #[max_len(96)]
+    pub contact: String,
+    /// When the admin last wrote this account
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProgramInfo {
+    pub const SEED_PREFIX: &'static [u8] = b"program_info";
+    /// Matches `max_len` on `version`
+    pub const MAX_VERSION_LEN: usize = 32;
+    /// Matches `max_len` on `contact`
+    pub const MAX_CONTACT_LEN: usize = 96;
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Singleton protocol parameters, set by the admin and checked by handlers
+/// in place of hard-coded limits
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    /// May update these parameters and hand the role on
+    pub admin: Pubkey,
+    /// Most contacts a config may list; at most `PanicConfig::MAX_CONTACTS`
+    pub max_contacts: u8,
+    /// Shortest allowed panic time-lock, in seconds
+    pub min_time_lock: i64,
+    /// Longest allowed panic time-lock, in seconds
+    pub max_time_lock: i64,
+    /// Fee on recovered funds in basis points, paid to the treasury; at most
+    /// `MAX_FEE_BPS`
+    pub fee_bps: u16,
+    /// Halts new configs and deposits during an incident; panics, recovery
+    /// and claims are never blocked
+    pub paused: bool,
+    /// Paid from the treasury to a reporter once their flag is corroborated;
+    /// 0 = no bounties. At most `MAX_REPORT_BOUNTY`
+    pub report_bounty: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"protocol_config";
+    /// Hard ceiling on `fee_bps` (10%)
+    pub const MAX_FEE_BPS: u16 = 1_000;
+    /// Hard ceiling on `report_bounty` (0.1 SOL)
+    pub const MAX_REPORT_BOUNTY: u64 = 100_000_000;
+}
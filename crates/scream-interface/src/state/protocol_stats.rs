@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Singleton running totals across every config, for dashboards
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolStats {
+    pub total_configs: u64,
+    pub total_panics: u64,
+    /// Lamports paid out of vaults by claims
+    pub total_lamports_recovered: u64,
+    /// Attacker flags created, by panics and community reports
+    pub total_attackers_flagged: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProtocolStats {
+    pub const SEED_PREFIX: &'static [u8] = b"protocol_stats";
+}
@@ -0,0 +1,8 @@
+/// System-owned PDA collecting protocol fees on recovered funds, spendable
+/// only by the ProtocolConfig admin. It carries no data; only its seeds are
+/// defined here.
+pub struct Treasury;
+
+impl Treasury {
+    pub const SEED_PREFIX: &'static [u8] = b"treasury";
+}
@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use super::panic_config::vault_seed;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub owner: Pubkey,
+    /// Timestamp when funds can be recovered (0 if not locked)
+    pub locked_until: i64,
+    /// Whether recovery has been initiated
+    pub recovery_initiated: bool,
+    /// Number of approvals received so far
+    pub approvals: u8,
+    /// Bumped each time a recovery starts; approvals only count toward the
+    /// round they were given in
+    pub recovery_round: u32,
+    /// When the current recovery round started
+    pub recovery_initiated_at: i64,
+    /// Rules for routine (non-recovery) withdrawals
+    pub withdraw_policy: WithdrawPolicy,
+    /// Looser policy queued by `set_withdraw_policy`
+    pub pending_policy: WithdrawPolicy,
+    /// When `pending_policy` replaces the active one (0 if nothing is queued)
+    pub pending_policy_at: i64,
+    /// Start of the current `vault_transfer` spending period
+    pub spend_period_start: i64,
+    /// Lamports sent by `vault_transfer` since `spend_period_start`
+    pub spent_in_period: u64,
+    /// Routine withdrawals counted against the daily cap as of
+    /// `window_updated_at`; they leak out linearly over `WITHDRAW_WINDOW`
+    pub withdrawn_in_window: u64,
+    pub window_updated_at: i64,
+    /// Attacker the rest of a dripped decoy is owed to
+    pub drip_recipient: Pubkey,
+    /// Decoy lamports still to be dripped
+    pub drip_remaining: u64,
+    /// Installments left in the drip
+    pub drip_installments_left: u8,
+    /// Earliest time the next installment can be released
+    pub drip_next_at: i64,
+    /// Bit `i` is set once `contacts[i]` has vetoed the current recovery
+    pub veto_votes: u8,
+    /// Claims are refused until this time after a veto (0 = not paused)
+    pub claim_paused_until: i64,
+    /// Bit `i` is set once `contacts[i]` has voted for the pending extension
+    pub extension_votes: u8,
+    /// Total seconds guardians have added to `locked_until`
+    pub lock_extended_by: i64,
+    /// Bit `i` is set once `contacts[i]` has voted to fast-track recovery
+    pub fast_track_votes: u8,
+    /// Lamports claimed so far, counted against `early_claim_limit`
+    pub early_claimed: u64,
+    /// Lamports in the hot tranche, released by `PanicConfig::hot_threshold`
+    /// approvals once the time-lock ends; the rest of the vault is cold
+    pub hot_lamports: u64,
+    /// When the last claim went out (0 = none yet)
+    pub last_claim_at: i64,
+    /// Bit `i` is set once the owner at `PanicConfig::owner_index` `i` has
+    /// signed off on claims
+    pub owner_claim_votes: u8,
+    /// Stake pool holding vault SOL (default = nothing staked)
+    pub stake_pool: Pubkey,
+    /// Lamports staked into `stake_pool`, at cost
+    pub staked_lamports: u64,
+    /// Pool tokens held in the vault's associated token account
+    pub staked_pool_tokens: u64,
+    /// Lending reserve supplied with vault SOL (default = nothing lent)
+    pub lending_reserve: Pubkey,
+    /// Lamports supplied to `lending_reserve`, at cost
+    pub lent_lamports: u64,
+    /// Reserve collateral tokens held in the vault's associated token account
+    pub lending_collateral: u64,
+    /// Whether the owner opted in to a deposit ledger, which deposits,
+    /// claims and withdrawals must then append to
+    pub has_deposit_ledger: bool,
+    /// Index of the config this vault belongs to
+    pub vault_index: u8,
+    /// Name hash of the config this vault belongs to (zeroes = unnamed)
+    pub vault_name_hash: [u8; 32],
+    /// Layout version; `migrate_account` upgrades older ones
+    pub version: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const SEED_PREFIX: &'static [u8] = b"vault";
+    /// See `PanicConfig::CURRENT_VERSION`.
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Span of the rolling daily withdrawal cap
+    pub const WITHDRAW_WINDOW: i64 = 24 * 60 * 60;
+    /// Seconds one guardian extension adds to the time-lock
+    pub const LOCK_EXTENSION: i64 = 3 * 24 * 60 * 60;
+    /// Cap on guardian extensions over the vault's life
+    pub const MAX_LOCK_EXTENSION: i64 = 30 * 24 * 60 * 60;
+    /// Minimum gap between two claims
+    pub const CLAIM_COOLDOWN: i64 = 60 * 60;
+    /// Seed of the system account that hands staked lamports to the pool
+    pub const STAKE_FUNDER_SEED: &'static [u8] = b"stake_funder";
+    /// Most of the vault, lent funds included, that may be out on loan
+    pub const MAX_LENDING_BPS: u64 = 5_000;
+
+    /// Seed of this vault's config's name or index; see `vault_seed`.
+    pub fn vault_seed(&self) -> &[u8] {
+        vault_seed(&self.vault_index, &self.vault_name_hash)
+    }
+
+    /// Whether the current recovery round ran past `recovery_window`
+    /// without reaching the threshold.
+    pub fn recovery_round_expired(&self, recovery_window: i64, threshold: u8, now: i64) -> bool {
+        self.recovery_initiated
+            && recovery_window > 0
+            && self.approvals < threshold
+            && now >= self.recovery_initiated_at + recovery_window
+    }
+
+    /// Open a new recovery round. Vetoes, the pause they caused and
+    /// fast-track votes belong to a round, so they start over.
+    pub fn start_recovery_round(&mut self, now: i64) {
+        self.recovery_initiated = true;
+        self.recovery_round += 1;
+        self.recovery_initiated_at = now;
+        self.veto_votes = 0;
+        self.claim_paused_until = 0;
+        self.fast_track_votes = 0;
+    }
+
+    /// Count a claim of `amount` against the early limit and the hot
+    /// tranche, which pays out first.
+    pub fn record_claim(&mut self, amount: u64, now: i64) {
+        self.early_claimed += amount;
+        self.hot_lamports = self.hot_lamports.saturating_sub(amount);
+        self.last_claim_at = now;
+    }
+
+    /// Swap in the queued policy once its delay has passed.
+    pub fn settle_policy(&mut self, now: i64) {
+        if self.pending_policy_at != 0 && now >= self.pending_policy_at {
+            self.withdraw_policy = self.pending_policy;
+            self.pending_policy_at = 0;
+        }
+    }
+
+    /// Start a fresh spending period if the current one has run out.
+    pub fn roll_spend_period(&mut self, now: i64) {
+        if now >= self.spend_period_start + self.withdraw_policy.spend_period {
+            self.spend_period_start = now;
+            self.spent_in_period = 0;
+        }
+    }
+
+    /// Withdrawals still counting against the daily cap at `now`.
+    pub fn withdrawn_in_window_at(&self, now: i64) -> u64 {
+        let elapsed = (now - self.window_updated_at).clamp(0, Self::WITHDRAW_WINDOW);
+        let remaining = (Self::WITHDRAW_WINDOW - elapsed) as u128;
+        (self.withdrawn_in_window as u128 * remaining / Self::WITHDRAW_WINDOW as u128) as u64
+    }
+
+    /// Count `amount` against the daily cap, or return false if it would
+    /// push the window over `daily_withdraw_limit`.
+    pub fn record_withdrawal(&mut self, amount: u64, now: i64) -> bool {
+        let total = self.withdrawn_in_window_at(now).saturating_add(amount);
+        if total > self.withdraw_policy.daily_withdraw_limit {
+            return false;
+        }
+        self.withdrawn_in_window = total;
+        self.window_updated_at = now;
+        true
+    }
+}
+
+/// Limits on withdrawals made outside of panic recovery. Tightening applies
+/// at once; loosening waits out the current `withdraw_delay` so a stolen key
+/// can't lift the limits and drain the vault in one go.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WithdrawPolicy {
+    /// Seconds between `request_withdraw` and `execute_withdraw`
+    pub withdraw_delay: i64,
+    /// Most `vault_transfer` may send per period (0 disables it)
+    pub spend_limit: u64,
+    /// Length of a spending period in seconds
+    pub spend_period: i64,
+    /// Only send to destinations on the owner's `WithdrawAllowlist`
+    pub require_allowlist: bool,
+    /// Most routine withdrawals may move in any rolling day
+    /// (`u64::MAX` for no cap)
+    pub daily_withdraw_limit: u64,
+}
+
+impl WithdrawPolicy {
+    pub const DEFAULT_WITHDRAW_DELAY: i64 = 24 * 60 * 60;
+    pub const DEFAULT_SPEND_PERIOD: i64 = 24 * 60 * 60;
+
+    /// Whether `self` is at least as restrictive as `other` everywhere.
+    pub fn is_at_least_as_strict_as(&self, other: &Self) -> bool {
+        self.withdraw_delay >= other.withdraw_delay
+            && self.spend_limit <= other.spend_limit
+            && self.spend_period >= other.spend_period
+            && self.require_allowlist >= other.require_allowlist
+            && self.daily_withdraw_limit <= other.daily_withdraw_limit
+    }
+}
+
+impl Default for WithdrawPolicy {
+    fn default() -> Self {
+        Self {
+            withdraw_delay: Self::DEFAULT_WITHDRAW_DELAY,
+            spend_limit: 0,
+            spend_period: Self::DEFAULT_SPEND_PERIOD,
+            require_allowlist: false,
+            daily_withdraw_limit: u64::MAX,
+        }
+    }
+}
+
+/// Vault summary returned by `vault_status`, with the rent math done
+/// on-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VaultStatus {
+    pub locked_until: i64,
+    pub recovery_initiated: bool,
+    pub approvals: u8,
+    /// Approvals required to claim
+    pub threshold: u8,
+    /// Lamports above the vault's rent-exempt minimum
+    pub claimable_lamports: u64,
+    /// Lamports staked into a pool at cost; claimable once unstaked
+    pub staked_lamports: u64,
+    /// Lamports supplied to a lending reserve at cost; claimable once redeemed
+    pub lent_lamports: u64,
+    /// Lamports in the hot tranche; see `Vault::hot_lamports`
+    pub hot_lamports: u64,
+}
+
+/// How much of one mint the vault's token accounts hold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TokenBalance {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
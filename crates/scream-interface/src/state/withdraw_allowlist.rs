@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Destinations routine withdrawals may go to once the vault's policy
+/// requires it
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawAllowlist {
+    pub owner: Pubkey,
+    #[max_len(8)]
+    pub entries: Vec<AllowlistEntry>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AllowlistEntry {
+    pub destination: Pubkey,
+    /// When the destination becomes usable; adds wait out the withdraw delay
+    pub active_at: i64,
+}
+
+impl WithdrawAllowlist {
+    pub const SEED_PREFIX: &'static [u8] = b"withdraw_allowlist";
+    pub const MAX_ENTRIES: usize = 8;
+
+    /// Whether `destination` is listed and past its add delay.
+    pub fn allows(&self, destination: &Pubkey, now: i64) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.destination == *destination && now >= entry.active_at)
+    }
+}
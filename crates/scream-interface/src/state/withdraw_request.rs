@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// A routine withdrawal waiting out the vault's withdraw delay
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawRequest {
+    pub owner: Pubkey,
+    /// Where `execute_withdraw` sends the lamports
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub requested_at: i64,
+    /// Earliest time `execute_withdraw` may run
+    pub executable_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl WithdrawRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"withdraw_request";
+}
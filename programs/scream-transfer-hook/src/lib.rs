@@ -0,0 +1,122 @@
+//! Token-2022 transfer hook for mints that want to be un-drainable after a
+//! SCREAM panic: any transfer out of a token account whose owner has a
+//! `CompromisedFlag` is rejected.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use scream_interface::state::CompromisedFlag;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("FxD1cgAk3DV6uuYpxN5ceCFXqPNLTBVnppYwB4YNCEhS");
+
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Extra accounts Token-2022 resolves for every transfer: the SCREAM
+/// program, then the source owner's compromised flag PDA under it.
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&scream_interface::ID, false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal {
+                    bytes: CompromisedFlag::SEED_PREFIX.to_vec(),
+                },
+                // The source token account's owner
+                Seed::AccountData {
+                    account_index: 0,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+#[program]
+pub mod scream_transfer_hook {
+    use super::*;
+
+    /// Write the mint's extra account list. Permissionless, since the list
+    /// is the same for every mint.
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let metas = extra_account_metas()?;
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+        Ok(())
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        require!(
+            *ctx.accounts.compromised_flag.owner != scream_interface::ID,
+            TransferHookError::SourceCompromised
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Sized and written as a TLV extra account list by the handler
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts in the order the transfer hook interface fixes them.
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The transfer authority, owner or delegate
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: The mint's extra account list
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Only its key is used, as the flag PDA's program
+    #[account(address = scream_interface::ID)]
+    pub scream_program: UncheckedAccount<'info>,
+
+    /// CHECK: The source owner's flag PDA, which need not exist
+    #[account(
+        seeds = [CompromisedFlag::SEED_PREFIX, source_token.owner.as_ref()],
+        bump,
+        seeds::program = scream_interface::ID,
+    )]
+    pub compromised_flag: UncheckedAccount<'info>,
+}
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("Source wallet has been flagged as compromised")]
+    SourceCompromised,
+}
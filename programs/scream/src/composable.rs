@@ -0,0 +1,149 @@
+//! Helpers for programs that call SCREAM through CPI, such as smart wallets
+//! that deposit or panic on a user's behalf and guards that refuse to act
+//! for a compromised owner or toward a flagged attacker. They wrap the
+//! `cpi` module Anchor generates, so callers don't copy account layouts.
+
+use anchor_lang::prelude::*;
+use crate::cpi;
+use crate::state::{CompromiseReason, FlagStatus};
+
+pub use crate::cpi::accounts::{ApproveRecovery, DepositFor, TriggerPanic};
+
+/// The accounts of a panic that sends no token decoy, runs no hooks and
+/// needs no attestor. Use `TriggerPanic` directly for those.
+pub struct PanicAccounts<'info> {
+    pub authority: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub panic_config: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub compromised_flag: AccountInfo<'info>,
+    pub attacker: AccountInfo<'info>,
+    pub attacker_flag: AccountInfo<'info>,
+    pub fee_reserve: AccountInfo<'info>,
+    pub protocol_stats: AccountInfo<'info>,
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub slot_hashes: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    #[cfg(feature = "event-cpi")]
+    pub event_authority: AccountInfo<'info>,
+    #[cfg(feature = "event-cpi")]
+    pub program: AccountInfo<'info>,
+}
+
+impl<'info> From<PanicAccounts<'info>> for TriggerPanic<'info> {
+    fn from(accounts: PanicAccounts<'info>) -> Self {
+        Self {
+            authority: accounts.authority,
+            owner: accounts.owner,
+            payer: accounts.payer,
+            panic_config: accounts.panic_config,
+            vault: accounts.vault,
+            compromised_flag: accounts.compromised_flag,
+            attacker: accounts.attacker,
+            attacker_flag: accounts.attacker_flag,
+            fee_reserve: accounts.fee_reserve,
+            protocol_stats: accounts.protocol_stats,
+            instructions_sysvar: accounts.instructions_sysvar,
+            slot_hashes: accounts.slot_hashes,
+            decoy_token_mint: None,
+            vault_decoy_token: None,
+            attacker_decoy_token: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            panic_hooks: None,
+            attestor: None,
+            system_program: accounts.system_program,
+            #[cfg(feature = "event-cpi")]
+            event_authority: accounts.event_authority,
+            #[cfg(feature = "event-cpi")]
+            program: accounts.program,
+        }
+    }
+}
+
+/// Fund `owner`'s vault from `depositor`. `signer_seeds` sign for a
+/// depositor that is a PDA of the calling program; pass `&[]` otherwise.
+pub fn deposit_for<'info>(
+    scream_program: AccountInfo<'info>,
+    accounts: DepositFor<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    cpi::deposit_for(
+        CpiContext::new_with_signer(scream_program, accounts, signer_seeds),
+        amount,
+    )
+}
+
+/// Panic on the owner's behalf, with `signer_seeds` signing for an
+/// authority that is a PDA of the calling program. `remaining_accounts`
+/// are the alert accounts, then whatever linked wallets, delegated token
+/// accounts and hooks the config calls for, in `trigger_panic`'s order.
+#[allow(clippy::too_many_arguments)]
+pub fn trigger_panic<'info>(
+    scream_program: AccountInfo<'info>,
+    accounts: impl Into<TriggerPanic<'info>>,
+    remaining_accounts: Vec<AccountInfo<'info>>,
+    signer_seeds: &[&[&[u8]]],
+    trigger_proof: Vec<u8>,
+    evidence_hash: [u8; 32],
+    reason: CompromiseReason,
+    severity: Option<u8>,
+) -> Result<()> {
+    cpi::trigger_panic(
+        CpiContext::new_with_signer(scream_program, accounts.into(), signer_seeds)
+            .with_remaining_accounts(remaining_accounts),
+        trigger_proof,
+        false,
+        vec![],
+        evidence_hash,
+        reason,
+        severity,
+    )
+}
+
+/// Approve recovery as a contact that is a PDA of the calling program.
+pub fn approve_recovery<'info>(
+    scream_program: AccountInfo<'info>,
+    accounts: ApproveRecovery<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    cpi::approve_recovery(CpiContext::new_with_signer(
+        scream_program,
+        accounts,
+        signer_seeds,
+    ))
+}
+
+/// Fail the calling instruction when `owner` has been flagged compromised.
+pub fn assert_not_compromised<'info>(
+    scream_program: AccountInfo<'info>,
+    compromised_flag: AccountInfo<'info>,
+    owner: Pubkey,
+) -> Result<()> {
+    cpi::assert_not_compromised(
+        CpiContext::new(
+            scream_program,
+            cpi::accounts::AssertNotCompromised { compromised_flag },
+        ),
+        owner,
+    )
+}
+
+/// Whether `attacker` is in the registry, for screening a counterparty.
+pub fn is_flagged<'info>(
+    scream_program: AccountInfo<'info>,
+    attacker: AccountInfo<'info>,
+    attacker_flag: AccountInfo<'info>,
+) -> Result<FlagStatus> {
+    let status = cpi::is_flagged(CpiContext::new(
+        scream_program,
+        cpi::accounts::IsFlagged {
+            attacker,
+            attacker_flag,
+        },
+    ))?;
+    Ok(status.get())
+}
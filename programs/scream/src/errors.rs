@@ -28,4 +28,236 @@ pub enum ScreamError {
     InsufficientFundsForDecoy,
     #[msg("Number of remaining accounts does not match number of contacts")]
     ContactAccountMismatch,
+    #[msg("Simulation complete: all checks passed, no funds were moved")]
+    SimulationOnly,
+    #[msg("TOTP trigger period must be greater than zero")]
+    InvalidTotpPeriod,
+    #[msg("TOTP trigger window has not started yet")]
+    TotpWindowNotStarted,
+    #[msg("Config has too many contacts for the composite panic instruction (max 2)")]
+    ConfigTooLargeForComposite,
+    #[msg("Token accounts must be passed as (owner, vault) pairs, at most 3")]
+    TokenAccountMismatch,
+    #[msg("Token account owner or mint does not match")]
+    InvalidTokenAccount,
+    #[msg("Shamir trigger threshold must be at least 2")]
+    InvalidShamirThreshold,
+    #[msg("Too many failed trigger attempts; try again after the lockout")]
+    TriggerLockedOut,
+    #[msg("Lockout duration must not be negative")]
+    InvalidLockoutDuration,
+    #[msg("Durable-nonce trigger does not use the registered nonce account and authority")]
+    UnregisteredDurableNonce,
+    #[msg("No valid Ed25519 approval signature from the contact in this transaction")]
+    MissingApprovalSignature,
+    #[msg("No recovery destination has been set for this config")]
+    NoRecoveryDestination,
+    #[msg("This contact's alert has not fired yet")]
+    AlertNotActive,
+    #[msg("Too many attacker addresses for one panic")]
+    TooManyAttackers,
+    #[msg("Extra attacker accounts don't match the attacker list")]
+    AttackerAccountMismatch,
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("Dispute window must be positive")]
+    InvalidDisputeWindow,
+    #[msg("Account is not a report against this attacker")]
+    InvalidReportAccount,
+    #[msg("Attacker flag has not expired")]
+    FlagNotExpired,
+    #[msg("A dispute against this flag is still pending")]
+    DisputePending,
+    #[msg("Guardian registry is full")]
+    GuardianRegistryFull,
+    #[msg("Time-lock duration is outside the protocol's allowed range")]
+    InvalidTimeLock,
+    #[msg("Fee exceeds the protocol maximum")]
+    FeeTooHigh,
+    #[msg("Treasury balance is too low for this withdrawal")]
+    InsufficientTreasuryBalance,
+    #[msg("Protocol is paused; new configs and deposits are disabled")]
+    ProtocolPaused,
+    #[msg("Account layout is not a version this program can migrate")]
+    UnsupportedAccountVersion,
+    #[msg("Withdraw delay must not be negative")]
+    InvalidWithdrawDelay,
+    #[msg("Withdrawal amount must be positive")]
+    InvalidWithdrawAmount,
+    #[msg("Vault doesn't hold enough lamports above rent for this withdrawal")]
+    InsufficientVaultBalance,
+    #[msg("Withdraw delay has not passed yet")]
+    WithdrawDelayActive,
+    #[msg("Spending period must be positive")]
+    InvalidSpendPeriod,
+    #[msg("Transfer would exceed the vault's spending limit for this period")]
+    SpendLimitExceeded,
+    #[msg("Withdrawal allowlist is full")]
+    AllowlistFull,
+    #[msg("Destination is already on the withdrawal allowlist")]
+    DestinationAlreadyAllowlisted,
+    #[msg("Destination is not an active entry on the withdrawal allowlist")]
+    DestinationNotAllowlisted,
+    #[msg("Withdrawal would exceed the vault's rolling daily limit")]
+    DailyWithdrawLimitExceeded,
+    #[msg("Decoy bps must be at most 10000, with min not above max")]
+    InvalidDecoyMode,
+    #[msg("Decoy drip needs at most 20 installments and a positive interval")]
+    InvalidDecoyDrip,
+    #[msg("No decoy installment is due")]
+    DecoyDripNotDue,
+    #[msg("Token decoy accounts are missing or don't match the configured decoy mint")]
+    DecoyTokenAccountMismatch,
+    #[msg("Decoy memo is too long")]
+    DecoyMemoTooLong,
+    #[msg("Balance buffer is outside the allowed range")]
+    InvalidBalanceBuffer,
+    #[msg("Stake account isn't initialized with the expected authorities")]
+    InvalidStakeAccount,
+    #[msg("Panic hook is not registered or not allowed")]
+    InvalidPanicHook,
+    #[msg("Too many panic hooks")]
+    TooManyPanicHooks,
+    #[msg("Panic hook accounts don't match the registered hooks")]
+    PanicHookAccountMismatch,
+    #[msg("Wallet has been flagged as compromised")]
+    WalletCompromised,
+    #[msg("Alert message is too long")]
+    AlertMessageTooLong,
+    #[msg("Alert URI template is too long or repeats the contact placeholder")]
+    InvalidAlertUriTemplate,
+    #[msg("Alert has already been acknowledged")]
+    AlertAlreadyAcknowledged,
+    #[msg("Veto quorum or duration out of range")]
+    InvalidVetoPolicy,
+    #[msg("This contact has already vetoed the recovery")]
+    AlreadyVetoed,
+    #[msg("Claims are paused by a guardian veto")]
+    ClaimVetoed,
+    #[msg("This contact has already voted for the pending extension")]
+    AlreadyVotedExtension,
+    #[msg("Guardian time-lock extensions are used up")]
+    LockExtensionExhausted,
+    #[msg("This contact has already voted to fast-track recovery")]
+    AlreadyVotedFastTrack,
+    #[msg("Claim tiers out of range")]
+    InvalidClaimTiers,
+    #[msg("Early claim limit reached; the rest unlocks after the second lock")]
+    SecondLockActive,
+    #[msg("Too soon after the last claim")]
+    ClaimCooldownActive,
+    #[msg("Claim split shares must be positive, sum to 10000 bps and match the destinations")]
+    InvalidClaimSplit,
+    #[msg("Signer is not the config's recovery authority")]
+    InvalidRecoveryAuthority,
+    #[msg("Signer is not the proposed new owner")]
+    NotPendingOwner,
+    #[msg("Co-owners must be distinct, not the owner, and cover the claim quorum")]
+    InvalidCoOwners,
+    #[msg("Signer is not an owner or linked wallet of this vault")]
+    NotAnOwner,
+    #[msg("This owner has already signed off on claims")]
+    AlreadyApprovedClaim,
+    #[msg("Not enough owners have signed off on claims")]
+    InsufficientOwnerApprovals,
+    #[msg("Too many linked wallets")]
+    TooManyLinkedWallets,
+    #[msg("Wallet is the owner or already linked")]
+    WalletAlreadyLinked,
+    #[msg("Wallet is not linked to this config")]
+    WalletNotLinked,
+    #[msg("Linked wallet accounts don't match the config's linked wallets")]
+    LinkedWalletAccountMismatch,
+    #[msg("Too many delegated token accounts")]
+    TooManyDelegatedTokenAccounts,
+    #[msg("Token account is already registered")]
+    DelegatedTokenAccountAlreadyRegistered,
+    #[msg("Token account is not registered")]
+    DelegatedTokenAccountNotRegistered,
+    #[msg("Delegated token accounts don't match the config or the vault's token accounts")]
+    DelegatedTokenAccountMismatch,
+    #[msg("Lamport debit exceeds the account balance")]
+    LamportUnderflow,
+    #[msg("Lamport credit overflows the account balance")]
+    LamportOverflow,
+    #[msg("Contacts must be distinct")]
+    DuplicateContact,
+    #[msg("The owner can't be their own emergency contact")]
+    OwnerIsContact,
+    #[msg("Recovery threshold must be at least 1 when contacts are set")]
+    ZeroRecoveryThreshold,
+    #[msg("Decoy amount must be positive")]
+    ZeroDecoyAmount,
+    #[msg("Decoy amount is above the 100 SOL ceiling")]
+    DecoyAmountTooLarge,
+    #[msg("The trigger transaction carries an instruction a panic doesn't travel with")]
+    UnexpectedInstruction,
+    #[msg("Recovery window must be at most 90 days")]
+    InvalidRecoveryWindow,
+    #[msg("This recovery round expired; initiate a new one")]
+    RecoveryRoundExpired,
+    #[msg("Active alerts can only be closed once funds have been claimed")]
+    AlertNotClosable,
+    #[msg("Account is not stale enough to garbage-collect")]
+    AccountNotCollectable,
+    #[msg("Keeper tip is above the ceiling")]
+    InvalidKeeperTip,
+    #[msg("Watcher and attestor must be distinct keys other than an owner")]
+    InvalidWatcher,
+    #[msg("Vault SOL is still staked; unstake it before claiming")]
+    VaultFundsStaked,
+    #[msg("Vault SOL is already staked in a different pool")]
+    StakePoolMismatch,
+    #[msg("Vault SOL is still lent out; redeem it before claiming")]
+    VaultFundsLent,
+    #[msg("Vault SOL is already lent to a different reserve")]
+    LendingReserveMismatch,
+    #[msg("Lending would put more of the vault out on loan than the cap allows")]
+    LendingCapExceeded,
+    #[msg("Report bounty is above the ceiling")]
+    InvalidReportBounty,
+    #[msg("Report bounties are turned off")]
+    NoReportBounty,
+    #[msg("Flag hasn't been corroborated by enough other victims")]
+    FlagNotCorroborated,
+    #[msg("Bounty for this flag was already paid")]
+    BountyAlreadyPaid,
+    #[msg("A link joins two distinct addresses in sorted order, one of them flagged")]
+    InvalidAttackerLink,
+    #[msg("Merkle tree is not the compressed registry's")]
+    InvalidMerkleTree,
+    #[msg("Alert tree is already set up")]
+    AlertTreeAlreadySet,
+    #[msg("Merkle tree is not the config's alert tree")]
+    InvalidAlertTree,
+    #[msg("Program version or contact is empty or too long")]
+    InvalidProgramInfo,
+    #[msg("Config label is too long")]
+    LabelTooLong,
+    #[msg("Severity must be between 1 and 10")]
+    InvalidSeverity,
+    #[msg("Too many mints have their own time-lock")]
+    TooManyTokenTimeLocks,
+    #[msg("Hot threshold must not exceed the recovery threshold and the cold lock at most 90 days")]
+    InvalidTranchePolicy,
+    #[msg("The cold tranche is still locked and the hot tranche is spent")]
+    ColdTrancheLocked,
+    #[msg("Vault name is too long")]
+    VaultNameTooLong,
+    #[msg("A named config must have vault index 0")]
+    NamedVaultIndexed,
+    #[msg("Only a rehearsal can give the Rehearsal reason")]
+    RehearsalReason,
+    #[msg("Large claim lock out of range")]
+    InvalidLargeClaimLock,
+    #[msg("A claim this large waits for the large claim lock")]
+    LargeClaimLocked,
+    #[msg("The vault keeps a deposit ledger, which must be passed")]
+    DepositLedgerRequired,
+    #[msg("Amount too large for the deposit ledger")]
+    LedgerAmountOverflow,
+    #[msg("Report bond stays locked until its flag is upheld, expires or is closed")]
+    ReportBondLocked,
+    #[msg("Every report backing the flag must be passed to clear it")]
+    ReportsMissing,
 }
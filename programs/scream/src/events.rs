@@ -1,46 +1,881 @@
 use anchor_lang::prelude::*;
+use crate::state::{
+    CompromiseReason, DecoyMode, LinkKind, TokenBalance, TriggerMode, WithdrawPolicy,
+};
+
+/// `emit!`, plus a self-CPI copy of the event when built with `event-cpi`.
+/// Logs can be truncated under heavy CPI use; inner instructions can't, so
+/// indexers rebuilding panic and recovery history should read those. The
+/// accounts struct must carry `#[event_cpi]` under that feature.
+macro_rules! emit_indexed {
+    ($accounts:expr, $bumps:expr, $event:expr) => {{
+        let event = $event;
+        #[cfg(feature = "event-cpi")]
+        crate::utils::emit_cpi_event(&$accounts.event_authority, $bumps.event_authority, &event)?;
+        emit!(event);
+    }};
+}
+pub(crate) use emit_indexed;
+
+/// Written first in every event. Events only ever gain fields at the end;
+/// any other change to an event's layout bumps this, so indexers can branch
+/// on it instead of misparsing events from another deployment.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
 
 #[event]
 pub struct ConfigInitialized {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub contacts_count: u8,
     pub time_lock_duration: i64,
     pub decoy_lamports: u64,
+    /// Which of the owner's configs was created
+    pub vault_index: u8,
+    /// Its name, when seeded by one (empty = by index)
+    pub vault_name: String,
+}
+
+#[event]
+pub struct TriggerUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub trigger_mode: TriggerMode,
+}
+
+#[event]
+pub struct AttemptLimitUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub max_failed_attempts: u8,
+    pub lockout_duration: i64,
+    pub soft_lock_on_lockout: bool,
+}
+
+#[event]
+pub struct PresignNonceUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub nonce_account: Pubkey,
+    pub nonce_authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryDestinationUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub recovery_destination: Pubkey,
 }
 
 #[event]
 pub struct Deposited {
+    pub schema_version: u8,
     pub owner: Pubkey,
+    pub depositor: Pubkey,
     pub amount: u64,
     pub vault_balance: u64,
 }
 
 #[event]
 pub struct PanicTriggered {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub attacker: Pubkey,
     pub vault_balance: u64,
     pub decoy_sent: u64,
     pub locked_until: i64,
     pub contacts_alerted: u8,
+    pub attackers_flagged: u8,
+    /// The config's contacts, in order, so `contacts_alerted` and later
+    /// approvals can be matched without reading the config
+    pub contacts: Vec<Pubkey>,
+    pub config_version: u8,
+    /// Per mint, what the vault's token accounts the panic touched hold
+    /// afterwards: the token decoy's source and delegated pull destinations
+    pub vault_token_balances: Vec<TokenBalance>,
+    /// What the owner's compromised flag records
+    pub reason: CompromiseReason,
+    pub severity: Option<u8>,
+    /// Which of the owner's configs panicked
+    pub vault_index: u8,
+    pub vault_name: String,
+}
+
+#[event]
+pub struct ContactAlerted {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub is_rehearsal: bool,
+}
+
+#[event]
+pub struct TriggerAttemptFailed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub failed_attempts: u8,
+    pub trigger_locked_until: i64,
+}
+
+#[event]
+pub struct TokensSwept {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
 }
 
 #[event]
 pub struct RecoveryInitiated {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub vault_balance: u64,
+    pub recovery_round: u32,
 }
 
 #[event]
 pub struct RecoveryApproved {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub contact: Pubkey,
     pub approvals_so_far: u8,
     pub threshold: u8,
+    pub recovery_round: u32,
 }
 
 #[event]
 pub struct FundsRecovered {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClaimFinalized {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DisputeConfigInitialized {
+    pub schema_version: u8,
+    pub arbiter: Pubkey,
+    pub bond_lamports: u64,
+    pub dispute_window: i64,
+}
+
+#[event]
+pub struct FlagDisputed {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub bond_lamports: u64,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub cleared: bool,
+    pub lapsed: bool,
+}
+
+#[event]
+pub struct AttackerReported {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct ReportSlashed {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reporter: Pubkey,
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct AttackerFlagExpired {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub flagged_at: i64,
+}
+
+#[event]
+pub struct GuardianRegistered {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+}
+
+#[event]
+pub struct ProtocolStatsUpdated {
+    pub schema_version: u8,
+    pub total_configs: u64,
+    pub total_panics: u64,
+    pub total_lamports_recovered: u64,
+    pub total_attackers_flagged: u64,
+}
+
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub max_contacts: u8,
+    pub min_time_lock: i64,
+    pub max_time_lock: i64,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct FeeCollected {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub schema_version: u8,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PausedUpdated {
+    pub schema_version: u8,
+    pub paused: bool,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct WithdrawPolicyUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub policy: WithdrawPolicy,
+    /// When the policy applies; later than now when it was loosened
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct WithdrawRequested {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct WithdrawExecuted {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WithdrawCancelled {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VaultTransferred {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    /// Total sent in the current spending period, including this transfer
+    pub spent_in_period: u64,
+}
+
+#[event]
+pub struct WithdrawDestinationAdded {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub active_at: i64,
+}
+
+#[event]
+pub struct WithdrawDestinationRemoved {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct DecoyModeUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub decoy_mode: DecoyMode,
+    pub jitter_bps: u16,
+}
+
+#[event]
+pub struct DecoyDripUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub installments: u8,
+    pub interval: i64,
+}
+
+#[event]
+pub struct DecoyDripReleased {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub attacker: Pubkey,
+    pub amount: u64,
+    pub installments_left: u8,
+}
+
+#[event]
+pub struct DecoyTokenUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DecoyTokensSent {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub attacker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TokenDecoySkipped {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BalanceBufferUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub balance_buffer: u64,
+}
+
+#[event]
+pub struct DelegateRevoked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub delegate: Pubkey,
+    pub delegated_amount: u64,
+}
+
+#[event]
+pub struct WrappedSolSwept {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    /// Wrapped balance plus the closed account's rent, now in the vault
+    pub lamports: u64,
+}
+
+#[event]
+pub struct StakeSecured {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub stake_account: Pubkey,
+    pub lamports: u64,
+    /// Whether the account was delegated and has started cooling down
+    pub deactivated: bool,
+}
+
+#[event]
+pub struct SecuredStakeWithdrawn {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub stake_account: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct PoolTokensUnstaked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub stake_pool: Pubkey,
+    pub pool_tokens: u64,
+    /// SOL the pool paid into the vault, after its withdrawal fee
+    pub lamports: u64,
+}
+
+#[event]
+pub struct CloseAuthorityNeutralized {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    /// The foreign close authority still set on the account
+    pub close_authority: Pubkey,
+    /// Tokens moved to the vault so closing the account yields nothing
+    pub amount_moved: u64,
+}
+
+#[event]
+pub struct PanicHookRegistered {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub account_count: u8,
+}
+
+#[event]
+pub struct PanicHookRemoved {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct PanicHookCalled {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct AlertMessageSet {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub len: u16,
+}
+
+#[event]
+pub struct AlertUriTemplateUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub template: String,
+}
+
+#[event]
+pub struct AlertAcknowledged {
+    pub schema_version: u8,
     pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub acknowledged_at: i64,
+}
+
+#[event]
+pub struct VetoPolicyUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub veto_quorum: u8,
+    pub veto_duration: i64,
+}
+
+#[event]
+pub struct ClaimVetoVoted {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub votes: u8,
+    pub quorum: u8,
+}
+
+#[event]
+pub struct ClaimPaused {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub paused_until: i64,
+}
+
+#[event]
+pub struct LockExtensionVoted {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub votes: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct TimeLockExtended {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub locked_until: i64,
+    pub lock_extended_by: i64,
+}
+
+#[event]
+pub struct FastTrackVoted {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+    pub votes: u8,
+    pub contacts: u8,
+}
+
+#[event]
+pub struct RecoveryFastTracked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ClaimTiersUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub early_claim_limit: u64,
+    pub second_lock_duration: i64,
+}
+
+#[event]
+pub struct ClaimSplitPaid {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub share_bps: u16,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RecoveryAuthorityUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub recovery_authority: Pubkey,
+}
+
+#[event]
+pub struct OwnerTransferProposed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerTransferred {
+    pub schema_version: u8,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub vault_balance: u64,
+}
+
+#[event]
+pub struct CoOwnersUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub co_owners: Vec<Pubkey>,
+    pub owner_claim_quorum: u8,
+}
+
+#[event]
+pub struct OwnerClaimApproved {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub quorum: u8,
+}
+
+#[event]
+pub struct WalletLinked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct WalletUnlinked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct LinkedWalletSwept {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub wallet: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct DelegatedTokenAccountRegistered {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct DelegatedTokenAccountRemoved {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct DelegatedTokensPulled {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
     pub amount: u64,
 }
+
+#[event]
+pub struct PanicRehearsed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contacts_alerted: u8,
+    /// Always `Rehearsal`
+    pub reason: CompromiseReason,
+}
+
+#[event]
+pub struct RecoveryWindowUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub recovery_window: i64,
+}
+
+#[event]
+pub struct AlertClosed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub contact: Pubkey,
+}
+
+#[event]
+pub struct FlagCleared {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub cleared_by: Pubkey,
+}
+
+#[event]
+pub struct AccountCollected {
+    pub schema_version: u8,
+    pub account: Pubkey,
+    pub recipient: Pubkey,
+    pub lamports: u64,
+    pub tip: u64,
+}
+
+#[event]
+pub struct KeeperTipUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub keeper_tip: u64,
+}
+
+#[event]
+pub struct KeeperTipPaid {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WatcherUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub watcher: Pubkey,
+    pub attestor: Pubkey,
+}
+
+#[event]
+pub struct WatcherSoftLocked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub watcher: Pubkey,
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct VaultSolStaked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub stake_pool: Pubkey,
+    pub lamports: u64,
+    pub pool_tokens: u64,
+}
+
+#[event]
+pub struct VaultSolUnstaked {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub stake_pool: Pubkey,
+    pub pool_tokens: u64,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct VaultSolLent {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub reserve: Pubkey,
+    pub lamports: u64,
+    pub collateral: u64,
+}
+
+#[event]
+pub struct VaultSolRedeemed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub reserve: Pubkey,
+    pub collateral: u64,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct FlagCorroborated {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub corroborated_by: Pubkey,
+    pub corroborations: u8,
+}
+
+#[event]
+pub struct ReportBountyUpdated {
+    pub schema_version: u8,
+    pub report_bounty: u64,
+}
+
+#[event]
+pub struct ReportBountyPaid {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AttackersLinked {
+    pub schema_version: u8,
+    pub address_a: Pubkey,
+    pub address_b: Pubkey,
+    pub kind: LinkKind,
+    pub reported_by: Pubkey,
+    pub evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct AttackersUnlinked {
+    pub schema_version: u8,
+    pub address_a: Pubkey,
+    pub address_b: Pubkey,
+    pub removed_by: Pubkey,
+}
+
+#[event]
+pub struct CompressedRegistryInitialized {
+    pub schema_version: u8,
+    pub merkle_tree: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+#[event]
+pub struct CompressedFlagAppended {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reported_by: Pubkey,
+    pub flagged_at: i64,
+    pub evidence_hash: [u8; 32],
+    pub leaf_index: u64,
+}
+
+#[event]
+pub struct AlertTreeInitialized {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+#[event]
+pub struct ProgramInfoUpdated {
+    pub schema_version: u8,
+    pub version: String,
+    pub audit_hash: [u8; 32],
+    pub contact: String,
+}
+
+#[event]
+pub struct LabelUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub label: String,
+}
+
+#[event]
+pub struct TokenTimeLockUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    /// `None` = back on the config's time-lock
+    pub duration: Option<i64>,
+}
+
+#[event]
+pub struct TokensClaimed {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TranchePolicyUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    /// 0 = tranches off
+    pub hot_threshold: u8,
+    pub cold_lock_duration: i64,
+}
+
+#[event]
+pub struct TranchesRebalanced {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub hot_lamports: u64,
+    pub cold_lamports: u64,
+}
+
+#[event]
+pub struct LargeClaimLockUpdated {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    /// 0 = no large claim lock
+    pub large_claim_threshold: u64,
+    pub large_claim_lock_duration: i64,
+}
+
+#[event]
+pub struct ReportReclaimed {
+    pub schema_version: u8,
+    pub attacker: Pubkey,
+    pub reporter: Pubkey,
+    pub bond_lamports: u64,
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{OwnerTransferred, EVENT_SCHEMA_VERSION};
+use crate::utils::{close_pda_account, init_pda_account, system_transfer};
+
+#[derive(Accounts)]
+pub struct AcceptOwnerTransfer<'info> {
+    /// Pays rent for the re-seeded accounts
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: The current owner. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = panic_config.pending_owner == new_owner.key() @ ScreamError::NotPendingOwner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    /// CHECK: Created here as the new owner's config.
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, new_owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub new_panic_config: UncheckedAccount<'info>,
+
+    /// CHECK: Created here as the new owner's vault.
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, new_owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub new_vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, new_owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub new_fee_reserve: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Re-seed the config, vault and fee reserve under `new_owner`, at the
+/// same `vault_index`, and close the old ones, moving every lamport across.
+/// Only these three move: alert, allowlist, ledger, hook and withdraw
+/// request accounts stay under the old key, and token accounts owned by the
+/// old vault must be emptied first.
+pub fn handler(ctx: Context<AcceptOwnerTransfer>) -> Result<()> {
+    require!(!ctx.accounts.panic_config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let owner_key = ctx.accounts.owner.key();
+    let new_owner_key = ctx.accounts.new_owner.key();
+    let payer = ctx.accounts.new_owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let mut config = (*ctx.accounts.panic_config).clone();
+    config.owner = new_owner_key;
+    config.pending_owner = Pubkey::default();
+    config.co_owners.retain(|k| *k != new_owner_key);
+    config.linked_wallets.retain(|k| *k != new_owner_key);
+    config.owner_claim_quorum = config
+        .owner_claim_quorum
+        .min(config.co_owners.len() as u8 + 1);
+    config.bump = ctx.bumps.new_panic_config;
+    init_pda_account(
+        &payer,
+        None,
+        &ctx.accounts.new_panic_config.to_account_info(),
+        &system_program,
+        ctx.program_id,
+        &[
+            PanicConfig::SEED_PREFIX,
+            new_owner_key.as_ref(),
+            config.vault_seed(),
+            &[config.bump],
+        ],
+        8 + PanicConfig::INIT_SPACE,
+        &config,
+    )?;
+
+    let mut vault = (*ctx.accounts.vault).clone();
+    vault.owner = new_owner_key;
+    vault.bump = ctx.bumps.new_vault;
+    init_pda_account(
+        &payer,
+        None,
+        &ctx.accounts.new_vault.to_account_info(),
+        &system_program,
+        ctx.program_id,
+        &[
+            Vault::SEED_PREFIX,
+            new_owner_key.as_ref(),
+            vault.vault_seed(),
+            &[vault.bump],
+        ],
+        8 + Vault::INIT_SPACE,
+        &vault,
+    )?;
+
+    let vault_balance = ctx.accounts.vault.to_account_info().lamports();
+    close_pda_account(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.new_vault.to_account_info(),
+    )?;
+    close_pda_account(&ctx.accounts.panic_config.to_account_info(), &payer)?;
+
+    let reserve_lamports = ctx.accounts.fee_reserve.lamports();
+    if reserve_lamports > 0 {
+        system_transfer(
+            &ctx.accounts.fee_reserve.to_account_info(),
+            Some(&[
+                FeeReserve::SEED_PREFIX,
+                owner_key.as_ref(),
+                ctx.accounts.panic_config.vault_seed(),
+                &[ctx.bumps.fee_reserve],
+            ]),
+            &ctx.accounts.new_fee_reserve.to_account_info(),
+            &system_program,
+            reserve_lamports,
+        )?;
+    }
+
+    emit!(OwnerTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        old_owner: owner_key,
+        new_owner: new_owner_key,
+        vault_balance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AlertAcknowledged, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct AcknowledgeAlert<'info> {
+    pub contact: Signer<'info>,
+
+    /// CHECK: The owner whose alert is acknowledged. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AlertAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            contact.key().as_ref(),
+            panic_config.vault_seed(),
+        ],
+        bump = alert_account.bump,
+        constraint = alert_account.owner == owner.key(),
+        constraint = alert_account.contact == contact.key(),
+    )]
+    pub alert_account: Account<'info, AlertAccount>,
+}
+
+/// A contact's "I've seen this", separate from approving recovery.
+pub fn handler(ctx: Context<AcknowledgeAlert>) -> Result<()> {
+    let alert = &mut ctx.accounts.alert_account;
+    require!(alert.is_active || alert.is_rehearsal, ScreamError::AlertNotActive);
+    require!(alert.acknowledged_at == 0, ScreamError::AlertAlreadyAcknowledged);
+
+    alert.acknowledged_at = Clock::get()?.unix_timestamp;
+
+    emit!(AlertAcknowledged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: alert.owner,
+        contact: alert.contact,
+        acknowledged_at: alert.acknowledged_at,
+    });
+
+    Ok(())
+}
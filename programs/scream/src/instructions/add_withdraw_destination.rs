@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WithdrawDestinationAdded, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct AddWithdrawDestination<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [WithdrawAllowlist::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = withdraw_allowlist.bump,
+        has_one = owner,
+    )]
+    pub withdraw_allowlist: Account<'info, WithdrawAllowlist>,
+}
+
+/// List a destination. It only becomes usable after the vault's withdraw
+/// delay, so a stolen key can't add its own address and withdraw at once.
+pub fn handler(ctx: Context<AddWithdrawDestination>, destination: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    vault.settle_policy(now);
+
+    let allowlist = &mut ctx.accounts.withdraw_allowlist;
+    require!(
+        !allowlist.entries.iter().any(|entry| entry.destination == destination),
+        ScreamError::DestinationAlreadyAllowlisted
+    );
+    require!(
+        allowlist.entries.len() < WithdrawAllowlist::MAX_ENTRIES,
+        ScreamError::AllowlistFull
+    );
+
+    let active_at = now + vault.withdraw_policy.withdraw_delay;
+    allowlist.entries.push(AllowlistEntry {
+        destination,
+        active_at,
+    });
+
+    emit!(WithdrawDestinationAdded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        destination,
+        active_at,
+    });
+
+    Ok(())
+}
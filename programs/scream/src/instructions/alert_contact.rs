@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ContactAlerted, EVENT_SCHEMA_VERSION};
+use crate::utils::{init_pda_account, rent_funder};
+
+#[derive(Accounts)]
+pub struct AlertContact<'info> {
+    /// Anyone may finish alerting contacts once panic has fired
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The owner who triggered panic. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    /// CHECK: Must be one of the config's contacts; checked in the handler.
+    pub contact: UncheckedAccount<'info>,
+
+    /// CHECK: Created here, or activated if it was pre-created at initialize.
+    #[account(
+        mut,
+        seeds = [
+            AlertAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            contact.key().as_ref(),
+            panic_config.vault_seed(),
+        ],
+        bump,
+    )]
+    pub alert_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Idempotent: alerting an already-alerted contact is a no-op.
+pub fn handler(ctx: Context<AlertContact>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(config.is_triggered, ScreamError::PanicNotTriggered);
+
+    let contact = ctx.accounts.contact.key();
+    let index = config
+        .contacts
+        .iter()
+        .position(|c| *c == contact)
+        .ok_or(ScreamError::InvalidContact)?;
+
+    if config.alerted_contacts & (1 << index) != 0 {
+        return Ok(());
+    }
+
+    let owner_key = ctx.accounts.owner.key();
+    let uri = config.alert_uri(&contact);
+    let reserve_seeds: &[&[u8]] = &[
+        FeeReserve::SEED_PREFIX,
+        owner_key.as_ref(),
+        config.vault_seed(),
+        &[ctx.bumps.fee_reserve],
+    ];
+    activate_alert(
+        &ctx.accounts.fee_reserve.to_account_info(),
+        reserve_seeds,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.alert_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.program_id,
+        &owner_key,
+        config.vault_seed(),
+        &contact,
+        uri,
+        ctx.bumps.alert_account,
+        Clock::get()?.unix_timestamp,
+        false,
+    )?;
+
+    ctx.accounts.panic_config.alerted_contacts |= 1 << index;
+
+    Ok(())
+}
+
+/// Map each passed alert account to `(contact index, bump)`, rejecting
+/// anything that isn't the alert PDA of a distinct configured contact.
+pub(crate) fn match_alert_accounts(
+    owner: &Pubkey,
+    config_seed: &[u8],
+    contacts: &[Pubkey],
+    alert_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Vec<(usize, u8)>> {
+    require!(
+        alert_accounts.len() <= contacts.len(),
+        ScreamError::ContactAccountMismatch
+    );
+
+    let expected: Vec<(Pubkey, u8)> = contacts
+        .iter()
+        .map(|contact| {
+            Pubkey::find_program_address(
+                &[AlertAccount::SEED_PREFIX, owner.as_ref(), contact.as_ref(), config_seed],
+                program_id,
+            )
+        })
+        .collect();
+
+    let mut targets: Vec<(usize, u8)> = Vec::with_capacity(alert_accounts.len());
+    for account in alert_accounts {
+        let index = expected
+            .iter()
+            .position(|(pda, _)| *pda == account.key())
+            .ok_or(ScreamError::ContactAccountMismatch)?;
+        require!(
+            targets.iter().all(|(i, _)| *i != index),
+            ScreamError::ContactAccountMismatch
+        );
+        targets.push((index, expected[index].1));
+    }
+    Ok(targets)
+}
+
+/// Fire one contact's alert. A pre-created AlertAccount is just flipped
+/// active; otherwise it is created, paying rent from the fee reserve when it
+/// can cover it and from `payer` otherwise. A rehearsal alert is marked as
+/// such and left inactive; any earlier acknowledgement is cleared either way.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn activate_alert<'info>(
+    fee_reserve: &AccountInfo<'info>,
+    reserve_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    alert_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    config_seed: &[u8],
+    contact: &Pubkey,
+    uri: String,
+    bump: u8,
+    now: i64,
+    is_rehearsal: bool,
+) -> Result<()> {
+    if alert_account.owner == program_id {
+        let mut data = alert_account.try_borrow_mut_data()?;
+        let mut alert = AlertAccount::try_deserialize(&mut &data[..])?;
+        alert.alerted_at = now;
+        alert.is_active |= !is_rehearsal;
+        alert.is_rehearsal = is_rehearsal;
+        alert.acknowledged_at = 0;
+        alert.uri = uri;
+        let mut writer: &mut [u8] = &mut data;
+        alert.try_serialize(&mut writer)?;
+    } else {
+        let space = 8 + AlertAccount::INIT_SPACE;
+        let (funder, from_reserve) =
+            rent_funder(fee_reserve, payer, Rent::get()?.minimum_balance(space))?;
+
+        init_pda_account(
+            &funder,
+            from_reserve.then_some(reserve_seeds),
+            alert_account,
+            system_program,
+            program_id,
+            &[
+                AlertAccount::SEED_PREFIX,
+                owner.as_ref(),
+                contact.as_ref(),
+                config_seed,
+                &[bump],
+            ],
+            space,
+            &AlertAccount {
+                owner: *owner,
+                contact: *contact,
+                alerted_at: now,
+                is_active: !is_rehearsal,
+                is_rehearsal,
+                has_approved: false,
+                approved_round: 0,
+                acknowledged_at: 0,
+                message: Vec::new(),
+                uri,
+                version: AlertAccount::CURRENT_VERSION,
+                bump,
+            },
+        )?;
+    }
+
+    emit!(ContactAlerted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: *owner,
+        contact: *contact,
+        is_rehearsal,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_sha256_hasher::hashv;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{CompressedFlagAppended, EVENT_SCHEMA_VERSION};
+use crate::instructions::init_compressed_registry::{
+    ACCOUNT_COMPRESSION_PROGRAM_ID, APPEND, NOOP_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+pub struct AppendCompressedFlag<'info> {
+    /// Any wallet, as with `report_attacker`
+    pub reporter: Signer<'info>,
+
+    /// CHECK: The address being flagged; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CompressedRegistry::SEED_PREFIX],
+        bump = compressed_registry.bump,
+    )]
+    pub compressed_registry: Account<'info, CompressedRegistry>,
+
+    /// CHECK: Checked against the registry; validated by the compression
+    /// program.
+    #[account(mut, address = compressed_registry.merkle_tree @ ScreamError::InvalidMerkleTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-account-compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// Flag an address in the compressed registry: a leaf instead of an
+/// `AttackerFlag` PDA, for registries too large to pay rent on. The event
+/// carries the leaf's fields and index so indexers can serve proofs.
+pub fn handler(ctx: Context<AppendCompressedFlag>, evidence_hash: [u8; 32]) -> Result<()> {
+    let flag = CompressedFlagLeaf {
+        attacker: ctx.accounts.attacker.key(),
+        reported_by: ctx.accounts.reporter.key(),
+        flagged_at: Clock::get()?.unix_timestamp,
+        evidence_hash,
+    };
+
+    let registry = &ctx.accounts.compressed_registry;
+    let mut data = APPEND.to_vec();
+    data.extend_from_slice(&leaf_hash(&flag));
+    let ix = Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+            AccountMeta::new_readonly(registry.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.noop.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            registry.to_account_info(),
+            ctx.accounts.noop.to_account_info(),
+        ],
+        &[&[CompressedRegistry::SEED_PREFIX, &[registry.bump]]],
+    )?;
+
+    let registry = &mut ctx.accounts.compressed_registry;
+    let leaf_index = registry.leaves;
+    registry.leaves += 1;
+
+    emit!(CompressedFlagAppended {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: flag.attacker,
+        reported_by: flag.reported_by,
+        flagged_at: flag.flagged_at,
+        evidence_hash,
+        leaf_index,
+    });
+
+    Ok(())
+}
+
+/// The tree leaf for `flag`.
+pub(crate) fn leaf_hash(flag: &CompressedFlagLeaf) -> [u8; 32] {
+    hashv(&[
+        CompressedRegistry::LEAF_DOMAIN,
+        flag.attacker.as_ref(),
+        flag.reported_by.as_ref(),
+        &flag.flagged_at.to_le_bytes(),
+        &flag.evidence_hash,
+    ])
+    .to_bytes()
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{OwnerClaimApproved, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct ApproveOwnerClaim<'info> {
+    /// The owner or one of the config's co-owners
+    pub approver: Signer<'info>,
+
+    /// CHECK: The owner of the shared vault. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Sign off on claiming from a shared vault. Claims go through once
+/// `owner_claim_quorum` owners have signed off and the guardians approved.
+pub fn handler(ctx: Context<ApproveOwnerClaim>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(config.is_triggered, ScreamError::PanicNotTriggered);
+
+    let approver = ctx.accounts.approver.key();
+    let index = config
+        .owner_index(&approver)
+        .ok_or(ScreamError::NotAnOwner)?;
+
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        vault.owner_claim_votes & (1 << index) == 0,
+        ScreamError::AlreadyApprovedClaim
+    );
+    vault.owner_claim_votes |= 1 << index;
+
+    emit!(OwnerClaimApproved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        approver,
+        approvals: vault.owner_claim_votes.count_ones() as u8,
+        quorum: config.owner_claim_quorum,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ScreamError;
-use crate::events::RecoveryApproved;
+use crate::events::{emit_indexed, RecoveryApproved, EVENT_SCHEMA_VERSION};
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct ApproveRecovery<'info> {
     pub contact: Signer<'info>,
@@ -11,7 +12,7 @@ pub struct ApproveRecovery<'info> {
     pub owner: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = panic_config.bump,
         constraint = panic_config.owner == owner.key(),
     )]
@@ -19,7 +20,7 @@ pub struct ApproveRecovery<'info> {
 
     #[account(
         mut,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = vault.bump,
         constraint = vault.owner == owner.key(),
     )]
@@ -27,7 +28,12 @@ pub struct ApproveRecovery<'info> {
 
     #[account(
         mut,
-        seeds = [AlertAccount::SEED_PREFIX, owner.key().as_ref(), contact.key().as_ref()],
+        seeds = [
+            AlertAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            contact.key().as_ref(),
+            panic_config.vault_seed(),
+        ],
         bump = alert_account.bump,
         constraint = alert_account.owner == owner.key(),
         constraint = alert_account.contact == contact.key(),
@@ -36,30 +42,81 @@ pub struct ApproveRecovery<'info> {
 }
 
 pub fn handler(ctx: Context<ApproveRecovery>) -> Result<()> {
-    let config = &ctx.accounts.panic_config;
-    let vault = &mut ctx.accounts.vault;
-    let alert = &mut ctx.accounts.alert_account;
+    let approved = record_approval(
+        &ctx.accounts.panic_config,
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.alert_account,
+        ctx.accounts.owner.key(),
+        ctx.accounts.contact.key(),
+    )?;
+    emit_indexed!(ctx.accounts, ctx.bumps, approved);
+    Ok(())
+}
+
+/// Credit one contact's approval; shared by the signed and relayed paths,
+/// which emit the returned event.
+pub(crate) fn record_approval(
+    config: &PanicConfig,
+    vault: &mut Vault,
+    alert: &mut AlertAccount,
+    owner: Pubkey,
+    contact: Pubkey,
+) -> Result<RecoveryApproved> {
+    require_approval_open(config, vault, &contact)?;
+
+    require!(alert.is_active, ScreamError::AlertNotActive);
+    // An approval from an earlier round is stale and doesn't block this one
+    require!(
+        !alert.has_approved || alert.approved_round != vault.recovery_round,
+        ScreamError::AlreadyApproved
+    );
+
+    alert.has_approved = true;
+    alert.approved_round = vault.recovery_round;
+    Ok(count_approval(config, vault, owner, contact))
+}
 
+/// Check that `contact` may approve the vault's current recovery round,
+/// whichever kind of alert they hold.
+pub(crate) fn require_approval_open(
+    config: &PanicConfig,
+    vault: &Vault,
+    contact: &Pubkey,
+) -> Result<()> {
     require!(config.is_triggered, ScreamError::PanicNotTriggered);
     require!(vault.recovery_initiated, ScreamError::RecoveryNotInitiated);
+    require!(
+        !vault.recovery_round_expired(
+            config.recovery_window,
+            config.recovery_threshold,
+            Clock::get()?.unix_timestamp,
+        ),
+        ScreamError::RecoveryRoundExpired
+    );
 
     // Verify contact is in the contacts list
     require!(
-        config.contacts.contains(&ctx.accounts.contact.key()),
+        config.contacts.contains(contact),
         ScreamError::InvalidContact
     );
+    Ok(())
+}
 
-    require!(!alert.has_approved, ScreamError::AlreadyApproved);
-
-    alert.has_approved = true;
+/// Add an approval the caller has recorded against the contact's alert.
+pub(crate) fn count_approval(
+    config: &PanicConfig,
+    vault: &mut Vault,
+    owner: Pubkey,
+    contact: Pubkey,
+) -> RecoveryApproved {
     vault.approvals += 1;
 
-    emit!(RecoveryApproved {
-        owner: ctx.accounts.owner.key(),
-        contact: ctx.accounts.contact.key(),
+    RecoveryApproved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner,
+        contact,
         approvals_so_far: vault.approvals,
         threshold: config.recovery_threshold,
-    });
-
-    Ok(())
+        recovery_round: vault.recovery_round,
+    }
 }
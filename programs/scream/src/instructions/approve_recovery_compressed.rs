@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::emit_indexed;
+use crate::instructions::approve_recovery::{count_approval, require_approval_open};
+use crate::instructions::init_alert_tree::alert_leaf;
+use crate::instructions::init_compressed_registry::{
+    ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID, REPLACE_LEAF,
+};
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ApproveRecoveryCompressed<'info> {
+    pub contact: Signer<'info>,
+
+    /// CHECK: The owner whose vault we're approving recovery for. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Checked against the config; validated by the compression
+    /// program.
+    #[account(
+        mut,
+        address = panic_config.alert_tree @ ScreamError::InvalidAlertTree,
+        constraint = panic_config.alert_tree != Pubkey::default() @ ScreamError::InvalidAlertTree,
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-account-compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// `approve_recovery` for a config with compressed alerts. The contact's
+/// leaf `index` (their position in the contact list) is rewritten from
+/// `previous_round`, the round they last approved in (0 = never), to the
+/// current one under `root`, one of the tree's recent roots. Remaining
+/// accounts: the proof, one node per account key, leaf to root, as served
+/// by an indexer. A wrong `previous_round` or a stale proof fails the CPI.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApproveRecoveryCompressed<'info>>,
+    root: [u8; 32],
+    previous_round: u32,
+    index: u32,
+) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let contact = ctx.accounts.contact.key();
+    let config = &ctx.accounts.panic_config;
+    let vault = &ctx.accounts.vault;
+    require_approval_open(config, vault, &contact)?;
+    require!(
+        config.contacts.get(index as usize) == Some(&contact),
+        ScreamError::InvalidContact
+    );
+    require!(
+        previous_round != vault.recovery_round,
+        ScreamError::AlreadyApproved
+    );
+
+    let mut data = REPLACE_LEAF.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&alert_leaf(&owner_key, &contact, previous_round));
+    data.extend_from_slice(&alert_leaf(&owner_key, &contact, vault.recovery_round));
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut metas = vec![
+        AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+        AccountMeta::new_readonly(config.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.noop.key(), false),
+    ];
+    metas.extend(
+        ctx.remaining_accounts
+            .iter()
+            .map(|node| AccountMeta::new_readonly(node.key(), false)),
+    );
+    let mut infos = vec![
+        ctx.accounts.merkle_tree.to_account_info(),
+        config.to_account_info(),
+        ctx.accounts.noop.to_account_info(),
+    ];
+    infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke_signed(
+        &Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: metas,
+            data,
+        },
+        &infos,
+        &[&[
+            PanicConfig::SEED_PREFIX,
+            owner_key.as_ref(),
+            config.vault_seed(),
+            &[config.bump],
+        ]],
+    )?;
+
+    let approved = count_approval(
+        &ctx.accounts.panic_config,
+        &mut ctx.accounts.vault,
+        owner_key,
+        contact,
+    );
+    emit_indexed!(ctx.accounts, ctx.bumps, approved);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::emit_indexed;
+use crate::instructions::approve_recovery::record_approval;
+use crate::sigverify::has_precompile_signature;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ApproveRecoveryWithSig<'info> {
+    /// Anyone may relay a contact's signed approval and pay the fee
+    pub relayer: Signer<'info>,
+
+    /// CHECK: The approving contact; authenticated by the Ed25519 signature.
+    pub contact: UncheckedAccount<'info>,
+
+    /// CHECK: The owner whose vault we're approving recovery for. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            AlertAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            contact.key().as_ref(),
+            panic_config.vault_seed(),
+        ],
+        bump = alert_account.bump,
+        constraint = alert_account.owner == owner.key(),
+        constraint = alert_account.contact == contact.key(),
+    )]
+    pub alert_account: Account<'info, AlertAccount>,
+
+    /// CHECK: Instructions sysvar, read to find the contact's Ed25519 signature
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Message a contact signs off-chain to approve recovery for an alert in
+/// one recovery round, so a signature can't be replayed into a later round.
+pub fn approval_message(alert_account: &Pubkey, recovery_round: u32) -> Vec<u8> {
+    [
+        AlertAccount::APPROVAL_SIGNATURE_DOMAIN,
+        alert_account.as_ref(),
+        &recovery_round.to_le_bytes(),
+    ]
+    .concat()
+}
+
+pub fn handler(ctx: Context<ApproveRecoveryWithSig>) -> Result<()> {
+    let signed = has_precompile_signature(
+        &ctx.accounts.instructions_sysvar,
+        &solana_sdk_ids::ed25519_program::ID,
+        ctx.accounts.contact.key().as_ref(),
+        &approval_message(
+            &ctx.accounts.alert_account.key(),
+            ctx.accounts.vault.recovery_round,
+        ),
+    )?;
+    require!(signed, ScreamError::MissingApprovalSignature);
+
+    let approved = record_approval(
+        &ctx.accounts.panic_config,
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.alert_account,
+        ctx.accounts.owner.key(),
+        ctx.accounts.contact.key(),
+    )?;
+    emit_indexed!(ctx.accounts, ctx.bumps, approved);
+    Ok(())
+}
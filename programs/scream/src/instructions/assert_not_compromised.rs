@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct AssertNotCompromised<'info> {
+    /// CHECK: The owner's compromised flag PDA, which need not exist.
+    #[account(
+        seeds = [CompromisedFlag::SEED_PREFIX, owner.as_ref()],
+        bump,
+    )]
+    pub compromised_flag: UncheckedAccount<'info>,
+}
+
+/// Fails when `owner` has panicked. Other programs can CPI in before acting
+/// for a wallet; the account list stays fixed so the call is stable.
+pub fn handler(ctx: Context<AssertNotCompromised>, _owner: Pubkey) -> Result<()> {
+    require!(
+        ctx.accounts.compromised_flag.owner != ctx.program_id,
+        ScreamError::WalletCompromised
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::{WithdrawCancelled, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct CancelWithdraw<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [WithdrawRequest::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = withdraw_request.bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+}
+
+pub fn handler(ctx: Context<CancelWithdraw>) -> Result<()> {
+    emit!(WithdrawCancelled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        amount: ctx.accounts.withdraw_request.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CheckAttacker<'info> {
+    /// CHECK: The address being screened; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    /// CHECK: The address's flag PDA, which need not exist.
+    #[account(
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub attacker_flag: UncheckedAccount<'info>,
+}
+
+/// Read-only screening, returned via return data. Other programs can CPI in
+/// and read the result; clients can simulate it.
+pub fn handler(ctx: Context<CheckAttacker>) -> Result<AttackerStatus> {
+    let flag_info = ctx.accounts.attacker_flag.to_account_info();
+    if flag_info.owner != ctx.program_id {
+        return Ok(AttackerStatus {
+            flagged: false,
+            weight_bps: 0,
+            score: 0,
+        });
+    }
+
+    let flag = AttackerFlag::try_deserialize(&mut &flag_info.try_borrow_data()?[..])?;
+    let weight_bps = flag.weight_bps(Clock::get()?.unix_timestamp);
+    Ok(AttackerStatus {
+        flagged: weight_bps > 0,
+        weight_bps,
+        score: if weight_bps > 0 { flag.score } else { 0 },
+    })
+}
@@ -1,62 +1,321 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ScreamError;
-use crate::events::FundsRecovered;
+use crate::events::{emit_indexed, FeeCollected, FundsRecovered, EVENT_SCHEMA_VERSION};
+use crate::utils::{emit_stats, move_lamports, record_ledger_outflow};
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct ClaimFromVault<'info> {
+    /// The owner, or the config's recovery authority when one is set
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is claimed. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = panic_config.bump,
         has_one = owner,
+        constraint = panic_config.recovery_signer() == authority.key()
+            @ ScreamError::InvalidRecoveryAuthority,
     )]
     pub panic_config: Account<'info, PanicConfig>,
 
     #[account(
         mut,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = vault.bump,
         has_one = owner,
     )]
     pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Receives the protocol fee
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
 }
 
-pub fn handler(ctx: Context<ClaimFromVault>) -> Result<()> {
+/// Claim `amount` lamports, or everything available when `None`, so funds
+/// can be dripped out to fresh wallets. Claims are `CLAIM_COOLDOWN` apart.
+/// An amount of at least `large_claim_threshold` also waits for the large
+/// claim lock, while `None` takes just under it until the lock runs out.
+pub fn handler(ctx: Context<ClaimFromVault>, simulate: bool, amount: Option<u64>) -> Result<()> {
     let config = &ctx.accounts.panic_config;
+    let vault = &ctx.accounts.vault;
+
+    require_claim_ready(config, vault)?;
+
+    // Transfer lamports from vault to the signer (keeping rent-exempt minimum)
+    let vault_info = vault.to_account_info();
+    let authority_info = ctx.accounts.authority.to_account_info();
+
+    let now = Clock::get()?.unix_timestamp;
+    let vault_balance = claimable_lamports(&vault_info)?;
+    let claimable = match amount {
+        Some(amount) => {
+            require!(amount > 0, ScreamError::InvalidWithdrawAmount);
+            require!(amount <= vault_balance, ScreamError::InsufficientVaultBalance);
+            require!(
+                amount <= tranche_claimable(config, vault, vault_balance, now)?,
+                ScreamError::ColdTrancheLocked
+            );
+            require!(
+                amount <= tiered_claimable(config, vault, vault_balance, now)?,
+                ScreamError::SecondLockActive
+            );
+            require_large_claim_unlocked(config, vault, amount, now)?;
+            amount
+        }
+        None => {
+            let claimable = tiered_claimable(config, vault, vault_balance, now)?;
+            large_claim_capped(config, vault, claimable, now)
+        }
+    };
+    let fee_bps = ctx.accounts.protocol_config.fee_bps;
+    let fee = protocol_fee(claimable, fee_bps);
+    let payout = claimable - fee;
+
+    if simulate {
+        emit!(FundsRecovered {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: ctx.accounts.owner.key(),
+            amount: payout,
+        });
+        return err!(ScreamError::SimulationOnly);
+    }
+
+    pay_protocol_fee(
+        &vault_info,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.owner.key(),
+        fee,
+        fee_bps,
+    )?;
+    if payout > 0 {
+        move_lamports(&vault_info, &authority_info, payout)?;
+    }
+
     let vault = &mut ctx.accounts.vault;
+    vault.record_claim(claimable, now);
+
+    record_ledger_outflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        ctx.accounts.authority.key(),
+        now,
+        claimable,
+    )?;
+
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.total_lamports_recovered += payout;
+    emit_stats(stats);
+
+    emit_indexed!(ctx.accounts, ctx.bumps, FundsRecovered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        amount: payout,
+    });
+
+    Ok(())
+}
+
+/// Panic fired, recovery initiated, enough approvals for at least the hot
+/// tranche, nothing left staked or lent, and neither the time-lock, a
+/// guardian veto nor the claim cooldown still running.
+pub(crate) fn require_claim_ready(config: &PanicConfig, vault: &Vault) -> Result<()> {
+    require_approvals(config, vault, config.claim_threshold())?;
+    require!(vault.staked_pool_tokens == 0, ScreamError::VaultFundsStaked);
+    require!(vault.lending_collateral == 0, ScreamError::VaultFundsLent);
 
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= vault.locked_until,
+        ScreamError::TimeLockActive
+    );
+    require!(
+        vault.last_claim_at == 0
+            || clock.unix_timestamp >= vault.last_claim_at + Vault::CLAIM_COOLDOWN,
+        ScreamError::ClaimCooldownActive
+    );
+
+    Ok(())
+}
+
+/// Panic fired, recovery initiated with enough approvals from contacts and
+/// owners, and no guardian veto running: what every claim, SOL or token,
+/// needs before its own time-lock is checked.
+pub(crate) fn require_recovery_approved(config: &PanicConfig, vault: &Vault) -> Result<()> {
+    require_approvals(config, vault, config.recovery_threshold)
+}
+
+/// `require_recovery_approved` with `threshold` contact approvals.
+fn require_approvals(config: &PanicConfig, vault: &Vault, threshold: u8) -> Result<()> {
     require!(config.is_triggered, ScreamError::PanicNotTriggered);
     require!(vault.recovery_initiated, ScreamError::RecoveryNotInitiated);
+    require!(vault.approvals >= threshold, ScreamError::InsufficientApprovals);
+    require!(
+        vault.owner_claim_votes.count_ones() as u8 >= config.owner_claim_quorum,
+        ScreamError::InsufficientOwnerApprovals
+    );
     require!(
-        vault.approvals >= config.recovery_threshold,
-        ScreamError::InsufficientApprovals
+        Clock::get()?.unix_timestamp >= vault.claim_paused_until,
+        ScreamError::ClaimVetoed
     );
+    Ok(())
+}
+
+/// When tokens of `mint` unlock: the SOL time-lock shifted by how much
+/// longer or shorter the mint's own lock is, so guardian extensions move
+/// both. A recovery every contact fast-tracked has no locks left.
+pub(crate) fn token_locked_until(config: &PanicConfig, vault: &Vault, mint: &Pubkey) -> i64 {
+    if fully_fast_tracked(config, vault) {
+        return vault.locked_until;
+    }
+    vault.locked_until + (config.token_time_lock(mint) - config.time_lock_duration)
+}
+
+/// Every contact voted to fast-track the recovery.
+fn fully_fast_tracked(config: &PanicConfig, vault: &Vault) -> bool {
+    !config.contacts.is_empty()
+        && vault.fast_track_votes.count_ones() as usize == config.contacts.len()
+}
+
+/// How much of `claimable` the tranches release. The cold tranche opens
+/// `cold_lock_duration` past the time-lock with `recovery_threshold`
+/// approvals, or once every contact fast-tracked; until then only what is
+/// left of the hot tranche.
+fn tranche_claimable(
+    config: &PanicConfig,
+    vault: &Vault,
+    claimable: u64,
+    now: i64,
+) -> Result<u64> {
+    let cold_open = vault.approvals >= config.recovery_threshold
+        && now >= vault.locked_until + config.cold_lock_duration;
+    if config.hot_threshold == 0 || cold_open || fully_fast_tracked(config, vault) {
+        return Ok(claimable);
+    }
 
-    let clock = Clock::get()?;
     require!(
-        clock.unix_timestamp >= vault.locked_until,
-        ScreamError::TimeLockActive
+        vault.hot_lamports > 0 || claimable == 0,
+        ScreamError::ColdTrancheLocked
     );
+    Ok(claimable.min(vault.hot_lamports))
+}
 
-    // Transfer all lamports from vault to owner (keeping rent-exempt minimum)
-    let vault_info = vault.to_account_info();
-    let owner_info = ctx.accounts.owner.to_account_info();
+/// How much of `claimable` may go out now: what the tranches release,
+/// and with tiered claims, only `early_claim_limit` in total until
+/// `second_lock_duration` past the time-lock, then everything.
+pub(crate) fn tiered_claimable(
+    config: &PanicConfig,
+    vault: &Vault,
+    claimable: u64,
+    now: i64,
+) -> Result<u64> {
+    let claimable = tranche_claimable(config, vault, claimable, now)?;
+    if config.early_claim_limit == 0 || now >= vault.locked_until + config.second_lock_duration {
+        return Ok(claimable);
+    }
+
+    let allowance = config.early_claim_limit.saturating_sub(vault.early_claimed);
+    require!(allowance > 0 || claimable == 0, ScreamError::SecondLockActive);
+    Ok(claimable.min(allowance))
+}
+
+/// A claim of `amount` is under `large_claim_threshold`, or the large claim
+/// lock has run out or was fast-tracked away.
+pub(crate) fn require_large_claim_unlocked(
+    config: &PanicConfig,
+    vault: &Vault,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    require!(
+        amount < config.large_claim_threshold || !large_claim_locked(config, vault, now),
+        ScreamError::LargeClaimLocked
+    );
+    Ok(())
+}
 
-    let vault_balance = vault_info.lamports();
+/// What a claim of everything available may take: `claimable`, kept just
+/// under `large_claim_threshold` while the large claim lock runs, so it
+/// gets what a smaller explicit claim would instead of failing.
+pub(crate) fn large_claim_capped(
+    config: &PanicConfig,
+    vault: &Vault,
+    claimable: u64,
+    now: i64,
+) -> u64 {
+    if large_claim_locked(config, vault, now) {
+        claimable.min(config.large_claim_threshold - 1)
+    } else {
+        claimable
+    }
+}
+
+/// The large claim lock is set, hasn't run out and wasn't fast-tracked away.
+fn large_claim_locked(config: &PanicConfig, vault: &Vault, now: i64) -> bool {
+    config.large_claim_threshold != 0
+        && now < vault.locked_until + config.large_claim_lock_duration
+        && !fully_fast_tracked(config, vault)
+}
+
+/// Vault lamports above its rent-exempt minimum.
+pub(crate) fn claimable_lamports(vault_info: &AccountInfo) -> Result<u64> {
     let rent = Rent::get()?.minimum_balance(vault_info.data_len());
-    let claimable = vault_balance.saturating_sub(rent);
+    Ok(vault_info.lamports().saturating_sub(rent))
+}
+
+/// Protocol fee owed on `amount`, rounded down.
+pub(crate) fn protocol_fee(amount: u64, fee_bps: u16) -> u64 {
+    (amount as u128 * fee_bps as u128 / 10_000) as u64
+}
 
-    if claimable > 0 {
-        **vault_info.try_borrow_mut_lamports()? -= claimable;
-        **owner_info.try_borrow_mut_lamports()? += claimable;
+/// Move `fee` from the vault to the treasury.
+pub(crate) fn pay_protocol_fee(
+    vault_info: &AccountInfo,
+    treasury_info: &AccountInfo,
+    owner: Pubkey,
+    fee: u64,
+    fee_bps: u16,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
     }
 
-    emit!(FundsRecovered {
-        owner: ctx.accounts.owner.key(),
-        amount: claimable,
+    move_lamports(vault_info, treasury_info, fee)?;
+
+    emit!(FeeCollected {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner,
+        amount: fee,
+        fee_bps,
     });
 
     Ok(())
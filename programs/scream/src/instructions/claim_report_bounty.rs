@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ReportBountyPaid, EVENT_SCHEMA_VERSION};
+use crate::utils::system_transfer;
+
+#[derive(Accounts)]
+pub struct ClaimReportBounty<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: The flagged address; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump = attacker_flag.bump,
+        constraint = attacker_flag.reported_by == reporter.key() @ ScreamError::Unauthorized,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    /// CHECK: Must not exist; a flag under dispute earns nothing until the
+    /// dispute is resolved.
+    #[account(
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub dispute: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Pays the bounty
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay the reporter of a flag the protocol's report bounty, once, after
+/// `BOUNTY_CORROBORATIONS` panics by other owners have flagged the same
+/// address. The treasury always keeps its rent floor.
+pub fn handler(ctx: Context<ClaimReportBounty>) -> Result<()> {
+    let flag = &ctx.accounts.attacker_flag;
+    let bounty = ctx.accounts.protocol_config.report_bounty;
+    require!(bounty > 0, ScreamError::NoReportBounty);
+    require!(!flag.bounty_paid, ScreamError::BountyAlreadyPaid);
+    require!(
+        flag.corroborations >= AttackerFlag::BOUNTY_CORROBORATIONS,
+        ScreamError::FlagNotCorroborated
+    );
+    require!(
+        ctx.accounts.dispute.data_is_empty(),
+        ScreamError::DisputePending
+    );
+
+    let available = ctx
+        .accounts
+        .treasury
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(bounty <= available, ScreamError::InsufficientTreasuryBalance);
+
+    system_transfer(
+        &ctx.accounts.treasury.to_account_info(),
+        Some(&[Treasury::SEED_PREFIX, &[ctx.bumps.treasury]]),
+        &ctx.accounts.reporter.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        bounty,
+    )?;
+
+    let flag = &mut ctx.accounts.attacker_flag;
+    flag.bounty_paid = true;
+
+    emit!(ReportBountyPaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: flag.attacker,
+        reporter: flag.reported_by,
+        amount: bounty,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{emit_indexed, ClaimSplitPaid, FundsRecovered, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::{
+    claimable_lamports, large_claim_capped, pay_protocol_fee, protocol_fee, require_claim_ready,
+    tiered_claimable,
+};
+use crate::utils::{emit_stats, move_lamports, record_ledger_outflow};
+
+/// Most destinations one split claim pays out to.
+pub const MAX_CLAIM_SPLITS: usize = 5;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ClaimSplit<'info> {
+    /// The owner, or the config's recovery authority when one is set
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is claimed. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = panic_config.recovery_signer() == authority.key()
+            @ ScreamError::InvalidRecoveryAuthority,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Receives the protocol fee
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+}
+
+/// Claim everything available and split it across the destinations passed
+/// as remaining accounts, `shares_bps[i]` going to the i-th; the first one
+/// picks up the rounding remainder. Like `claim_from_vault`, destinations
+/// aren't held to the withdraw allowlist, which only covers routine outflows.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimSplit<'info>>,
+    shares_bps: Vec<u16>,
+) -> Result<()> {
+    let destinations = ctx.remaining_accounts;
+    require!(
+        !shares_bps.is_empty()
+            && shares_bps.len() <= MAX_CLAIM_SPLITS
+            && shares_bps.len() == destinations.len()
+            && shares_bps.iter().all(|share| *share > 0)
+            && shares_bps.iter().map(|share| *share as u32).sum::<u32>() == 10_000,
+        ScreamError::InvalidClaimSplit
+    );
+
+    let config = &ctx.accounts.panic_config;
+    let vault = &ctx.accounts.vault;
+    require_claim_ready(config, vault)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    for destination in destinations {
+        require!(destination.is_writable, ScreamError::InvalidClaimSplit);
+    }
+
+    let vault_info = vault.to_account_info();
+    let claimable = tiered_claimable(config, vault, claimable_lamports(&vault_info)?, now)?;
+    let claimable = large_claim_capped(config, vault, claimable, now);
+    let fee_bps = ctx.accounts.protocol_config.fee_bps;
+    let fee = protocol_fee(claimable, fee_bps);
+    let payout = claimable - fee;
+
+    pay_protocol_fee(
+        &vault_info,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.owner.key(),
+        fee,
+        fee_bps,
+    )?;
+
+    let owner_key = ctx.accounts.owner.key();
+    let amounts: Vec<u64> = shares_bps
+        .iter()
+        .map(|share| (payout as u128 * *share as u128 / 10_000) as u64)
+        .collect();
+    let remainder = payout - amounts.iter().sum::<u64>();
+    for (i, (destination, share_bps)) in destinations.iter().zip(&shares_bps).enumerate() {
+        let amount = if i == 0 { amounts[i] + remainder } else { amounts[i] };
+        if amount > 0 {
+            move_lamports(&vault_info, destination, amount)?;
+        }
+        // As with `claim_from_vault`, the fee is counted against the claim,
+        // here its first payout, so the entries add up to what left the vault
+        record_ledger_outflow(
+            &ctx.accounts.vault,
+            ctx.accounts.deposit_ledger.as_ref(),
+            destination.key(),
+            now,
+            if i == 0 { amount + fee } else { amount },
+        )?;
+
+        emit!(ClaimSplitPaid {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            destination: destination.key(),
+            share_bps: *share_bps,
+            amount,
+        });
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_claim(claimable, now);
+
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.total_lamports_recovered += payout;
+    emit_stats(stats);
+
+    emit_indexed!(ctx.accounts, ctx.bumps, FundsRecovered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        amount: payout,
+    });
+
+    Ok(())
+}
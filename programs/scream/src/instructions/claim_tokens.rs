@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{emit_indexed, TokensClaimed, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::{require_recovery_approved, token_locked_until};
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ClaimTokens<'info> {
+    /// The owner, or the config's recovery authority when one is set
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is claimed. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = panic_config.recovery_signer() == authority.key()
+            @ ScreamError::InvalidRecoveryAuthority,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The vault's token account being claimed from
+    #[account(
+        mut,
+        constraint = vault_token.owner == vault.key() @ ScreamError::InvalidTokenAccount,
+    )]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == vault_token.mint @ ScreamError::InvalidTokenAccount,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim `amount` of one mint from the vault, or all of it when `None`.
+/// Each mint waits out its own time-lock; approvals and vetoes are shared
+/// with SOL claims.
+pub fn handler(ctx: Context<ClaimTokens>, amount: Option<u64>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    let vault = &ctx.accounts.vault;
+    require_recovery_approved(config, vault)?;
+
+    let mint = ctx.accounts.vault_token.mint;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= token_locked_until(config, vault, &mint),
+        ScreamError::TimeLockActive
+    );
+
+    let balance = ctx.accounts.vault_token.amount;
+    let amount = match amount {
+        Some(amount) => {
+            require!(amount > 0, ScreamError::InvalidWithdrawAmount);
+            amount
+        }
+        None => balance,
+    };
+    require!(amount > 0 && amount <= balance, ScreamError::InsufficientVaultBalance);
+
+    let owner_key = ctx.accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        vault.vault_seed(),
+        &[vault.bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        amount,
+    )?;
+
+    emit_indexed!(ctx.accounts, ctx.bumps, TokensClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AlertClosed, EVENT_SCHEMA_VERSION};
+use crate::utils::close_pda_account;
+
+#[derive(Accounts)]
+pub struct CloseAlerts<'info> {
+    /// The owner, or a contact closing their own alert
+    pub closer: Signer<'info>,
+
+    /// CHECK: The owner, who gets the rent back. Validated via PDA seeds.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Remaining accounts: the alert PDAs to close. Active alerts can only be
+/// closed once funds have been claimed; inactive ones (pre-created or
+/// rehearsal) at any time. A contact may only close their own alert.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseAlerts<'info>>) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let closer = ctx.accounts.closer.key();
+    let claimed = ctx.accounts.vault.last_claim_at != 0;
+    let owner_info = ctx.accounts.owner.to_account_info();
+
+    for alert_info in ctx.remaining_accounts {
+        let alert = Account::<AlertAccount>::try_from(alert_info)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[
+                AlertAccount::SEED_PREFIX,
+                owner_key.as_ref(),
+                alert.contact.as_ref(),
+                ctx.accounts.vault.vault_seed(),
+            ],
+            ctx.program_id,
+        );
+        require!(
+            alert_info.key() == expected && alert.owner == owner_key,
+            ScreamError::ContactAccountMismatch
+        );
+        require!(
+            closer == owner_key || closer == alert.contact,
+            ScreamError::InvalidContact
+        );
+        require!(claimed || !alert.is_active, ScreamError::AlertNotClosable);
+
+        close_pda_account(alert_info, &owner_info)?;
+
+        emit!(AlertClosed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            contact: alert.contact,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{FlagCleared, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct CloseAttackerFlag<'info> {
+    /// The reporter recanting, or the dispute arbiter
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    /// CHECK: The flagged address; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump = attacker_flag.bump,
+        close = reporter,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    /// CHECK: Whoever filed the flag and paid its rent; gets the rent back.
+    #[account(mut, address = attacker_flag.reported_by)]
+    pub reporter: UncheckedAccount<'info>,
+
+    /// CHECK: Must not exist; a pending dispute is settled with
+    /// `resolve_dispute` instead.
+    #[account(
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub dispute: UncheckedAccount<'info>,
+}
+
+/// Withdraw a flag before it expires: the reporter can recant their own,
+/// and the arbiter can remove any, e.g. after reviewing it off-chain.
+pub fn handler(ctx: Context<CloseAttackerFlag>) -> Result<()> {
+    let flag = &ctx.accounts.attacker_flag;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == flag.reported_by || authority == ctx.accounts.dispute_config.arbiter,
+        ScreamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.dispute.data_is_empty(),
+        ScreamError::DisputePending
+    );
+
+    emit!(FlagCleared {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: flag.attacker,
+        reported_by: flag.reported_by,
+        cleared_by: authority,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::state::*;
-use crate::events::Deposited;
+use crate::errors::ScreamError;
+use crate::events::{Deposited, EVENT_SCHEMA_VERSION};
+use crate::utils::record_ledger_inflow;
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -9,7 +11,7 @@ pub struct Deposit<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = panic_config.bump,
         has_one = owner,
     )]
@@ -17,12 +19,27 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = vault.bump,
         has_one = owner,
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ ScreamError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -38,8 +55,18 @@ pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    record_ledger_inflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        ctx.accounts.owner.key(),
+        Clock::get()?.unix_timestamp,
+        amount,
+    )?;
+
     emit!(Deposited {
+        schema_version: EVENT_SCHEMA_VERSION,
         owner: ctx.accounts.owner.key(),
+        depositor: ctx.accounts.owner.key(),
         amount,
         vault_balance: ctx.accounts.vault.to_account_info().lamports(),
     });
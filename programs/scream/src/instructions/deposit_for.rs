@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{Deposited, EVENT_SCHEMA_VERSION};
+use crate::utils::record_ledger_inflow;
+
+#[derive(Accounts)]
+pub struct DepositFor<'info> {
+    /// Anyone funding the owner's vault, e.g. an employer or family member
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: The owner whose vault receives the deposit. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ ScreamError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositFor>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    record_ledger_inflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        ctx.accounts.depositor.key(),
+        Clock::get()?.unix_timestamp,
+        amount,
+    )?;
+
+    emit!(Deposited {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        vault_balance: ctx.accounts.vault.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::{FlagDisputed, EVENT_SCHEMA_VERSION};
+use crate::utils::system_transfer;
+
+#[derive(Accounts)]
+pub struct DisputeFlag<'info> {
+    /// The flagged address; signing proves the dispute comes from its holder
+    #[account(mut)]
+    pub attacker: Signer<'info>,
+
+    #[account(
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump = attacker_flag.bump,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        init,
+        payer = attacker,
+        space = 8 + FlagDispute::INIT_SPACE,
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub dispute: Account<'info, FlagDispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a dispute against the caller's own flag, locking the configured bond
+/// in the dispute account until the arbiter rules or the window lapses.
+pub fn handler(ctx: Context<DisputeFlag>) -> Result<()> {
+    let bond_lamports = ctx.accounts.dispute_config.bond_lamports;
+    if bond_lamports > 0 {
+        system_transfer(
+            &ctx.accounts.attacker.to_account_info(),
+            None,
+            &ctx.accounts.dispute.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            bond_lamports,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.attacker = ctx.accounts.attacker.key();
+    dispute.bond_lamports = bond_lamports;
+    dispute.opened_at = now;
+    dispute.deadline = now + ctx.accounts.dispute_config.dispute_window;
+    dispute.bump = ctx.bumps.dispute;
+
+    emit!(FlagDisputed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: dispute.attacker,
+        reported_by: ctx.accounts.attacker_flag.reported_by,
+        bond_lamports,
+        deadline: dispute.deadline,
+    });
+
+    Ok(())
+}
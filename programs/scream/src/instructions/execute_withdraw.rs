@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WithdrawExecuted, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::utils::{move_lamports, record_ledger_outflow};
+
+#[derive(Accounts)]
+pub struct ExecuteWithdraw<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [WithdrawRequest::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = withdraw_request.bump,
+        has_one = owner,
+        has_one = destination,
+        close = owner,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+
+    /// CHECK: Must match the destination fixed at request time.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Required once the vault's policy demands an allowlist
+    #[account(
+        seeds = [WithdrawAllowlist::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = withdraw_allowlist.bump,
+    )]
+    pub withdraw_allowlist: Option<Account<'info, WithdrawAllowlist>>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+}
+
+pub fn handler(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+    // A panic after the request voids it
+    require!(!ctx.accounts.panic_config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let now = Clock::get()?.unix_timestamp;
+    let request = &ctx.accounts.withdraw_request;
+    require!(now >= request.executable_at, ScreamError::WithdrawDelayActive);
+
+    let vault = &mut ctx.accounts.vault;
+    require!(now >= vault.locked_until, ScreamError::TimeLockActive);
+    vault.settle_policy(now);
+    require_allowed_destination(
+        vault,
+        ctx.accounts.withdraw_allowlist.as_ref(),
+        &request.destination,
+        now,
+    )?;
+
+    require!(
+        vault.record_withdrawal(request.amount, now),
+        ScreamError::DailyWithdrawLimitExceeded
+    );
+
+    let vault_info = vault.to_account_info();
+    require!(
+        request.amount <= claimable_lamports(&vault_info)?,
+        ScreamError::InsufficientVaultBalance
+    );
+
+    let amount = request.amount;
+    move_lamports(&vault_info, &ctx.accounts.destination.to_account_info(), amount)?;
+
+    record_ledger_outflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        request.destination,
+        now,
+        amount,
+    )?;
+
+    emit!(WithdrawExecuted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        destination: request.destination,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// With `require_allowlist` set, routine outflows may only go to active
+/// allowlist entries.
+pub(crate) fn require_allowed_destination(
+    vault: &Vault,
+    allowlist: Option<&Account<WithdrawAllowlist>>,
+    destination: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    if vault.withdraw_policy.require_allowlist {
+        require!(
+            allowlist.is_some_and(|list| list.allows(destination, now)),
+            ScreamError::DestinationNotAllowlisted
+        );
+    }
+    Ok(())
+}
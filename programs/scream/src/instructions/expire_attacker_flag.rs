@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AttackerFlagExpired, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct ExpireAttackerFlag<'info> {
+    /// Anyone may garbage-collect an expired flag
+    pub cranker: Signer<'info>,
+
+    /// CHECK: The flagged address; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump = attacker_flag.bump,
+        close = reporter,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    /// CHECK: Whoever filed the flag and paid its rent; gets the rent back.
+    #[account(mut, address = attacker_flag.reported_by)]
+    pub reporter: UncheckedAccount<'info>,
+
+    /// CHECK: Must not exist, so a pending dispute keeps the flag it targets.
+    #[account(
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub dispute: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireAttackerFlag>) -> Result<()> {
+    let flag = &ctx.accounts.attacker_flag;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        flag.expires_at != 0 && now >= flag.expires_at,
+        ScreamError::FlagNotExpired
+    );
+    require!(
+        ctx.accounts.dispute.data_is_empty(),
+        ScreamError::DisputePending
+    );
+
+    emit!(AttackerFlagExpired {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: flag.attacker,
+        reported_by: flag.reported_by,
+        flagged_at: flag.flagged_at,
+    });
+
+    Ok(())
+}
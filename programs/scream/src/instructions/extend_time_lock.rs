@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{LockExtensionVoted, TimeLockExtended, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct ExtendTimeLock<'info> {
+    pub contact: Signer<'info>,
+
+    /// CHECK: The owner whose vault stays locked. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Vote to keep the vault locked while the situation is unresolved. Once
+/// `recovery_threshold` contacts agree, the lock runs `LOCK_EXTENSION` past
+/// the later of now and `locked_until`, up to `MAX_LOCK_EXTENSION` in total,
+/// and voting starts over.
+pub fn handler(ctx: Context<ExtendTimeLock>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(config.is_triggered, ScreamError::PanicNotTriggered);
+
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        vault.lock_extended_by < Vault::MAX_LOCK_EXTENSION,
+        ScreamError::LockExtensionExhausted
+    );
+
+    let contact = ctx.accounts.contact.key();
+    let index = config
+        .contacts
+        .iter()
+        .position(|c| *c == contact)
+        .ok_or(ScreamError::InvalidContact)?;
+    require!(
+        vault.extension_votes & (1 << index) == 0,
+        ScreamError::AlreadyVotedExtension
+    );
+
+    vault.extension_votes |= 1 << index;
+    let votes = vault.extension_votes.count_ones() as u8;
+
+    emit!(LockExtensionVoted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        contact,
+        votes,
+        threshold: config.recovery_threshold,
+    });
+
+    if votes >= config.recovery_threshold {
+        let now = Clock::get()?.unix_timestamp;
+        let extension =
+            Vault::LOCK_EXTENSION.min(Vault::MAX_LOCK_EXTENSION - vault.lock_extended_by);
+        vault.locked_until = vault.locked_until.max(now) + extension;
+        vault.lock_extended_by += extension;
+        vault.extension_votes = 0;
+
+        emit!(TimeLockExtended {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: config.owner,
+            locked_until: vault.locked_until,
+            lock_extended_by: vault.lock_extended_by,
+        });
+    }
+
+    Ok(())
+}
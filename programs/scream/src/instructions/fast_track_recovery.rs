@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{FastTrackVoted, RecoveryFastTracked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct FastTrackRecovery<'info> {
+    pub contact: Signer<'info>,
+
+    /// CHECK: The owner whose recovery is fast-tracked. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Vote to skip the rest of the time-lock. The lock only guards against a
+/// coerced owner, so once every contact has voted it is lifted and recovery
+/// counts as initiated and fully approved; the owner can claim right away.
+pub fn handler(ctx: Context<FastTrackRecovery>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(config.is_triggered, ScreamError::PanicNotTriggered);
+
+    let vault = &mut ctx.accounts.vault;
+    let contact = ctx.accounts.contact.key();
+    let index = config
+        .contacts
+        .iter()
+        .position(|c| *c == contact)
+        .ok_or(ScreamError::InvalidContact)?;
+    require!(
+        vault.fast_track_votes & (1 << index) == 0,
+        ScreamError::AlreadyVotedFastTrack
+    );
+
+    vault.fast_track_votes |= 1 << index;
+    let votes = vault.fast_track_votes.count_ones() as u8;
+    let contacts = config.contacts.len() as u8;
+
+    emit!(FastTrackVoted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        contact,
+        votes,
+        contacts,
+    });
+
+    if votes == contacts {
+        let now = Clock::get()?.unix_timestamp;
+        vault.locked_until = vault.locked_until.min(now);
+        if !vault.recovery_initiated {
+            // The votes that opened the round count toward it
+            let fast_track_votes = vault.fast_track_votes;
+            vault.start_recovery_round(now);
+            vault.fast_track_votes = fast_track_votes;
+        }
+        vault.approvals = vault.approvals.max(contacts);
+
+        emit!(RecoveryFastTracked {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: config.owner,
+            approvals: vault.approvals,
+        });
+    }
+
+    Ok(())
+}
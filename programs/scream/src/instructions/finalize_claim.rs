@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{emit_indexed, ClaimFinalized, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::{
+    claimable_lamports, large_claim_capped, pay_protocol_fee, protocol_fee, require_claim_ready,
+    tiered_claimable,
+};
+use crate::utils::{emit_stats, move_lamports, pay_keeper_tip, record_ledger_outflow};
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct FinalizeClaim<'info> {
+    /// Any keeper may push a ready claim through, for the config's keeper tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: The owner whose vault is being finalized. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+        constraint = panic_config.recovery_destination != Pubkey::default()
+            @ ScreamError::NoRecoveryDestination,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Receives the protocol fee
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: Must be the destination pre-set on the config.
+    #[account(mut, address = panic_config.recovery_destination)]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+
+    /// Pays the keeper tip
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FinalizeClaim>) -> Result<()> {
+    require_claim_ready(&ctx.accounts.panic_config, &ctx.accounts.vault)?;
+
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let destination_info = ctx.accounts.destination.to_account_info();
+
+    let now = Clock::get()?.unix_timestamp;
+    let claimable = tiered_claimable(
+        &ctx.accounts.panic_config,
+        &ctx.accounts.vault,
+        claimable_lamports(&vault_info)?,
+        now,
+    )?;
+    let claimable = large_claim_capped(
+        &ctx.accounts.panic_config,
+        &ctx.accounts.vault,
+        claimable,
+        now,
+    );
+    let fee_bps = ctx.accounts.protocol_config.fee_bps;
+    let fee = protocol_fee(claimable, fee_bps);
+    let payout = claimable - fee;
+
+    pay_protocol_fee(
+        &vault_info,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.owner.key(),
+        fee,
+        fee_bps,
+    )?;
+    if payout > 0 {
+        move_lamports(&vault_info, &destination_info, payout)?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_claim(claimable, now);
+
+    record_ledger_outflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        ctx.accounts.destination.key(),
+        now,
+        claimable,
+    )?;
+
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.total_lamports_recovered += payout;
+    emit_stats(stats);
+
+    let owner_key = ctx.accounts.owner.key();
+    pay_keeper_tip(
+        &ctx.accounts.fee_reserve.to_account_info(),
+        &[
+            FeeReserve::SEED_PREFIX,
+            owner_key.as_ref(),
+            ctx.accounts.panic_config.vault_seed(),
+            &[ctx.bumps.fee_reserve],
+        ],
+        &ctx.accounts.keeper.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        owner_key,
+        ctx.accounts.panic_config.keeper_tip,
+    )?;
+
+    emit_indexed!(ctx.accounts, ctx.bumps, ClaimFinalized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        destination: ctx.accounts.destination.key(),
+        keeper: ctx.accounts.keeper.key(),
+        amount: payout,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AccountCollected, EVENT_SCHEMA_VERSION};
+use crate::utils::{close_pda_account, move_lamports};
+
+/// Lamports of each collected account's rent paid to the caller.
+pub const GC_TIP_LAMPORTS: u64 = 10_000;
+
+#[derive(Accounts)]
+pub struct Gc<'info> {
+    /// Anyone may collect stale accounts, for a small tip
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+/// Remaining accounts, one group per account to collect:
+/// - a stale AlertAccount (see `AlertAccount::is_stale`), then its owner;
+/// - an expired AttackerFlag, then its reporter, then its FlagDispute PDA,
+///   which must not exist.
+///
+/// Each account's rent goes back to whoever paid it, less `GC_TIP_LAMPORTS`
+/// for the caller. Anything that isn't stale fails the whole call.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, Gc<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let cranker = ctx.accounts.cranker.to_account_info();
+
+    let mut accounts = ctx.remaining_accounts;
+    while let Some((target, rest)) = accounts.split_first() {
+        require_keys_eq!(*target.owner, *ctx.program_id, ScreamError::AccountNotCollectable);
+        let is_alert = target.try_borrow_data()?.starts_with(AlertAccount::DISCRIMINATOR);
+
+        let recipient = if is_alert {
+            let alert = Account::<AlertAccount>::try_from(target)?;
+            let recipient = rest.first().ok_or(ScreamError::AccountNotCollectable)?;
+            require!(
+                alert.is_stale(now) && recipient.key() == alert.owner,
+                ScreamError::AccountNotCollectable
+            );
+            accounts = &rest[1..];
+            recipient
+        } else {
+            let flag = Account::<AttackerFlag>::try_from(target)?;
+            let [recipient, dispute, ..] = rest else {
+                return err!(ScreamError::AccountNotCollectable);
+            };
+            let (dispute_pda, _) = Pubkey::find_program_address(
+                &[FlagDispute::SEED_PREFIX, flag.attacker.as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                flag.expires_at != 0
+                    && now >= flag.expires_at
+                    && recipient.key() == flag.reported_by
+                    && dispute.key() == dispute_pda,
+                ScreamError::AccountNotCollectable
+            );
+            require!(dispute.data_is_empty(), ScreamError::DisputePending);
+            accounts = &rest[2..];
+            recipient
+        };
+
+        let lamports = target.lamports();
+        let tip = GC_TIP_LAMPORTS.min(lamports);
+        move_lamports(target, &cranker, tip)?;
+        close_pda_account(target, recipient)?;
+
+        emit!(AccountCollected {
+            schema_version: EVENT_SCHEMA_VERSION,
+            account: target.key(),
+            recipient: recipient.key(),
+            lamports: lamports - tip,
+            tip,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::instructions::vault_status::GetVaultStatus;
+
+/// The config without its trigger hash, with claimable lamports and time
+/// remaining worked out; returned via return data and meant to be simulated.
+pub fn handler(ctx: Context<GetVaultStatus>) -> Result<ConfigView> {
+    let config = &ctx.accounts.panic_config;
+    let vault = &ctx.accounts.vault;
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ConfigView {
+        owner: config.owner,
+        trigger_mode: config.trigger_mode,
+        contacts: config.contacts.clone(),
+        recovery_threshold: config.recovery_threshold,
+        time_lock_duration: config.time_lock_duration,
+        decoy_lamports: config.decoy_lamports,
+        decoy_mode: config.decoy_mode,
+        decoy_jitter_bps: config.decoy_jitter_bps,
+        decoy_drip_installments: config.decoy_drip_installments,
+        decoy_drip_interval: config.decoy_drip_interval,
+        decoy_token_mint: config.decoy_token_mint,
+        decoy_token_amount: config.decoy_token_amount,
+        balance_buffer: config.balance_buffer,
+        veto_quorum: config.veto_quorum,
+        veto_duration: config.veto_duration,
+        early_claim_limit: config.early_claim_limit,
+        second_lock_duration: config.second_lock_duration,
+        large_claim_threshold: config.large_claim_threshold,
+        large_claim_lock_duration: config.large_claim_lock_duration,
+        hot_threshold: config.hot_threshold,
+        cold_lock_duration: config.cold_lock_duration,
+        recovery_window: config.recovery_window,
+        keeper_tip: config.keeper_tip,
+        watcher: config.watcher,
+        attestor: config.attestor,
+        alert_tree: config.alert_tree,
+        is_triggered: config.is_triggered,
+        alerted_contacts: config.alerted_contacts,
+        max_failed_attempts: config.max_failed_attempts,
+        lockout_duration: config.lockout_duration,
+        soft_lock_on_lockout: config.soft_lock_on_lockout,
+        failed_attempts: config.failed_attempts,
+        trigger_locked_until: config.trigger_locked_until,
+        presign_nonce_account: config.presign_nonce_account,
+        presign_nonce_authority: config.presign_nonce_authority,
+        recovery_destination: config.recovery_destination,
+        recovery_authority: config.recovery_authority,
+        pending_owner: config.pending_owner,
+        co_owners: config.co_owners.clone(),
+        owner_claim_quorum: config.owner_claim_quorum,
+        linked_wallets: config.linked_wallets.clone(),
+        delegated_token_accounts: config.delegated_token_accounts.clone(),
+        vault_index: config.vault_index,
+        version: config.version,
+        claimable_lamports: claimable_lamports(&vault.to_account_info())?,
+        time_lock_remaining: vault.locked_until.saturating_sub(now).max(0),
+        trigger_lockout_remaining: config.trigger_locked_until.saturating_sub(now).max(0),
+    })
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_sha256_hasher::hashv;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AlertTreeInitialized, EVENT_SCHEMA_VERSION};
+use crate::instructions::init_compressed_registry::{
+    ACCOUNT_COMPRESSION_PROGRAM_ID, APPEND, INIT_EMPTY_MERKLE_TREE, NOOP_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+pub struct InitAlertTree<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// CHECK: Allocated to the compression program, zeroed, earlier in the
+    /// transaction; the compression program checks its size.
+    #[account(mut, owner = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-account-compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// Switch the config to compressed alerts: a freshly allocated tree, with
+/// the config PDA as its authority, gets one never-approved leaf per
+/// contact, leaf `i` belonging to `contacts[i]`. Panic then alerts contacts
+/// by event instead of creating AlertAccounts, and they approve recovery
+/// with `approve_recovery_compressed`. One-way; frozen once panic fires.
+pub fn handler(ctx: Context<InitAlertTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        config.alert_tree == Pubkey::default(),
+        ScreamError::AlertTreeAlreadySet
+    );
+
+    let owner_key = ctx.accounts.owner.key();
+    let config_seeds: &[&[u8]] = &[
+        PanicConfig::SEED_PREFIX,
+        owner_key.as_ref(),
+        config.vault_seed(),
+        &[config.bump],
+    ];
+    let metas = vec![
+        AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+        AccountMeta::new_readonly(config.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.noop.key(), false),
+    ];
+    let infos = [
+        ctx.accounts.merkle_tree.to_account_info(),
+        config.to_account_info(),
+        ctx.accounts.noop.to_account_info(),
+    ];
+
+    let mut data = INIT_EMPTY_MERKLE_TREE.to_vec();
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    invoke_signed(
+        &Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: metas.clone(),
+            data,
+        },
+        &infos,
+        &[config_seeds],
+    )?;
+
+    for contact in &config.contacts {
+        let mut data = APPEND.to_vec();
+        data.extend_from_slice(&alert_leaf(&owner_key, contact, 0));
+        invoke_signed(
+            &Instruction {
+                program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+                accounts: metas.clone(),
+                data,
+            },
+            &infos,
+            &[config_seeds],
+        )?;
+    }
+
+    ctx.accounts.panic_config.alert_tree = ctx.accounts.merkle_tree.key();
+
+    emit!(AlertTreeInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        max_depth,
+        max_buffer_size,
+    });
+
+    Ok(())
+}
+
+/// The tree leaf for `contact`'s alert, last approved in `approved_round`.
+pub(crate) fn alert_leaf(owner: &Pubkey, contact: &Pubkey, approved_round: u32) -> [u8; 32] {
+    hashv(&[
+        AlertAccount::COMPRESSED_LEAF_DOMAIN,
+        owner.as_ref(),
+        contact.as_ref(),
+        &approved_round.to_le_bytes(),
+    ])
+    .to_bytes()
+}
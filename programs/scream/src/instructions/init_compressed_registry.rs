@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+use crate::events::{CompressedRegistryInitialized, EVENT_SCHEMA_VERSION};
+
+/// The spl-account-compression program.
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+/// The spl-noop program the compression program logs changes through.
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Anchor discriminator of the compression program's `init_empty_merkle_tree`.
+pub(crate) const INIT_EMPTY_MERKLE_TREE: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+/// Anchor discriminator of `append`.
+pub(crate) const APPEND: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+/// Anchor discriminator of `replace_leaf`.
+pub(crate) const REPLACE_LEAF: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+/// Anchor discriminator of `verify_leaf`.
+pub(crate) const VERIFY_LEAF: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+#[derive(Accounts)]
+pub struct InitCompressedRegistry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CompressedRegistry::INIT_SPACE,
+        seeds = [CompressedRegistry::SEED_PREFIX],
+        bump,
+    )]
+    pub compressed_registry: Account<'info, CompressedRegistry>,
+
+    /// CHECK: Allocated to the compression program, zeroed, earlier in the
+    /// transaction; the compression program checks its size.
+    #[account(mut, owner = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-account-compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set up the compressed attacker registry on a freshly allocated tree,
+/// with the registry PDA as its authority. A tree of depth `max_depth`
+/// holds `2^max_depth` flags; `max_buffer_size` bounds how many appends
+/// can land in one slot without invalidating each other's proofs.
+pub fn handler(
+    ctx: Context<InitCompressedRegistry>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.compressed_registry;
+    registry.merkle_tree = ctx.accounts.merkle_tree.key();
+    registry.leaves = 0;
+    registry.bump = ctx.bumps.compressed_registry;
+
+    let mut data = INIT_EMPTY_MERKLE_TREE.to_vec();
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    let ix = Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+            AccountMeta::new_readonly(registry.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.noop.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            registry.to_account_info(),
+            ctx.accounts.noop.to_account_info(),
+        ],
+        &[&[CompressedRegistry::SEED_PREFIX, &[registry.bump]]],
+    )?;
+
+    emit!(CompressedRegistryInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        max_depth,
+        max_buffer_size,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitDepositLedger<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + std::mem::size_of::<DepositLedger>(),
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: AccountLoader<'info, DepositLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opt in to an on-chain history of vault deposits and claims. From then
+/// on every deposit, claim and withdrawal must pass the ledger.
+pub fn handler(ctx: Context<InitDepositLedger>) -> Result<()> {
+    let mut ledger = ctx.accounts.deposit_ledger.load_init()?;
+    ledger.owner = ctx.accounts.owner.key();
+    ctx.accounts.vault.has_deposit_ledger = true;
+
+    Ok(())
+}
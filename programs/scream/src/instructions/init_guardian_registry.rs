@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitGuardianRegistry<'info> {
+    /// The contact opting in to be discoverable
+    #[account(mut)]
+    pub contact: Signer<'info>,
+
+    #[account(
+        init,
+        payer = contact,
+        space = 8 + GuardianRegistry::INIT_SPACE,
+        seeds = [GuardianRegistry::SEED_PREFIX, contact.key().as_ref()],
+        bump,
+    )]
+    pub guardian_registry: Account<'info, GuardianRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitGuardianRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.guardian_registry;
+    registry.contact = ctx.accounts.contact.key();
+    registry.owners = Vec::new();
+    registry.bump = ctx.bumps.guardian_registry;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitPanicHooks<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PanicHooks::INIT_SPACE,
+        seeds = [PanicHooks::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub panic_hooks: Account<'info, PanicHooks>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitPanicHooks>) -> Result<()> {
+    let panic_hooks = &mut ctx.accounts.panic_hooks;
+    panic_hooks.owner = ctx.accounts.owner.key();
+    panic_hooks.hooks = Vec::new();
+    panic_hooks.bump = ctx.bumps.panic_hooks;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitWithdrawAllowlist<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + WithdrawAllowlist::INIT_SPACE,
+        seeds = [WithdrawAllowlist::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump,
+    )]
+    pub withdraw_allowlist: Account<'info, WithdrawAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitWithdrawAllowlist>) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdraw_allowlist;
+    allowlist.owner = ctx.accounts.owner.key();
+    allowlist.entries = Vec::new();
+    allowlist.bump = ctx.bumps.withdraw_allowlist;
+
+    Ok(())
+}
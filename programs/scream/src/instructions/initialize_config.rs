@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ScreamError;
-use crate::events::ConfigInitialized;
+use crate::events::{ConfigInitialized, EVENT_SCHEMA_VERSION};
+use crate::instructions::alert_contact::match_alert_accounts;
+use crate::utils::{emit_stats, init_pda_account, panic_rent_cost, system_transfer};
 
 #[derive(Accounts)]
+#[instruction(vault_index: u8, vault_name: String)]
 pub struct InitializeConfig<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -12,7 +15,11 @@ pub struct InitializeConfig<'info> {
         init,
         payer = owner,
         space = 8 + PanicConfig::INIT_SPACE,
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [
+            PanicConfig::SEED_PREFIX,
+            owner.key().as_ref(),
+            vault_seed(&vault_index, &vault_name_hash(&vault_name)),
+        ],
         bump,
     )]
     pub panic_config: Account<'info, PanicConfig>,
@@ -21,36 +28,165 @@ pub struct InitializeConfig<'info> {
         init,
         payer = owner,
         space = 8 + Vault::INIT_SPACE,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [
+            Vault::SEED_PREFIX,
+            owner.key().as_ref(),
+            vault_seed(&vault_index, &vault_name_hash(&vault_name)),
+        ],
         bump,
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Funded here so panic-time rent never depends on the owner's balance
+    #[account(
+        mut,
+        seeds = [
+            FeeReserve::SEED_PREFIX,
+            owner.key().as_ref(),
+            vault_seed(&vault_index, &vault_name_hash(&vault_name)),
+        ],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ ScreamError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<InitializeConfig>,
+/// `vault_index` picks which of the owner's configs this is; 0 is the one
+/// every owner had before configs were indexed. A non-empty `vault_name`
+/// seeds the config by name instead, and then `vault_index` must be 0.
+///
+/// Remaining accounts (optional): alert PDAs for any subset of `contacts`.
+/// They are created inactive, paid by the owner, so `trigger_panic` only
+/// has to flip them active instead of allocating them under duress.
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializeConfig<'info>>,
+    vault_index: u8,
+    vault_name: String,
     trigger_hash: [u8; 32],
     contacts: Vec<Pubkey>,
     recovery_threshold: u8,
     time_lock_duration: i64,
     decoy_lamports: u64,
+    decoy_memo: String,
+    label: String,
 ) -> Result<()> {
-    require!(contacts.len() <= 5, ScreamError::TooManyContacts);
+    let protocol_config = &ctx.accounts.protocol_config;
+    require!(
+        contacts.len() <= protocol_config.max_contacts as usize,
+        ScreamError::TooManyContacts
+    );
+    require!(
+        time_lock_duration >= protocol_config.min_time_lock
+            && time_lock_duration <= protocol_config.max_time_lock,
+        ScreamError::InvalidTimeLock
+    );
     require!(
         recovery_threshold <= contacts.len() as u8,
         ScreamError::InvalidThreshold
     );
+    require!(
+        recovery_threshold > 0 || contacts.is_empty(),
+        ScreamError::ZeroRecoveryThreshold
+    );
+    let owner = ctx.accounts.owner.key();
+    for (i, contact) in contacts.iter().enumerate() {
+        require!(*contact != owner, ScreamError::OwnerIsContact);
+        require!(!contacts[..i].contains(contact), ScreamError::DuplicateContact);
+    }
+    require!(decoy_lamports > 0, ScreamError::ZeroDecoyAmount);
+    require!(
+        decoy_lamports <= PanicConfig::MAX_DECOY_LAMPORTS,
+        ScreamError::DecoyAmountTooLarge
+    );
+    require!(
+        decoy_memo.len() <= PanicConfig::MAX_DECOY_MEMO_LEN,
+        ScreamError::DecoyMemoTooLong
+    );
+    require!(label.len() <= PanicConfig::MAX_LABEL_LEN, ScreamError::LabelTooLong);
+    require!(
+        vault_name.len() <= PanicConfig::MAX_VAULT_NAME_LEN,
+        ScreamError::VaultNameTooLong
+    );
+    require!(
+        vault_name.is_empty() || vault_index == 0,
+        ScreamError::NamedVaultIndexed
+    );
+    let name_hash = if vault_name.is_empty() {
+        [0; 32]
+    } else {
+        vault_name_hash(&vault_name)
+    };
 
     let config = &mut ctx.accounts.panic_config;
-    config.owner = ctx.accounts.owner.key();
+    config.owner = owner;
     config.trigger_hash = trigger_hash;
+    config.trigger_mode = TriggerMode::Preimage;
+    config.trigger_nonce = 0;
+    config.passkey_sign_count = 0;
     config.contacts = contacts.clone();
     config.recovery_threshold = recovery_threshold;
     config.time_lock_duration = time_lock_duration;
     config.decoy_lamports = decoy_lamports;
+    config.decoy_mode = DecoyMode::Fixed;
+    config.decoy_jitter_bps = 0;
+    config.decoy_drip_installments = 0;
+    config.decoy_drip_interval = 0;
+    config.decoy_token_mint = Pubkey::default();
+    config.decoy_token_amount = 0;
+    config.decoy_memo = decoy_memo;
+    config.balance_buffer = PanicConfig::DEFAULT_BALANCE_BUFFER;
+    config.alert_uri_template = String::new();
+    config.veto_quorum = PanicConfig::DEFAULT_VETO_QUORUM;
+    config.veto_duration = PanicConfig::DEFAULT_VETO_DURATION;
+    config.early_claim_limit = 0;
+    config.second_lock_duration = 0;
+    config.large_claim_threshold = 0;
+    config.large_claim_lock_duration = 0;
+    config.token_time_locks = Vec::new();
+    config.hot_threshold = 0;
+    config.cold_lock_duration = 0;
+    config.recovery_window = 0;
+    config.keeper_tip = 0;
+    config.watcher = Pubkey::default();
+    config.attestor = Pubkey::default();
+    config.alert_tree = Pubkey::default();
+    config.recovery_authority = Pubkey::default();
+    config.pending_owner = Pubkey::default();
+    config.co_owners = Vec::new();
+    config.owner_claim_quorum = 0;
+    config.linked_wallets = Vec::new();
+    config.delegated_token_accounts = Vec::new();
+    config.label = label;
+    config.vault_index = vault_index;
+    config.vault_name = vault_name.clone();
+    config.vault_name_hash = name_hash;
     config.is_triggered = false;
+    config.alerted_contacts = 0;
+    config.max_failed_attempts = PanicConfig::DEFAULT_MAX_FAILED_ATTEMPTS;
+    config.lockout_duration = PanicConfig::DEFAULT_LOCKOUT_DURATION;
+    config.soft_lock_on_lockout = false;
+    config.failed_attempts = 0;
+    config.trigger_locked_until = 0;
+    config.presign_nonce_account = Pubkey::default();
+    config.presign_nonce_authority = Pubkey::default();
+    config.recovery_destination = Pubkey::default();
+    config.version = PanicConfig::CURRENT_VERSION;
     config.bump = ctx.bumps.panic_config;
 
     let vault = &mut ctx.accounts.vault;
@@ -58,13 +194,109 @@ pub fn handler(
     vault.locked_until = 0;
     vault.recovery_initiated = false;
     vault.approvals = 0;
+    vault.recovery_round = 0;
+    vault.recovery_initiated_at = 0;
+    vault.withdraw_policy = WithdrawPolicy::default();
+    vault.pending_policy = WithdrawPolicy::default();
+    vault.pending_policy_at = 0;
+    vault.spend_period_start = 0;
+    vault.spent_in_period = 0;
+    vault.withdrawn_in_window = 0;
+    vault.window_updated_at = 0;
+    vault.drip_recipient = Pubkey::default();
+    vault.drip_remaining = 0;
+    vault.drip_installments_left = 0;
+    vault.drip_next_at = 0;
+    vault.veto_votes = 0;
+    vault.claim_paused_until = 0;
+    vault.extension_votes = 0;
+    vault.lock_extended_by = 0;
+    vault.fast_track_votes = 0;
+    vault.early_claimed = 0;
+    vault.hot_lamports = 0;
+    vault.last_claim_at = 0;
+    vault.owner_claim_votes = 0;
+    vault.stake_pool = Pubkey::default();
+    vault.staked_lamports = 0;
+    vault.staked_pool_tokens = 0;
+    vault.lending_reserve = Pubkey::default();
+    vault.lent_lamports = 0;
+    vault.lending_collateral = 0;
+    vault.has_deposit_ledger = false;
+    vault.vault_index = vault_index;
+    vault.vault_name_hash = name_hash;
+    vault.version = Vault::CURRENT_VERSION;
     vault.bump = ctx.bumps.vault;
 
+    let owner_key = ctx.accounts.owner.key();
+    let config_seed = vault_seed(&vault_index, &name_hash);
+    let alert_targets = match_alert_accounts(
+        &owner_key,
+        config_seed,
+        &contacts,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    for (alert_account_info, (index, bump)) in ctx.remaining_accounts.iter().zip(&alert_targets) {
+        let contact = contacts[*index];
+        init_pda_account(
+            &ctx.accounts.owner.to_account_info(),
+            None,
+            alert_account_info,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &[
+                AlertAccount::SEED_PREFIX,
+                owner_key.as_ref(),
+                contact.as_ref(),
+                config_seed,
+                &[*bump],
+            ],
+            8 + AlertAccount::INIT_SPACE,
+            &AlertAccount {
+                owner: owner_key,
+                contact,
+                alerted_at: 0,
+                is_active: false,
+                is_rehearsal: false,
+                has_approved: false,
+                approved_round: 0,
+                acknowledged_at: 0,
+                message: Vec::new(),
+                uri: String::new(),
+                version: AlertAccount::CURRENT_VERSION,
+                bump: *bump,
+            },
+        )?;
+    }
+
+    // Top the fee reserve up to its rent floor plus worst-case panic rent;
+    // pre-created alerts no longer need rent at panic time
+    let pending_alerts = contacts.len() - alert_targets.len();
+    let reserve_target = Rent::get()?.minimum_balance(0) + panic_rent_cost(pending_alerts)?;
+    let reserve_shortfall = reserve_target.saturating_sub(ctx.accounts.fee_reserve.lamports());
+    if reserve_shortfall > 0 {
+        system_transfer(
+            &ctx.accounts.owner.to_account_info(),
+            None,
+            &ctx.accounts.fee_reserve.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            reserve_shortfall,
+        )?;
+    }
+
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.total_configs += 1;
+    emit_stats(stats);
+
     emit!(ConfigInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
         owner: ctx.accounts.owner.key(),
         contacts_count: contacts.len() as u8,
         time_lock_duration,
         decoy_lamports,
+        vault_index,
+        vault_name,
     });
 
     Ok(())
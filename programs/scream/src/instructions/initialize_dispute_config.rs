@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DisputeConfigInitialized, EVENT_SCHEMA_VERSION};
+use crate::program::Scream;
+
+#[derive(Accounts)]
+pub struct InitializeDisputeConfig<'info> {
+    /// Must be the program's upgrade authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DisputeConfig::INIT_SPACE,
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Scream>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ScreamError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeDisputeConfig>,
+    arbiter: Pubkey,
+    bond_lamports: u64,
+    dispute_window: i64,
+) -> Result<()> {
+    require!(dispute_window > 0, ScreamError::InvalidDisputeWindow);
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.arbiter = arbiter;
+    dispute_config.bond_lamports = bond_lamports;
+    dispute_config.dispute_window = dispute_window;
+    dispute_config.bump = ctx.bumps.dispute_config;
+
+    emit!(DisputeConfigInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        arbiter,
+        bond_lamports,
+        dispute_window,
+    });
+
+    Ok(())
+}
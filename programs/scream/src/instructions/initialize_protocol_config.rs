@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ProtocolConfigUpdated, EVENT_SCHEMA_VERSION};
+use crate::program::Scream;
+use crate::utils::system_transfer;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    /// Must be the program's upgrade authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Funded to its rent floor here so fees of any size can land in it
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Scream>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ScreamError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeProtocolConfig>,
+    admin: Pubkey,
+    max_contacts: u8,
+    min_time_lock: i64,
+    max_time_lock: i64,
+    fee_bps: u16,
+) -> Result<()> {
+    let treasury_floor = Rent::get()?.minimum_balance(0);
+    let shortfall = treasury_floor.saturating_sub(ctx.accounts.treasury.lamports());
+    if shortfall > 0 {
+        system_transfer(
+            &ctx.accounts.authority.to_account_info(),
+            None,
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            shortfall,
+        )?;
+    }
+
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.paused = false;
+    protocol_config.report_bounty = 0;
+    protocol_config.bump = ctx.bumps.protocol_config;
+    apply_protocol_params(
+        protocol_config,
+        admin,
+        max_contacts,
+        min_time_lock,
+        max_time_lock,
+        fee_bps,
+    )
+}
+
+/// Validate and store protocol parameters; shared with `set_protocol_config`.
+pub(crate) fn apply_protocol_params(
+    protocol_config: &mut ProtocolConfig,
+    admin: Pubkey,
+    max_contacts: u8,
+    min_time_lock: i64,
+    max_time_lock: i64,
+    fee_bps: u16,
+) -> Result<()> {
+    require!(
+        max_contacts as usize <= PanicConfig::MAX_CONTACTS,
+        ScreamError::TooManyContacts
+    );
+    require!(
+        min_time_lock >= 0 && min_time_lock <= max_time_lock,
+        ScreamError::InvalidTimeLock
+    );
+    require!(fee_bps <= ProtocolConfig::MAX_FEE_BPS, ScreamError::FeeTooHigh);
+
+    protocol_config.admin = admin;
+    protocol_config.max_contacts = max_contacts;
+    protocol_config.min_time_lock = min_time_lock;
+    protocol_config.max_time_lock = max_time_lock;
+    protocol_config.fee_bps = fee_bps;
+
+    emit!(ProtocolConfigUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        admin,
+        max_contacts,
+        min_time_lock,
+        max_time_lock,
+        fee_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolStats<'info> {
+    /// Anyone may create the singleton, once
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProtocolStats::INIT_SPACE,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeProtocolStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.total_configs = 0;
+    stats.total_panics = 0;
+    stats.total_lamports_recovered = 0;
+    stats.total_attackers_flagged = 0;
+    stats.bump = ctx.bumps.protocol_stats;
+
+    Ok(())
+}
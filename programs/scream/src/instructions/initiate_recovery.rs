@@ -1,48 +1,69 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ScreamError;
-use crate::events::RecoveryInitiated;
+use crate::events::{emit_indexed, RecoveryInitiated, EVENT_SCHEMA_VERSION};
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct InitiateRecovery<'info> {
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    /// The owner, or the config's recovery authority when one is set
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is recovered. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = panic_config.bump,
         has_one = owner,
+        constraint = panic_config.recovery_signer() == authority.key()
+            @ ScreamError::InvalidRecoveryAuthority,
     )]
     pub panic_config: Account<'info, PanicConfig>,
 
     #[account(
         mut,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = vault.bump,
         has_one = owner,
     )]
     pub vault: Account<'info, Vault>,
 }
 
+/// Start a recovery round, or restart one that expired short of the
+/// threshold; approvals from the old round no longer count. Any decoy still
+/// dripping is cancelled, so claims can't take lamports it still owes.
 pub fn handler(ctx: Context<InitiateRecovery>) -> Result<()> {
     let config = &ctx.accounts.panic_config;
     require!(config.is_triggered, ScreamError::PanicNotTriggered);
 
+    let clock = Clock::get()?;
     let vault = &mut ctx.accounts.vault;
-    require!(!vault.recovery_initiated, ScreamError::RecoveryAlreadyInitiated);
+    require!(
+        !vault.recovery_initiated
+            || vault.recovery_round_expired(
+                config.recovery_window,
+                config.recovery_threshold,
+                clock.unix_timestamp,
+            ),
+        ScreamError::RecoveryAlreadyInitiated
+    );
 
-    let clock = Clock::get()?;
     require!(
         clock.unix_timestamp >= vault.locked_until,
         ScreamError::TimeLockActive
     );
 
-    vault.recovery_initiated = true;
+    vault.start_recovery_round(clock.unix_timestamp);
     vault.approvals = 0;
+    vault.drip_remaining = 0;
+    vault.drip_installments_left = 0;
 
-    emit!(RecoveryInitiated {
+    emit_indexed!(ctx.accounts, ctx.bumps, RecoveryInitiated {
+        schema_version: EVENT_SCHEMA_VERSION,
         owner: ctx.accounts.owner.key(),
         vault_balance: vault.to_account_info().lamports(),
+        recovery_round: vault.recovery_round,
     });
 
     Ok(())
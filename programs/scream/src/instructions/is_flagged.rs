@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct IsFlagged<'info> {
+    /// CHECK: The address being looked up; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    /// CHECK: The address's flag PDA, which need not exist.
+    #[account(
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub attacker_flag: UncheckedAccount<'info>,
+}
+
+/// Read-only lookup, returned via return data; meant to be simulated.
+pub fn handler(ctx: Context<IsFlagged>) -> Result<FlagStatus> {
+    let flag_info = ctx.accounts.attacker_flag.to_account_info();
+    if flag_info.owner != ctx.program_id {
+        return Ok(FlagStatus {
+            flagged: false,
+            reported_by: Pubkey::default(),
+            flagged_at: 0,
+        });
+    }
+
+    let flag = AttackerFlag::try_deserialize(&mut &flag_info.try_borrow_data()?[..])?;
+    Ok(FlagStatus {
+        flagged: flag.weight_bps(Clock::get()?.unix_timestamp) > 0,
+        reported_by: flag.reported_by,
+        flagged_at: flag.flagged_at,
+    })
+}
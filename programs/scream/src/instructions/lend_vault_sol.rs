@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, spl_token, CloseAccount, SyncNative, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VaultSolLent, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::utils::move_lamports;
+
+/// The SPL token-lending program; forks that keep its instruction layout,
+/// such as Solend, can be targeted by rebuilding with their id.
+pub const LENDING_PROGRAM_ID: Pubkey = pubkey!("LendZqTs7gn5CTSJU1jWKhKuVpjJGom45nnwPb2AMTi");
+
+/// `LendingInstruction::DepositReserveLiquidity` tag.
+const DEPOSIT_RESERVE_LIQUIDITY: u8 = 4;
+
+#[derive(Accounts)]
+pub struct LendVaultSol<'info> {
+    /// Pays for the transient wrapped SOL account and gets its rent back
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = !panic_config.is_triggered @ ScreamError::PanicAlreadyTriggered,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The vault's wrapped SOL account; created here and closed again
+    /// once the deposit went through.
+    #[account(
+        mut,
+        address = get_associated_token_address(&vault.key(), &spl_token::native_mint::ID),
+    )]
+    pub vault_wsol: UncheckedAccount<'info>,
+
+    /// CHECK: The native mint
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: UncheckedAccount<'info>,
+
+    /// The vault's collateral account, credited by the reserve
+    #[account(
+        mut,
+        associated_token::mint = reserve_collateral_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_collateral: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the lending program
+    #[account(
+        mut,
+        owner = LENDING_PROGRAM_ID,
+        constraint = vault.lending_reserve == Pubkey::default()
+            || vault.lending_reserve == reserve.key() @ ScreamError::LendingReserveMismatch,
+    )]
+    pub reserve: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(mut)]
+    pub reserve_liquidity_supply: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(mut)]
+    pub reserve_collateral_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(owner = LENDING_PROGRAM_ID)]
+    pub lending_market: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    pub lending_market_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, passed through to the lending program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The SPL token-lending program
+    #[account(address = LENDING_PROGRAM_ID)]
+    pub lending_program: UncheckedAccount<'info>,
+}
+
+/// Supply idle vault SOL to a lending reserve, the collateral tokens landing
+/// in the vault's associated token account. At most `MAX_LENDING_BPS` of
+/// the vault can be out on loan, in one reserve at a time, and claims wait
+/// until `redeem_vault_sol` has brought it all back. Frozen once panic
+/// fires.
+pub fn handler(ctx: Context<LendVaultSol>, lamports: u64) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let vault_info = accounts.vault.to_account_info();
+    let available = claimable_lamports(&vault_info)?;
+    require!(
+        lamports > 0 && lamports <= available,
+        ScreamError::InsufficientVaultBalance
+    );
+    let lent = accounts.vault.lent_lamports;
+    require!(
+        (lent + lamports) as u128 * 10_000
+            <= (available + lent) as u128 * Vault::MAX_LENDING_BPS as u128,
+        ScreamError::LendingCapExceeded
+    );
+
+    let owner_key = accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        accounts.vault.vault_seed(),
+        &[accounts.vault.bump],
+    ];
+    let wsol_info = accounts.vault_wsol.to_account_info();
+    let token_program = accounts.token_program.to_account_info();
+
+    // Wrap the lamports so the reserve can take them as tokens
+    associated_token::create_idempotent(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: accounts.owner.to_account_info(),
+            associated_token: wsol_info.clone(),
+            authority: vault_info.clone(),
+            mint: accounts.native_mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+    move_lamports(&vault_info, &wsol_info, lamports)?;
+    token::sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: wsol_info.clone(),
+        },
+    ))?;
+
+    let collateral_before = accounts.vault_collateral.amount;
+    let mut data = vec![DEPOSIT_RESERVE_LIQUIDITY];
+    data.extend_from_slice(&lamports.to_le_bytes());
+    let ix = Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(wsol_info.key(), false),
+            AccountMeta::new(accounts.vault_collateral.key(), false),
+            AccountMeta::new(accounts.reserve.key(), false),
+            AccountMeta::new(accounts.reserve_liquidity_supply.key(), false),
+            AccountMeta::new(accounts.reserve_collateral_mint.key(), false),
+            AccountMeta::new_readonly(accounts.lending_market.key(), false),
+            AccountMeta::new_readonly(accounts.lending_market_authority.key(), false),
+            AccountMeta::new_readonly(vault_info.key(), true),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            wsol_info.clone(),
+            accounts.vault_collateral.to_account_info(),
+            accounts.reserve.to_account_info(),
+            accounts.reserve_liquidity_supply.to_account_info(),
+            accounts.reserve_collateral_mint.to_account_info(),
+            accounts.lending_market.to_account_info(),
+            accounts.lending_market_authority.to_account_info(),
+            vault_info.clone(),
+            accounts.clock.to_account_info(),
+            token_program.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    // The emptied wrapper's rent goes back to the owner
+    token::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: wsol_info,
+            destination: accounts.owner.to_account_info(),
+            authority: vault_info,
+        },
+        &[vault_seeds],
+    ))?;
+
+    ctx.accounts.vault_collateral.reload()?;
+    let collateral = ctx.accounts.vault_collateral.amount - collateral_before;
+    let reserve = ctx.accounts.reserve.key();
+
+    let vault = &mut ctx.accounts.vault;
+    vault.lending_reserve = reserve;
+    vault.lent_lamports += lamports;
+    vault.lending_collateral += collateral;
+
+    emit!(VaultSolLent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        reserve,
+        lamports,
+        collateral,
+    });
+
+    Ok(())
+}
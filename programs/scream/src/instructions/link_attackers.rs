@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AttackersLinked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct LinkAttackers<'info> {
+    /// Any wallet; pays the link's rent
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: The address that sorts first; only its key is used.
+    pub address_a: UncheckedAccount<'info>,
+
+    /// CHECK: The address that sorts second; only its key is used.
+    #[account(constraint = address_a.key() < address_b.key() @ ScreamError::InvalidAttackerLink)]
+    pub address_b: UncheckedAccount<'info>,
+
+    /// The flag of either address, anchoring the link in the registry
+    #[account(
+        seeds = [AttackerFlag::SEED_PREFIX, attacker_flag.attacker.as_ref()],
+        bump = attacker_flag.bump,
+        constraint = attacker_flag.attacker == address_a.key()
+            || attacker_flag.attacker == address_b.key() @ ScreamError::InvalidAttackerLink,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + AttackerLink::INIT_SPACE,
+        seeds = [AttackerLink::SEED_PREFIX, address_a.key().as_ref(), address_b.key().as_ref()],
+        bump,
+    )]
+    pub attacker_link: Account<'info, AttackerLink>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Assert a link between a flagged address and another one, e.g. a funding
+/// wallet or a second address using the same drainer contract. Each pair
+/// gets one undirected edge; tools walk the edges to taint whole clusters.
+pub fn handler(ctx: Context<LinkAttackers>, kind: LinkKind, evidence_hash: [u8; 32]) -> Result<()> {
+    let link = &mut ctx.accounts.attacker_link;
+    link.address_a = ctx.accounts.address_a.key();
+    link.address_b = ctx.accounts.address_b.key();
+    link.kind = kind;
+    link.reported_by = ctx.accounts.reporter.key();
+    link.evidence_hash = evidence_hash;
+    link.linked_at = Clock::get()?.unix_timestamp;
+    link.bump = ctx.bumps.attacker_link;
+
+    emit!(AttackersLinked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        address_a: link.address_a,
+        address_b: link.address_b,
+        kind,
+        reported_by: link.reported_by,
+        evidence_hash,
+    });
+
+    Ok(())
+}
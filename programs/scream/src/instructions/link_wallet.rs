@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WalletLinked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct LinkWallet<'info> {
+    pub owner: Signer<'info>,
+
+    /// Signs to prove the owner controls it, so nobody else's wallet can be
+    /// flagged by this config's panic
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Put another of the owner's wallets under this config. It can trigger
+/// panic itself, and any panic flags it compromised and sweeps it into the
+/// vault when it signs. Frozen once panic fires.
+pub fn handler(ctx: Context<LinkWallet>) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let wallet = ctx.accounts.wallet.key();
+    require!(
+        wallet != config.owner && !config.linked_wallets.contains(&wallet),
+        ScreamError::WalletAlreadyLinked
+    );
+    require!(
+        config.linked_wallets.len() < PanicConfig::MAX_LINKED_WALLETS,
+        ScreamError::TooManyLinkedWallets
+    );
+
+    config.linked_wallets.push(wallet);
+
+    emit!(WalletLinked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        wallet,
+    });
+
+    Ok(())
+}
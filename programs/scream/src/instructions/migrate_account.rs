@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AccountMigrated, EVENT_SCHEMA_VERSION};
+use crate::utils::resize_account;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays for any extra rent the new layouts need
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: May still be in an old layout, so it's decoded by hand.
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub panic_config: UncheckedAccount<'info>,
+
+    /// CHECK: May still be in an old layout, so it's decoded by hand.
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade the owner's config and vault to the current layouts, growing the
+/// accounts as needed. Accounts already current are left untouched.
+/// Old layouts predate indexed configs, so only index 0 is ever migrated.
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let owner = ctx.accounts.owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let config_info = ctx.accounts.panic_config.to_account_info();
+    let from_version = panic_config_version(&config_info)?;
+    if from_version < PanicConfig::CURRENT_VERSION {
+        let old = {
+            let data = config_info.try_borrow_data()?;
+            PanicConfigV0::deserialize(&mut &data[8..])?
+        };
+        let contacts_len = old.contacts.len();
+        let config = PanicConfig {
+            owner: old.owner,
+            trigger_hash: old.trigger_hash,
+            trigger_mode: TriggerMode::Preimage,
+            trigger_nonce: 0,
+            passkey_sign_count: 0,
+            contacts: old.contacts,
+            recovery_threshold: old.recovery_threshold,
+            time_lock_duration: old.time_lock_duration,
+            decoy_lamports: old.decoy_lamports,
+            decoy_mode: DecoyMode::Fixed,
+            decoy_jitter_bps: 0,
+            decoy_drip_installments: 0,
+            decoy_drip_interval: 0,
+            decoy_token_mint: Pubkey::default(),
+            decoy_token_amount: 0,
+            decoy_memo: String::new(),
+            balance_buffer: PanicConfig::DEFAULT_BALANCE_BUFFER,
+            alert_uri_template: String::new(),
+            veto_quorum: PanicConfig::DEFAULT_VETO_QUORUM,
+            veto_duration: PanicConfig::DEFAULT_VETO_DURATION,
+            early_claim_limit: 0,
+            second_lock_duration: 0,
+            large_claim_threshold: 0,
+            large_claim_lock_duration: 0,
+            token_time_locks: Vec::new(),
+            hot_threshold: 0,
+            cold_lock_duration: 0,
+            recovery_window: 0,
+            keeper_tip: 0,
+            watcher: Pubkey::default(),
+            attestor: Pubkey::default(),
+            alert_tree: Pubkey::default(),
+            is_triggered: old.is_triggered,
+            // Version 0 alerted every contact when panic fired
+            alerted_contacts: if old.is_triggered {
+                ((1u16 << contacts_len) - 1) as u8
+            } else {
+                0
+            },
+            max_failed_attempts: PanicConfig::DEFAULT_MAX_FAILED_ATTEMPTS,
+            lockout_duration: PanicConfig::DEFAULT_LOCKOUT_DURATION,
+            soft_lock_on_lockout: false,
+            failed_attempts: 0,
+            trigger_locked_until: 0,
+            presign_nonce_account: Pubkey::default(),
+            presign_nonce_authority: Pubkey::default(),
+            recovery_destination: Pubkey::default(),
+            recovery_authority: Pubkey::default(),
+            pending_owner: Pubkey::default(),
+            co_owners: Vec::new(),
+            owner_claim_quorum: 0,
+            linked_wallets: Vec::new(),
+            delegated_token_accounts: Vec::new(),
+            label: String::new(),
+            vault_index: 0,
+            vault_name: String::new(),
+            vault_name_hash: [0; 32],
+            version: PanicConfig::CURRENT_VERSION,
+            bump: old.bump,
+        };
+        write_migrated(
+            &config_info,
+            &owner,
+            &system_program,
+            8 + PanicConfig::INIT_SPACE,
+            &config,
+        )?;
+
+        emit!(AccountMigrated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner.key(),
+            account: config_info.key(),
+            from_version,
+            to_version: PanicConfig::CURRENT_VERSION,
+        });
+    }
+
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let from_version = vault_version(&vault_info)?;
+    if from_version < Vault::CURRENT_VERSION {
+        let old = {
+            let data = vault_info.try_borrow_data()?;
+            VaultV0::deserialize(&mut &data[8..])?
+        };
+        let vault = Vault {
+            owner: old.owner,
+            locked_until: old.locked_until,
+            recovery_initiated: old.recovery_initiated,
+            approvals: old.approvals,
+            recovery_round: 0,
+            recovery_initiated_at: 0,
+            withdraw_policy: WithdrawPolicy::default(),
+            pending_policy: WithdrawPolicy::default(),
+            pending_policy_at: 0,
+            spend_period_start: 0,
+            spent_in_period: 0,
+            withdrawn_in_window: 0,
+            window_updated_at: 0,
+            drip_recipient: Pubkey::default(),
+            drip_remaining: 0,
+            drip_installments_left: 0,
+            drip_next_at: 0,
+            veto_votes: 0,
+            claim_paused_until: 0,
+            extension_votes: 0,
+            lock_extended_by: 0,
+            fast_track_votes: 0,
+            early_claimed: 0,
+            hot_lamports: 0,
+            last_claim_at: 0,
+            owner_claim_votes: 0,
+            stake_pool: Pubkey::default(),
+            staked_lamports: 0,
+            staked_pool_tokens: 0,
+            lending_reserve: Pubkey::default(),
+            lent_lamports: 0,
+            lending_collateral: 0,
+            has_deposit_ledger: false,
+            vault_index: 0,
+            vault_name_hash: [0; 32],
+            version: Vault::CURRENT_VERSION,
+            bump: old.bump,
+        };
+        write_migrated(&vault_info, &owner, &system_program, 8 + Vault::INIT_SPACE, &vault)?;
+
+        emit!(AccountMigrated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner.key(),
+            account: vault_info.key(),
+            from_version,
+            to_version: Vault::CURRENT_VERSION,
+        });
+    }
+
+    Ok(())
+}
+
+/// Version of a stored PanicConfig, telling layouts apart by size since
+/// version 0 had no version field.
+fn panic_config_version(info: &AccountInfo) -> Result<u8> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *PanicConfig::DISCRIMINATOR,
+        ScreamError::UnsupportedAccountVersion
+    );
+    match data.len() - 8 {
+        len if len == PanicConfigV0::INIT_SPACE => Ok(0),
+        len if len == PanicConfig::INIT_SPACE => {
+            Ok(PanicConfig::try_deserialize(&mut &data[..])?.version)
+        }
+        _ => err!(ScreamError::UnsupportedAccountVersion),
+    }
+}
+
+/// Version of a stored Vault; see `panic_config_version`.
+fn vault_version(info: &AccountInfo) -> Result<u8> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *Vault::DISCRIMINATOR,
+        ScreamError::UnsupportedAccountVersion
+    );
+    match data.len() - 8 {
+        len if len == VaultV0::INIT_SPACE => Ok(0),
+        len if len == Vault::INIT_SPACE => Ok(Vault::try_deserialize(&mut &data[..])?.version),
+        _ => err!(ScreamError::UnsupportedAccountVersion),
+    }
+}
+
+/// Grow `info` to `space` and write the upgraded account over it.
+pub(crate) fn write_migrated<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    account: &T,
+) -> Result<()> {
+    resize_account(info, payer, system_program, space)?;
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    account.try_serialize(&mut writer)
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AccountMigrated, EVENT_SCHEMA_VERSION};
+use crate::instructions::migrate_account::write_migrated;
+
+#[derive(Accounts)]
+pub struct MigrateAlert<'info> {
+    /// Pays for any extra rent the new layout needs; anyone may, e.g. the
+    /// contact
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The owner whose contact was alerted. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: The alerted contact. Validated via PDA seeds.
+    pub contact: UncheckedAccount<'info>,
+
+    /// CHECK: May still be in an old layout, so it's decoded by hand.
+    #[account(
+        mut,
+        seeds = [AlertAccount::SEED_PREFIX, owner.key().as_ref(), contact.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub alert_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade an alert to the current layout so its contact can approve
+/// recovery again. Version 0 alerts were only created when panic fired, so
+/// they come out active, with any approval counted in round 0, the round a
+/// migrated vault starts in. Like `migrate_account`, only index 0 configs
+/// have old alerts. An alert already current is left untouched.
+pub fn handler(ctx: Context<MigrateAlert>) -> Result<()> {
+    let info = ctx.accounts.alert_account.to_account_info();
+    let from_version = alert_version(&info)?;
+    if from_version >= AlertAccount::CURRENT_VERSION {
+        return Ok(());
+    }
+
+    let old = {
+        let data = info.try_borrow_data()?;
+        AlertAccountV0::deserialize(&mut &data[8..])?
+    };
+    let alert = AlertAccount {
+        owner: old.owner,
+        contact: old.contact,
+        alerted_at: old.alerted_at,
+        is_active: true,
+        is_rehearsal: false,
+        has_approved: old.has_approved,
+        approved_round: 0,
+        acknowledged_at: 0,
+        message: Vec::new(),
+        uri: String::new(),
+        version: AlertAccount::CURRENT_VERSION,
+        bump: old.bump,
+    };
+    write_migrated(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + AlertAccount::INIT_SPACE,
+        &alert,
+    )?;
+
+    emit!(AccountMigrated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: old.owner,
+        account: info.key(),
+        from_version,
+        to_version: AlertAccount::CURRENT_VERSION,
+    });
+
+    Ok(())
+}
+
+/// Version of a stored AlertAccount, telling layouts apart by size since
+/// version 0 had no version field.
+fn alert_version(info: &AccountInfo) -> Result<u8> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *AlertAccount::DISCRIMINATOR,
+        ScreamError::UnsupportedAccountVersion
+    );
+    match data.len() - 8 {
+        len if len == AlertAccountV0::INIT_SPACE => Ok(0),
+        len if len == AlertAccount::INIT_SPACE => {
+            Ok(AlertAccount::try_deserialize(&mut &data[..])?.version)
+        }
+        _ => err!(ScreamError::UnsupportedAccountVersion),
+    }
+}
@@ -1,13 +1,210 @@
 pub mod initialize_config;
+pub mod update_trigger;
+pub mod set_attempt_limit;
+pub mod set_presign_nonce;
+pub mod set_recovery_destination;
 pub mod deposit;
+pub mod deposit_for;
 pub mod trigger_panic;
+pub mod alert_contact;
+pub mod panic_and_sweep_tokens;
 pub mod initiate_recovery;
 pub mod approve_recovery;
+pub mod approve_recovery_with_sig;
 pub mod claim_from_vault;
+pub mod finalize_claim;
+pub mod initialize_dispute_config;
+pub mod dispute_flag;
+pub mod resolve_dispute;
+pub mod report_attacker;
+pub mod expire_attacker_flag;
+pub mod check_attacker;
+pub mod is_flagged;
+pub mod vault_status;
+pub mod init_guardian_registry;
+pub mod register_guardian;
+pub mod initialize_protocol_stats;
+pub mod initialize_protocol_config;
+pub mod set_protocol_config;
+pub mod withdraw_treasury;
+pub mod set_paused;
+pub mod migrate_account;
+pub mod init_deposit_ledger;
+pub mod set_withdraw_policy;
+pub mod request_withdraw;
+pub mod execute_withdraw;
+pub mod cancel_withdraw;
+pub mod vault_transfer;
+pub mod init_withdraw_allowlist;
+pub mod add_withdraw_destination;
+pub mod remove_withdraw_destination;
+pub mod set_decoy_mode;
+pub mod set_decoy_drip;
+pub mod release_decoy_drip;
+pub mod set_decoy_token;
+pub mod set_balance_buffer;
+pub mod revoke_delegates;
+pub mod secure_stake_accounts;
+pub mod withdraw_secured_stake;
+pub mod unstake_pool_tokens;
+pub mod reset_close_authorities;
+pub mod init_panic_hooks;
+pub mod register_panic_hook;
+pub mod remove_panic_hook;
+pub mod assert_not_compromised;
+pub mod set_alert_message;
+pub mod set_alert_uri_template;
+pub mod acknowledge_alert;
+pub mod set_veto_policy;
+pub mod veto_claim;
+pub mod extend_time_lock;
+pub mod fast_track_recovery;
+pub mod set_claim_tiers;
+pub mod claim_split;
+pub mod set_recovery_authority;
+pub mod propose_owner_transfer;
+pub mod accept_owner_transfer;
+pub mod set_co_owners;
+pub mod approve_owner_claim;
+pub mod link_wallet;
+pub mod unlink_wallet;
+pub mod register_delegated_token_account;
+pub mod remove_delegated_token_account;
+pub mod rehearse_panic;
+pub mod simulate_panic;
+pub mod set_recovery_window;
+pub mod close_alerts;
+pub mod close_attacker_flag;
+pub mod gc;
+pub mod set_keeper_tip;
+pub mod set_watcher;
+pub mod stake_vault_sol;
+pub mod unstake_vault_sol;
+#[cfg(feature = "lending")]
+pub mod lend_vault_sol;
+#[cfg(feature = "lending")]
+pub mod redeem_vault_sol;
+pub mod set_report_bounty;
+pub mod claim_report_bounty;
+pub mod link_attackers;
+pub mod unlink_attackers;
+pub mod init_compressed_registry;
+pub mod append_compressed_flag;
+pub mod verify_compressed_flag;
+pub mod init_alert_tree;
+pub mod approve_recovery_compressed;
+pub mod set_program_info;
+pub mod get_config;
+pub mod set_label;
+pub mod set_token_time_lock;
+pub mod claim_tokens;
+pub mod set_tranche_policy;
+pub mod rebalance_tranches;
+pub mod set_large_claim_lock;
+pub mod migrate_alert;
+pub mod reclaim_report;
 
 pub use initialize_config::*;
+pub use update_trigger::*;
+pub use set_attempt_limit::*;
+pub use set_presign_nonce::*;
+pub use set_recovery_destination::*;
 pub use deposit::*;
+pub use deposit_for::*;
 pub use trigger_panic::*;
+pub use alert_contact::*;
+pub use panic_and_sweep_tokens::*;
 pub use initiate_recovery::*;
 pub use approve_recovery::*;
+pub use approve_recovery_with_sig::*;
 pub use claim_from_vault::*;
+pub use finalize_claim::*;
+pub use initialize_dispute_config::*;
+pub use dispute_flag::*;
+pub use resolve_dispute::*;
+pub use report_attacker::*;
+pub use expire_attacker_flag::*;
+pub use check_attacker::*;
+pub use is_flagged::*;
+pub use vault_status::*;
+pub use init_guardian_registry::*;
+pub use register_guardian::*;
+pub use initialize_protocol_stats::*;
+pub use initialize_protocol_config::*;
+pub use set_protocol_config::*;
+pub use withdraw_treasury::*;
+pub use set_paused::*;
+pub use migrate_account::*;
+pub use init_deposit_ledger::*;
+pub use set_withdraw_policy::*;
+pub use request_withdraw::*;
+pub use execute_withdraw::*;
+pub use cancel_withdraw::*;
+pub use vault_transfer::*;
+pub use init_withdraw_allowlist::*;
+pub use add_withdraw_destination::*;
+pub use remove_withdraw_destination::*;
+pub use set_decoy_mode::*;
+pub use set_decoy_drip::*;
+pub use release_decoy_drip::*;
+pub use set_decoy_token::*;
+pub use set_balance_buffer::*;
+pub use revoke_delegates::*;
+pub use secure_stake_accounts::*;
+pub use withdraw_secured_stake::*;
+pub use unstake_pool_tokens::*;
+pub use reset_close_authorities::*;
+pub use init_panic_hooks::*;
+pub use register_panic_hook::*;
+pub use remove_panic_hook::*;
+pub use assert_not_compromised::*;
+pub use set_alert_message::*;
+pub use set_alert_uri_template::*;
+pub use acknowledge_alert::*;
+pub use set_veto_policy::*;
+pub use veto_claim::*;
+pub use extend_time_lock::*;
+pub use fast_track_recovery::*;
+pub use set_claim_tiers::*;
+pub use claim_split::*;
+pub use set_recovery_authority::*;
+pub use propose_owner_transfer::*;
+pub use accept_owner_transfer::*;
+pub use set_co_owners::*;
+pub use approve_owner_claim::*;
+pub use link_wallet::*;
+pub use unlink_wallet::*;
+pub use register_delegated_token_account::*;
+pub use remove_delegated_token_account::*;
+pub use rehearse_panic::*;
+pub use simulate_panic::*;
+pub use set_recovery_window::*;
+pub use close_alerts::*;
+pub use close_attacker_flag::*;
+pub use gc::*;
+pub use set_keeper_tip::*;
+pub use set_watcher::*;
+pub use stake_vault_sol::*;
+pub use unstake_vault_sol::*;
+#[cfg(feature = "lending")]
+pub use lend_vault_sol::*;
+#[cfg(feature = "lending")]
+pub use redeem_vault_sol::*;
+pub use set_report_bounty::*;
+pub use claim_report_bounty::*;
+pub use link_attackers::*;
+pub use unlink_attackers::*;
+pub use init_compressed_registry::*;
+pub use append_compressed_flag::*;
+pub use verify_compressed_flag::*;
+pub use init_alert_tree::*;
+pub use approve_recovery_compressed::*;
+pub use set_program_info::*;
+pub use set_label::*;
+pub use set_token_time_lock::*;
+pub use claim_tokens::*;
+pub use set_tranche_policy::*;
+pub use rebalance_tranches::*;
+pub use set_large_claim_lock::*;
+pub use migrate_alert::*;
+pub use reclaim_report::*;
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token, CloseAccount, Token, TokenAccount, Transfer};
+use crate::errors::ScreamError;
+use crate::state::CompromiseReason;
+use crate::events::{TokensSwept, WrappedSolSwept, EVENT_SCHEMA_VERSION};
+use crate::instructions::trigger_panic::*;
+
+/// Largest contact list the composite instruction accepts while staying
+/// within a single transaction's account and compute budget.
+pub const MAX_COMPOSITE_CONTACTS: usize = 2;
+/// Largest number of (owner, vault) token account pairs swept in one go.
+pub const MAX_COMPOSITE_TOKEN_ACCOUNTS: usize = 3;
+
+#[derive(Accounts)]
+pub struct PanicAndSweepTokens<'info> {
+    pub panic: TriggerPanic<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// The triggering owner's wrapped SOL account, closed into the vault when
+    /// passed
+    #[account(
+        mut,
+        constraint = owner_wsol.owner == panic.authority.key() @ ScreamError::InvalidTokenAccount,
+        constraint = owner_wsol.mint == spl_token::native_mint::ID
+            @ ScreamError::InvalidTokenAccount,
+    )]
+    pub owner_wsol: Option<Account<'info, TokenAccount>>,
+}
+
+/// Remaining accounts: one alert PDA per contact (config order), followed
+/// by up to three `(owner token account, vault token account)` pairs, the
+/// token accounts belonging to whichever owner signs as `authority`. A
+/// wrapped SOL account passed as `owner_wsol` is unwrapped into the vault.
+/// Linked wallets and delegated token accounts aren't touched and panic
+/// hooks aren't called here; leave `panic_hooks` out. A watcher signing
+/// as `authority` has nothing swept, so passes no token accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PanicAndSweepTokens<'info>>,
+    trigger_proof: Vec<u8>,
+    simulate: bool,
+    evidence_hash: [u8; 32],
+    reason: CompromiseReason,
+    severity: Option<u8>,
+) -> Result<()> {
+    let contacts_len = ctx.accounts.panic.panic_config.contacts.len();
+    require!(
+        contacts_len <= MAX_COMPOSITE_CONTACTS,
+        ScreamError::ConfigTooLargeForComposite
+    );
+    require!(
+        ctx.remaining_accounts.len() >= contacts_len,
+        ScreamError::ContactAccountMismatch
+    );
+
+    let (alert_accounts, token_accounts) = ctx.remaining_accounts.split_at(contacts_len);
+    require!(
+        token_accounts.len() % 2 == 0
+            && token_accounts.len() / 2 <= MAX_COMPOSITE_TOKEN_ACCOUNTS,
+        ScreamError::TokenAccountMismatch
+    );
+
+    let owner_key = ctx.accounts.panic.owner.key();
+    let authority_key = ctx.accounts.panic.authority.key();
+    let vault_key = ctx.accounts.panic.vault.key();
+
+    // A watcher's own tokens aren't the owner's to lock away
+    require!(
+        !ctx.accounts.panic.panic_config.is_watcher(&authority_key)
+            || (token_accounts.is_empty() && ctx.accounts.owner_wsol.is_none()),
+        ScreamError::InvalidTokenAccount
+    );
+
+    // Validate every pair before anything moves
+    let mut sweeps = Vec::with_capacity(token_accounts.len() / 2);
+    for pair in token_accounts.chunks_exact(2) {
+        let source = Account::<TokenAccount>::try_from(&pair[0])?;
+        let destination = Account::<TokenAccount>::try_from(&pair[1])?;
+        require_keys_eq!(source.owner, authority_key, ScreamError::InvalidTokenAccount);
+        require_keys_eq!(destination.owner, vault_key, ScreamError::InvalidTokenAccount);
+        require_keys_eq!(source.mint, destination.mint, ScreamError::InvalidTokenAccount);
+        sweeps.push((source.mint, source.amount));
+    }
+
+    if simulate {
+        for (mint, amount) in &sweeps {
+            emit!(TokensSwept {
+                schema_version: EVENT_SCHEMA_VERSION,
+                owner: owner_key,
+                mint: *mint,
+                amount: *amount,
+            });
+        }
+    }
+
+    // Lock, decoy, flags and alerts (aborts here when simulating)
+    let triggered = execute(
+        &mut ctx.accounts.panic,
+        &ctx.bumps.panic,
+        alert_accounts,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        ctx.program_id,
+        &trigger_proof,
+        simulate,
+        evidence_hash,
+        reason,
+        severity,
+    )?;
+    if !triggered {
+        return Ok(());
+    }
+
+    for (pair, (mint, amount)) in token_accounts.chunks_exact(2).zip(sweeps) {
+        if amount == 0 {
+            continue;
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: pair[0].clone(),
+                    to: pair[1].clone(),
+                    authority: ctx.accounts.panic.authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(TokensSwept {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            mint,
+            amount,
+        });
+    }
+
+    // Closing a native account releases its wrapped balance and rent as SOL
+    if let Some(owner_wsol) = &ctx.accounts.owner_wsol {
+        let wsol_info = owner_wsol.to_account_info();
+        let lamports = wsol_info.lamports();
+        token::close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: wsol_info,
+                destination: ctx.accounts.panic.vault.to_account_info(),
+                authority: ctx.accounts.panic.authority.to_account_info(),
+            },
+        ))?;
+
+        emit!(WrappedSolSwept {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            lamports,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{OwnerTransferProposed, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct ProposeOwnerTransfer<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// First half of a key rotation; `new_owner` completes it with
+/// `accept_owner_transfer`. The default key withdraws a proposal.
+pub fn handler(ctx: Context<ProposeOwnerTransfer>, new_owner: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.pending_owner = new_owner;
+
+    emit!(OwnerTransferProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
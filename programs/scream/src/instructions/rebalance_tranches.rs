@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{TranchesRebalanced, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+
+#[derive(Accounts)]
+pub struct RebalanceTranches<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Put `hot_lamports` of the vault in the hot tranche and the rest in
+/// the cold one. Only before a panic, so a thief can't warm the vault up.
+pub fn handler(ctx: Context<RebalanceTranches>, hot_lamports: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.panic_config.is_triggered,
+        ScreamError::PanicAlreadyTriggered
+    );
+
+    let balance = claimable_lamports(&ctx.accounts.vault.to_account_info())?;
+    require!(hot_lamports <= balance, ScreamError::InsufficientVaultBalance);
+
+    ctx.accounts.vault.hot_lamports = hot_lamports;
+
+    emit!(TranchesRebalanced {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        hot_lamports,
+        cold_lamports: balance - hot_lamports,
+    });
+
+    Ok(())
+}
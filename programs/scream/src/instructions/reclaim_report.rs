@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ReportReclaimed, EVENT_SCHEMA_VERSION};
+use crate::utils::update_attacker_flag;
+
+#[derive(Accounts)]
+pub struct ReclaimReport<'info> {
+    /// The reporter, who gets the bond and the report's rent back
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: The reported address; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AttackerReport::SEED_PREFIX, attacker.key().as_ref(), reporter.key().as_ref()],
+        bump = report.bump,
+        close = reporter,
+    )]
+    pub report: Account<'info, AttackerReport>,
+
+    /// CHECK: The flag the report backs; may already be closed.
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub attacker_flag: UncheckedAccount<'info>,
+
+    /// CHECK: Must not exist while the flag is live, since a pending dispute
+    /// may still slash the report.
+    #[account(
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub dispute: UncheckedAccount<'info>,
+}
+
+/// Close a report once it has done its job: its flag was upheld in a
+/// dispute, has expired, or is gone. A flag filed after the report is a new
+/// one, so the report's own flag is gone. While the flag is live the report
+/// stops counting toward it.
+pub fn handler(ctx: Context<ReclaimReport>) -> Result<()> {
+    let report = &ctx.accounts.report;
+    let flag_info = ctx.accounts.attacker_flag.to_account_info();
+    let flag = if flag_info.owner == ctx.program_id {
+        Some(AttackerFlag::try_deserialize(&mut &flag_info.try_borrow_data()?[..])?)
+    } else {
+        None
+    };
+
+    if let Some(flag) = flag.filter(|flag| report.reported_at >= flag.flagged_at) {
+        let now = Clock::get()?.unix_timestamp;
+        let expired = flag.expires_at != 0 && now >= flag.expires_at;
+        require!(
+            expired || flag.disputes_upheld > 0,
+            ScreamError::ReportBondLocked
+        );
+        require!(
+            ctx.accounts.dispute.data_is_empty(),
+            ScreamError::DisputePending
+        );
+        let bond_lamports = report.bond_lamports;
+        update_attacker_flag(&flag_info, |flag| {
+            flag.reporters = flag.reporters.saturating_sub(1);
+            flag.backing_lamports = flag.backing_lamports.saturating_sub(bond_lamports);
+            Ok(true)
+        })?;
+    }
+
+    emit!(ReportReclaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: report.attacker,
+        reporter: report.reporter,
+        bond_lamports: report.bond_lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, spl_token, CloseAccount, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VaultSolRedeemed, EVENT_SCHEMA_VERSION};
+use crate::instructions::lend_vault_sol::LENDING_PROGRAM_ID;
+
+/// `LendingInstruction::RedeemReserveCollateral` tag.
+const REDEEM_RESERVE_COLLATERAL: u8 = 5;
+
+#[derive(Accounts)]
+pub struct RedeemVaultSol<'info> {
+    /// The owner before panic fires; the recovery signer once recovery has
+    /// been initiated. Pays for the transient wrapped SOL account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is redeemed. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// Receives the redeemed SOL
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = vault.lending_reserve == reserve.key() @ ScreamError::LendingReserveMismatch,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The vault's wrapped SOL account; created here and closed into
+    /// the vault once the redemption went through.
+    #[account(
+        mut,
+        address = get_associated_token_address(&vault.key(), &spl_token::native_mint::ID),
+    )]
+    pub vault_wsol: UncheckedAccount<'info>,
+
+    /// CHECK: The native mint
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: UncheckedAccount<'info>,
+
+    /// The vault's collateral account, burned by the reserve
+    #[account(
+        mut,
+        associated_token::mint = reserve_collateral_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_collateral: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the lending program
+    #[account(mut, owner = LENDING_PROGRAM_ID)]
+    pub reserve: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(mut)]
+    pub reserve_collateral_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(mut)]
+    pub reserve_liquidity_supply: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    #[account(owner = LENDING_PROGRAM_ID)]
+    pub lending_market: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the lending program
+    pub lending_market_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, passed through to the lending program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The SPL token-lending program
+    #[account(address = LENDING_PROGRAM_ID)]
+    pub lending_program: UncheckedAccount<'info>,
+}
+
+/// Redeem `collateral` of the vault's reserve collateral back into the vault
+/// as SOL, 0 for all of it. The owner can redeem any time before panic;
+/// afterwards only the recovery signer can, once recovery has been
+/// initiated. Interest stays in the vault and is claimed with the rest.
+pub fn handler(ctx: Context<RedeemVaultSol>, collateral: u64) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    let authority = ctx.accounts.authority.key();
+    if config.is_triggered {
+        require!(
+            ctx.accounts.vault.recovery_initiated,
+            ScreamError::RecoveryNotInitiated
+        );
+        require_keys_eq!(
+            authority,
+            config.recovery_signer(),
+            ScreamError::InvalidRecoveryAuthority
+        );
+    } else {
+        require_keys_eq!(authority, config.owner, ScreamError::NotAnOwner);
+    }
+
+    let held = ctx.accounts.vault.lending_collateral;
+    let collateral = if collateral == 0 { held } else { collateral };
+    require!(
+        collateral > 0 && collateral <= held,
+        ScreamError::InvalidWithdrawAmount
+    );
+
+    let accounts = &ctx.accounts;
+    let owner_key = accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        accounts.vault.vault_seed(),
+        &[accounts.vault.bump],
+    ];
+    let vault_info = accounts.vault.to_account_info();
+    let wsol_info = accounts.vault_wsol.to_account_info();
+    let token_program = accounts.token_program.to_account_info();
+    let vault_before = vault_info.lamports();
+
+    associated_token::create_idempotent(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: accounts.authority.to_account_info(),
+            associated_token: wsol_info.clone(),
+            authority: vault_info.clone(),
+            mint: accounts.native_mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+    let wsol_rent = wsol_info.lamports();
+
+    let mut data = vec![REDEEM_RESERVE_COLLATERAL];
+    data.extend_from_slice(&collateral.to_le_bytes());
+    let ix = Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.vault_collateral.key(), false),
+            AccountMeta::new(wsol_info.key(), false),
+            AccountMeta::new(accounts.reserve.key(), false),
+            AccountMeta::new(accounts.reserve_collateral_mint.key(), false),
+            AccountMeta::new(accounts.reserve_liquidity_supply.key(), false),
+            AccountMeta::new_readonly(accounts.lending_market.key(), false),
+            AccountMeta::new_readonly(accounts.lending_market_authority.key(), false),
+            AccountMeta::new_readonly(vault_info.key(), true),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.vault_collateral.to_account_info(),
+            wsol_info.clone(),
+            accounts.reserve.to_account_info(),
+            accounts.reserve_collateral_mint.to_account_info(),
+            accounts.reserve_liquidity_supply.to_account_info(),
+            accounts.lending_market.to_account_info(),
+            accounts.lending_market_authority.to_account_info(),
+            vault_info.clone(),
+            accounts.clock.to_account_info(),
+            token_program.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    // Closing the wrapper unwraps the redeemed SOL into the vault
+    token::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: wsol_info,
+            destination: vault_info.clone(),
+            authority: vault_info.clone(),
+        },
+        &[vault_seeds],
+    ))?;
+    let lamports = vault_info.lamports() - vault_before - wsol_rent;
+
+    let vault = &mut ctx.accounts.vault;
+    let reserve = vault.lending_reserve;
+    let cost = (vault.lent_lamports as u128 * collateral as u128 / held as u128) as u64;
+    vault.lent_lamports -= cost;
+    vault.lending_collateral -= collateral;
+    if vault.lending_collateral == 0 {
+        vault.lending_reserve = Pubkey::default();
+    }
+
+    emit!(VaultSolRedeemed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        reserve,
+        collateral,
+        lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DelegatedTokenAccountRegistered, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RegisterDelegatedTokenAccount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// A secondary wallet's token account that has already `approve`d the
+    /// vault as its delegate
+    #[account(
+        constraint = token_account.delegate == Some(vault.key()).into()
+            @ ScreamError::DelegatedTokenAccountMismatch,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+}
+
+/// Register a token account whose holder delegated it to the vault, so
+/// `trigger_panic` pulls the delegated balance in even though that wallet
+/// doesn't sign the panic. The delegation itself is the holder's consent.
+/// Frozen once panic fires.
+pub fn handler(ctx: Context<RegisterDelegatedTokenAccount>) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let token_account = ctx.accounts.token_account.key();
+    require!(
+        !config.delegated_token_accounts.contains(&token_account),
+        ScreamError::DelegatedTokenAccountAlreadyRegistered
+    );
+    require!(
+        config.delegated_token_accounts.len() < PanicConfig::MAX_DELEGATED_TOKEN_ACCOUNTS,
+        ScreamError::TooManyDelegatedTokenAccounts
+    );
+
+    config.delegated_token_accounts.push(token_account);
+
+    emit!(DelegatedTokenAccountRegistered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        token_account,
+        mint: ctx.accounts.token_account.mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{GuardianRegistered, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RegisterGuardian<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// CHECK: Must be one of the config's contacts; checked in the handler.
+    pub contact: UncheckedAccount<'info>,
+
+    /// Only exists if the contact opted in
+    #[account(
+        mut,
+        seeds = [GuardianRegistry::SEED_PREFIX, contact.key().as_ref()],
+        bump = guardian_registry.bump,
+    )]
+    pub guardian_registry: Account<'info, GuardianRegistry>,
+}
+
+/// Append the owner to a contact's registry; meant to be bundled with
+/// `initialize_config`. Registering twice is a no-op.
+pub fn handler(ctx: Context<RegisterGuardian>) -> Result<()> {
+    let contact = ctx.accounts.contact.key();
+    require!(
+        ctx.accounts.panic_config.contacts.contains(&contact),
+        ScreamError::InvalidContact
+    );
+
+    let owner = ctx.accounts.owner.key();
+    let registry = &mut ctx.accounts.guardian_registry;
+    if registry.owners.contains(&owner) {
+        return Ok(());
+    }
+    require!(
+        registry.owners.len() < GuardianRegistry::MAX_OWNERS,
+        ScreamError::GuardianRegistryFull
+    );
+    registry.owners.push(owner);
+
+    emit!(GuardianRegistered { schema_version: EVENT_SCHEMA_VERSION, owner, contact });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{PanicHookRegistered, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RegisterPanicHook<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [PanicHooks::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_hooks.bump,
+        has_one = owner,
+    )]
+    pub panic_hooks: Account<'info, PanicHooks>,
+}
+
+/// Add a hook, or update its account count if it's already registered.
+/// A hook that fails aborts the whole panic, so only register programs
+/// that are known to implement the interface.
+pub fn handler(ctx: Context<RegisterPanicHook>, program: Pubkey, account_count: u8) -> Result<()> {
+    require!(!ctx.accounts.panic_config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(program != crate::ID, ScreamError::InvalidPanicHook);
+
+    let panic_hooks = &mut ctx.accounts.panic_hooks;
+    let hook = PanicHook {
+        program,
+        account_count,
+    };
+    match panic_hooks.hooks.iter_mut().find(|hook| hook.program == program) {
+        Some(existing) => *existing = hook,
+        None => {
+            require!(
+                panic_hooks.hooks.len() < PanicHooks::MAX_HOOKS,
+                ScreamError::TooManyPanicHooks
+            );
+            panic_hooks.hooks.push(hook);
+        }
+    }
+
+    emit!(PanicHookRegistered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        program,
+        account_count,
+    });
+
+    Ok(())
+}
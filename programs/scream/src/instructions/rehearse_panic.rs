@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{PanicRehearsed, EVENT_SCHEMA_VERSION};
+use crate::instructions::alert_contact::{activate_alert, match_alert_accounts};
+
+#[derive(Accounts)]
+pub struct RehearsePanic<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// Pays for alerts that don't exist yet, which a real panic then only
+    /// has to flip active
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: alert PDAs for any subset of the contacts. Runs the
+/// alert path with each alert marked as a rehearsal so contacts can practise
+/// their part; nothing is locked, swept, sent or flagged, and the config
+/// isn't marked triggered.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RehearsePanic<'info>>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let owner_key = ctx.accounts.owner.key();
+    let targets = match_alert_accounts(
+        &owner_key,
+        config.vault_seed(),
+        &config.contacts,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    let reserve_seeds: &[&[u8]] = &[
+        FeeReserve::SEED_PREFIX,
+        owner_key.as_ref(),
+        config.vault_seed(),
+        &[ctx.bumps.fee_reserve],
+    ];
+    let now = Clock::get()?.unix_timestamp;
+    for (alert_account, (index, bump)) in ctx.remaining_accounts.iter().zip(&targets) {
+        let contact = config.contacts[*index];
+        activate_alert(
+            &ctx.accounts.fee_reserve.to_account_info(),
+            reserve_seeds,
+            &ctx.accounts.owner.to_account_info(),
+            alert_account,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &owner_key,
+            config.vault_seed(),
+            &contact,
+            config.alert_uri(&contact),
+            *bump,
+            now,
+            true,
+        )?;
+    }
+
+    emit!(PanicRehearsed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        contacts_alerted: targets.len() as u8,
+        reason: CompromiseReason::Rehearsal,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DecoyDripReleased, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::utils::{move_lamports, pay_keeper_tip};
+
+#[derive(Accounts)]
+pub struct ReleaseDecoyDrip<'info> {
+    /// Any keeper may release a due installment, for the config's keeper tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: The owner whose decoy is dripping. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Must be the attacker the drip was scheduled for.
+    #[account(mut, address = vault.drip_recipient)]
+    pub attacker: UncheckedAccount<'info>,
+
+    /// Pays the keeper tip
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless crank releasing the next due decoy installment. The last
+/// one carries whatever is left; initiating recovery cancels the rest.
+/// The keeper is the only signer, so an automation thread PDA can run it.
+pub fn handler(ctx: Context<ReleaseDecoyDrip>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        ctx.accounts.panic_config.is_triggered
+            && !vault.recovery_initiated
+            && vault.drip_installments_left > 0
+            && now >= vault.drip_next_at,
+        ScreamError::DecoyDripNotDue
+    );
+
+    let installment = vault.drip_remaining / vault.drip_installments_left as u64;
+    let vault_info = vault.to_account_info();
+    let amount = installment.min(claimable_lamports(&vault_info)?);
+
+    vault.drip_remaining -= installment;
+    vault.drip_installments_left -= 1;
+    vault.drip_next_at = now + ctx.accounts.panic_config.decoy_drip_interval;
+
+    move_lamports(&vault_info, &ctx.accounts.attacker.to_account_info(), amount)?;
+
+    emit!(DecoyDripReleased {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        attacker: ctx.accounts.attacker.key(),
+        amount,
+        installments_left: vault.drip_installments_left,
+    });
+
+    let owner_key = ctx.accounts.owner.key();
+    pay_keeper_tip(
+        &ctx.accounts.fee_reserve.to_account_info(),
+        &[
+            FeeReserve::SEED_PREFIX,
+            owner_key.as_ref(),
+            ctx.accounts.panic_config.vault_seed(),
+            &[ctx.bumps.fee_reserve],
+        ],
+        &ctx.accounts.keeper.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        owner_key,
+        ctx.accounts.panic_config.keeper_tip,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DelegatedTokenAccountRemoved, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RemoveDelegatedTokenAccount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Stop pulling a delegated token account at panic. The holder revokes the
+/// delegation itself with the token program. Frozen once panic fires.
+pub fn handler(ctx: Context<RemoveDelegatedTokenAccount>, token_account: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let index = config
+        .delegated_token_accounts
+        .iter()
+        .position(|a| *a == token_account)
+        .ok_or(ScreamError::DelegatedTokenAccountNotRegistered)?;
+    config.delegated_token_accounts.remove(index);
+
+    emit!(DelegatedTokenAccountRemoved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        token_account,
+    });
+
+    Ok(())
+}
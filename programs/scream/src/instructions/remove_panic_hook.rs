@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{PanicHookRemoved, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RemovePanicHook<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [PanicHooks::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_hooks.bump,
+        has_one = owner,
+    )]
+    pub panic_hooks: Account<'info, PanicHooks>,
+}
+
+pub fn handler(ctx: Context<RemovePanicHook>, program: Pubkey) -> Result<()> {
+    let panic_hooks = &mut ctx.accounts.panic_hooks;
+    let index = panic_hooks
+        .hooks
+        .iter()
+        .position(|hook| hook.program == program)
+        .ok_or(ScreamError::InvalidPanicHook)?;
+    panic_hooks.hooks.remove(index);
+
+    emit!(PanicHookRemoved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        program,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WithdrawDestinationRemoved, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RemoveWithdrawDestination<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [WithdrawAllowlist::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = withdraw_allowlist.bump,
+        has_one = owner,
+    )]
+    pub withdraw_allowlist: Account<'info, WithdrawAllowlist>,
+}
+
+/// Removal only tightens the list, so it applies immediately.
+pub fn handler(ctx: Context<RemoveWithdrawDestination>, destination: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdraw_allowlist;
+    let index = allowlist
+        .entries
+        .iter()
+        .position(|entry| entry.destination == destination)
+        .ok_or(ScreamError::DestinationNotAllowlisted)?;
+    allowlist.entries.remove(index);
+
+    emit!(WithdrawDestinationRemoved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        destination,
+    });
+
+    Ok(())
+}
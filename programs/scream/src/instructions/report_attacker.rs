@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AttackerReported, EVENT_SCHEMA_VERSION};
+use crate::utils::{emit_stats, init_pda_account, system_transfer, update_attacker_flag};
+
+#[derive(Accounts)]
+pub struct ReportAttacker<'info> {
+    /// Any wallet; no scream config is needed to report
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: The address being reported; only its key is used.
+    pub attacker: UncheckedAccount<'info>,
+
+    /// CHECK: Created here with the reporter as `reported_by` if the address
+    /// hasn't been flagged yet; an existing flag counts the report toward its
+    /// score.
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump,
+    )]
+    pub attacker_flag: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + AttackerReport::INIT_SPACE,
+        seeds = [AttackerReport::SEED_PREFIX, attacker.key().as_ref(), reporter.key().as_ref()],
+        bump,
+    )]
+    pub report: Account<'info, AttackerReport>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Report an attacker address, locking the configured bond in the report.
+pub fn handler(ctx: Context<ReportAttacker>, evidence_hash: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let attacker_key = ctx.accounts.attacker.key();
+    let reporter_key = ctx.accounts.reporter.key();
+
+    let bond_lamports = ctx.accounts.dispute_config.bond_lamports;
+    let attacker_flag_info = ctx.accounts.attacker_flag.to_account_info();
+    if attacker_flag_info.owner != ctx.program_id {
+        let mut flag = AttackerFlag {
+            attacker: attacker_key,
+            reported_by: reporter_key,
+            flagged_at: now,
+            evidence_hash,
+            expires_at: now + AttackerFlag::DEFAULT_TTL,
+            corroborations: 0,
+            bounty_paid: false,
+            reporters: 1,
+            backing_lamports: bond_lamports,
+            disputes_upheld: 0,
+            score: 0,
+            bump: ctx.bumps.attacker_flag,
+        };
+        flag.refresh_score();
+        init_pda_account(
+            &ctx.accounts.reporter.to_account_info(),
+            None,
+            &attacker_flag_info,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &[
+                AttackerFlag::SEED_PREFIX,
+                attacker_key.as_ref(),
+                &[ctx.bumps.attacker_flag],
+            ],
+            8 + AttackerFlag::INIT_SPACE,
+            &flag,
+        )?;
+
+        let stats = &mut ctx.accounts.protocol_stats;
+        stats.total_attackers_flagged += 1;
+        emit_stats(stats);
+    } else {
+        // Each reporter has one report PDA, so every report is independent
+        update_attacker_flag(&attacker_flag_info, |flag| {
+            flag.reporters = flag.reporters.saturating_add(1);
+            flag.backing_lamports = flag
+                .backing_lamports
+                .checked_add(bond_lamports)
+                .ok_or(ScreamError::LamportOverflow)?;
+            Ok(true)
+        })?;
+    }
+
+    if bond_lamports > 0 {
+        system_transfer(
+            &ctx.accounts.reporter.to_account_info(),
+            None,
+            &ctx.accounts.report.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            bond_lamports,
+        )?;
+    }
+
+    let report = &mut ctx.accounts.report;
+    report.attacker = attacker_key;
+    report.reporter = reporter_key;
+    report.evidence_hash = evidence_hash;
+    report.bond_lamports = bond_lamports;
+    report.reported_at = now;
+    report.bump = ctx.bumps.report;
+
+    emit!(AttackerReported {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: attacker_key,
+        reporter: reporter_key,
+        evidence_hash,
+        bond_lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WithdrawRequested, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+
+#[derive(Accounts)]
+pub struct RequestWithdraw<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// One pending withdrawal per vault
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + WithdrawRequest::INIT_SPACE,
+        seeds = [WithdrawRequest::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a routine withdrawal. It can run once the vault's withdraw delay
+/// has passed, unless panic fires first.
+pub fn handler(ctx: Context<RequestWithdraw>, amount: u64, destination: Pubkey) -> Result<()> {
+    require!(!ctx.accounts.panic_config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(amount > 0, ScreamError::InvalidWithdrawAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    require!(now >= vault.locked_until, ScreamError::TimeLockActive);
+    require!(
+        amount <= claimable_lamports(&vault.to_account_info())?,
+        ScreamError::InsufficientVaultBalance
+    );
+
+    vault.settle_policy(now);
+    let executable_at = now + vault.withdraw_policy.withdraw_delay;
+
+    let request = &mut ctx.accounts.withdraw_request;
+    request.owner = ctx.accounts.owner.key();
+    request.destination = destination;
+    request.amount = amount;
+    request.requested_at = now;
+    request.executable_at = executable_at;
+    request.bump = ctx.bumps.withdraw_request;
+
+    emit!(WithdrawRequested {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: request.owner,
+        destination,
+        amount,
+        executable_at,
+    });
+
+    Ok(())
+}
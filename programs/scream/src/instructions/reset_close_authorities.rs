@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{CloseAuthorityNeutralized, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct ResetCloseAuthorities<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Remaining accounts: `(owner token account, vault token account)` pairs.
+///
+/// The token program only lets a close authority be changed by whoever
+/// holds it, so one granted to someone else can't be revoked by the owner.
+/// Instead, every account with a foreign close authority is emptied into
+/// the vault's token account for the same mint, leaving the holder nothing
+/// but the account's rent to take. Accounts whose close authority is unset
+/// or the owner are left alone.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResetCloseAuthorities<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        ScreamError::TokenAccountMismatch
+    );
+
+    let owner_key = ctx.accounts.owner.key();
+    let vault_key = ctx.accounts.vault.key();
+
+    for pair in ctx.remaining_accounts.chunks_exact(2) {
+        let source = Account::<TokenAccount>::try_from(&pair[0])?;
+        let destination = Account::<TokenAccount>::try_from(&pair[1])?;
+        require_keys_eq!(source.owner, owner_key, ScreamError::InvalidTokenAccount);
+        require_keys_eq!(destination.owner, vault_key, ScreamError::InvalidTokenAccount);
+        require_keys_eq!(source.mint, destination.mint, ScreamError::InvalidTokenAccount);
+
+        let close_authority = match Option::<Pubkey>::from(source.close_authority) {
+            Some(close_authority) if close_authority != owner_key => close_authority,
+            _ => continue,
+        };
+
+        if source.amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: pair[0].clone(),
+                        to: pair[1].clone(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                source.amount,
+            )?;
+        }
+
+        emit!(CloseAuthorityNeutralized {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            token_account: pair[0].key(),
+            close_authority,
+            amount_moved: source.amount,
+        });
+    }
+
+    Ok(())
+}
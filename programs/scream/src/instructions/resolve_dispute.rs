@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DisputeResolved, ReportSlashed, EVENT_SCHEMA_VERSION};
+use crate::utils::{close_pda_account, move_lamports};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// The arbiter while the window is open; anyone once it has lapsed
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    /// CHECK: The disputing address; receives the dispute account's rent and,
+    /// unless slashed, its bond. Validated via PDA seeds.
+    #[account(mut)]
+    pub attacker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
+        bump = attacker_flag.bump,
+    )]
+    pub attacker_flag: Account<'info, AttackerFlag>,
+
+    /// CHECK: Whoever filed the flag; receives a slashed bond, or the flag's
+    /// rent when it is cleared.
+    #[account(mut, address = attacker_flag.reported_by)]
+    pub reporter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FlagDispute::SEED_PREFIX, attacker.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, FlagDispute>,
+}
+
+/// Settle a dispute. Inside the window the arbiter decides: `cleared` removes
+/// the flag and refunds the bond, otherwise the bond goes to the reporter.
+/// Once the window has lapsed anyone may close the dispute; the flag stands
+/// and the bond is refunded, since the arbiter never ruled.
+///
+/// Remaining accounts: community reports against this attacker. When the
+/// flag is cleared every report backing it must be passed, so none is left
+/// holding a bond nothing can settle; they are closed and their bonds
+/// slashed to the attacker.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+    cleared: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let lapsed = now > ctx.accounts.dispute.deadline;
+    if !lapsed {
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.dispute_config.arbiter,
+            ScreamError::Unauthorized
+        );
+    }
+    let cleared = cleared && !lapsed;
+    let slashed = !cleared && !lapsed;
+
+    let attacker_info = ctx.accounts.attacker.to_account_info();
+    let reporter_info = ctx.accounts.reporter.to_account_info();
+
+    if slashed {
+        let bond = ctx.accounts.dispute.bond_lamports;
+        let dispute_info = ctx.accounts.dispute.to_account_info();
+        move_lamports(&dispute_info, &reporter_info, bond)?;
+
+        let flag = &mut ctx.accounts.attacker_flag;
+        flag.disputes_upheld = flag.disputes_upheld.saturating_add(1);
+        flag.refresh_score();
+    }
+    if cleared {
+        let flagged_at = ctx.accounts.attacker_flag.flagged_at;
+        require!(
+            ctx.remaining_accounts.len() == ctx.accounts.attacker_flag.reporters as usize,
+            ScreamError::ReportsMissing
+        );
+        for report_info in ctx.remaining_accounts {
+            require_keys_eq!(
+                *report_info.owner,
+                crate::ID,
+                ScreamError::InvalidReportAccount
+            );
+            let report =
+                AttackerReport::try_deserialize(&mut &report_info.try_borrow_data()?[..])?;
+            require_keys_eq!(
+                report.attacker,
+                ctx.accounts.attacker.key(),
+                ScreamError::InvalidReportAccount
+            );
+            // A report older than the flag backed an earlier one
+            require!(
+                report.reported_at >= flagged_at,
+                ScreamError::InvalidReportAccount
+            );
+            close_pda_account(report_info, &attacker_info)?;
+            emit!(ReportSlashed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                attacker: report.attacker,
+                reporter: report.reporter,
+                bond_lamports: report.bond_lamports,
+            });
+        }
+        ctx.accounts.attacker_flag.close(reporter_info)?;
+    }
+    ctx.accounts.dispute.close(attacker_info)?;
+
+    emit!(DisputeResolved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        attacker: ctx.accounts.attacker.key(),
+        cleared,
+        lapsed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Revoke, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DelegateRevoked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct RevokeDelegates<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Remaining accounts: the owner's token accounts under `token_program`.
+/// Meant to be bundled right after `trigger_panic` so approvals a drainer
+/// set up earlier stop working immediately. Accounts without a delegate
+/// are skipped.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RevokeDelegates<'info>>) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    for token_account_info in ctx.remaining_accounts {
+        require_keys_eq!(
+            *token_account_info.owner,
+            token_program.key(),
+            ScreamError::InvalidTokenAccount
+        );
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
+        require_keys_eq!(token_account.owner, owner_key, ScreamError::InvalidTokenAccount);
+
+        let Some(delegate) = Option::<Pubkey>::from(token_account.delegate) else {
+            continue;
+        };
+
+        token_interface::revoke(CpiContext::new(
+            token_program.clone(),
+            Revoke {
+                source: token_account_info.clone(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ))?;
+
+        emit!(DelegateRevoked {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            token_account: token_account_info.key(),
+            delegate,
+            delegated_amount: token_account.delegated_amount,
+        });
+    }
+
+    Ok(())
+}
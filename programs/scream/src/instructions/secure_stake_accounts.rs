@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use solana_stake_interface::instruction as stake_instruction;
+use solana_stake_interface::state::{Meta, StakeAuthorize, StakeStateV2};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{StakeSecured, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SecureStakeAccounts<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = panic_config.is_triggered @ ScreamError::PanicNotTriggered,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Clock sysvar, passed through to the stake program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: The native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+}
+
+/// Remaining accounts: stake accounts whose staker and withdrawer are the
+/// owner. Each is deactivated if delegated, then both authorities move to
+/// the vault PDA so the attacker can't re-delegate or withdraw it;
+/// `withdraw_secured_stake` later pulls the cooled-down SOL into the vault.
+/// Accounts under an active lockup can't change withdrawer and fail.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SecureStakeAccounts<'info>>,
+) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let vault_key = ctx.accounts.vault.key();
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let clock_info = ctx.accounts.clock.to_account_info();
+
+    for stake_info in ctx.remaining_accounts {
+        let (state, meta) = stake_state(stake_info)?;
+        require!(
+            meta.authorized.staker == owner_key && meta.authorized.withdrawer == owner_key,
+            ScreamError::InvalidStakeAccount
+        );
+
+        let deactivated = matches!(state, StakeStateV2::Stake(..));
+        if deactivated {
+            invoke(
+                &stake_instruction::deactivate_stake(stake_info.key, &owner_key),
+                &[stake_info.clone(), clock_info.clone(), owner_info.clone()],
+            )?;
+        }
+
+        for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+            invoke(
+                &stake_instruction::authorize(
+                    stake_info.key,
+                    &owner_key,
+                    &vault_key,
+                    stake_authorize,
+                    None,
+                ),
+                &[stake_info.clone(), clock_info.clone(), owner_info.clone()],
+            )?;
+        }
+
+        emit!(StakeSecured {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            stake_account: stake_info.key(),
+            lamports: stake_info.lamports(),
+            deactivated,
+        });
+    }
+
+    Ok(())
+}
+
+/// Decode a stake-program-owned account that has been initialized.
+pub(crate) fn stake_state(info: &AccountInfo) -> Result<(StakeStateV2, Meta)> {
+    require_keys_eq!(
+        *info.owner,
+        solana_stake_interface::program::ID,
+        ScreamError::InvalidStakeAccount
+    );
+    let state = StakeStateV2::deserialize(&mut &info.try_borrow_data()?[..])?;
+    let meta = state.meta().ok_or(ScreamError::InvalidStakeAccount)?;
+    Ok((state, meta))
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AlertMessageSet, EVENT_SCHEMA_VERSION};
+use crate::utils::init_pda_account;
+
+#[derive(Accounts)]
+pub struct SetAlertMessage<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// CHECK: Must be one of the config's contacts; checked in the handler.
+    pub contact: UncheckedAccount<'info>,
+
+    /// CHECK: Pre-created inactive here if it doesn't exist yet.
+    #[account(
+        mut,
+        seeds = [
+            AlertAccount::SEED_PREFIX,
+            owner.key().as_ref(),
+            contact.key().as_ref(),
+            panic_config.vault_seed(),
+        ],
+        bump,
+    )]
+    pub alert_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Store `message` on the contact's alert, where they can read it once
+/// panic activates it. It is public on-chain, so it must already be
+/// encrypted to the contact. Locked once panic fires so a coerced owner
+/// can't mislead the guardians.
+pub fn handler(ctx: Context<SetAlertMessage>, message: Vec<u8>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        message.len() <= AlertAccount::MAX_MESSAGE_LEN,
+        ScreamError::AlertMessageTooLong
+    );
+
+    let contact = ctx.accounts.contact.key();
+    require!(config.contacts.contains(&contact), ScreamError::InvalidContact);
+
+    let owner_key = ctx.accounts.owner.key();
+    let alert_info = ctx.accounts.alert_account.to_account_info();
+    let space = 8 + AlertAccount::INIT_SPACE;
+    let len = message.len() as u16;
+
+    if alert_info.owner == ctx.program_id {
+        let mut data = alert_info.try_borrow_mut_data()?;
+        let mut alert = AlertAccount::try_deserialize(&mut &data[..])?;
+        alert.message = message;
+        let mut writer: &mut [u8] = &mut data;
+        alert.try_serialize(&mut writer)?;
+    } else {
+        let bump = ctx.bumps.alert_account;
+        init_pda_account(
+            &ctx.accounts.owner.to_account_info(),
+            None,
+            &alert_info,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &[
+                AlertAccount::SEED_PREFIX,
+                owner_key.as_ref(),
+                contact.as_ref(),
+                ctx.accounts.panic_config.vault_seed(),
+                &[bump],
+            ],
+            space,
+            &AlertAccount {
+                owner: owner_key,
+                contact,
+                alerted_at: 0,
+                is_active: false,
+                is_rehearsal: false,
+                has_approved: false,
+                approved_round: 0,
+                acknowledged_at: 0,
+                message,
+                uri: String::new(),
+                version: AlertAccount::CURRENT_VERSION,
+                bump,
+            },
+        )?;
+    }
+
+    emit!(AlertMessageSet {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        contact,
+        len,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AlertUriTemplateUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetAlertUriTemplate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Set the URI each alert carries once panic fires. `{contact}` may appear
+/// once, so every guardian can get their own encrypted document.
+pub fn handler(ctx: Context<SetAlertUriTemplate>, template: String) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        template.len() <= PanicConfig::MAX_ALERT_URI_TEMPLATE_LEN
+            && template.matches(PanicConfig::ALERT_URI_CONTACT_PLACEHOLDER).count() <= 1,
+        ScreamError::InvalidAlertUriTemplate
+    );
+
+    config.alert_uri_template = template.clone();
+
+    emit!(AlertUriTemplateUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        template,
+    });
+
+    Ok(())
+}
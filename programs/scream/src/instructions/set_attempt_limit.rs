@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AttemptLimitUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetAttemptLimit<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetAttemptLimit>,
+    max_failed_attempts: u8,
+    lockout_duration: i64,
+    soft_lock_on_lockout: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(lockout_duration >= 0, ScreamError::InvalidLockoutDuration);
+
+    config.max_failed_attempts = max_failed_attempts;
+    config.lockout_duration = lockout_duration;
+    config.soft_lock_on_lockout = soft_lock_on_lockout;
+    config.failed_attempts = 0;
+
+    emit!(AttemptLimitUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        max_failed_attempts,
+        lockout_duration,
+        soft_lock_on_lockout,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{BalanceBufferUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetBalanceBuffer<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Set how much SOL panic leaves in the owner's wallet, e.g. more for
+/// owners with many token accounts to keep rent-exempt.
+pub fn handler(ctx: Context<SetBalanceBuffer>, balance_buffer: u64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        (PanicConfig::MIN_BALANCE_BUFFER..=PanicConfig::MAX_BALANCE_BUFFER)
+            .contains(&balance_buffer),
+        ScreamError::InvalidBalanceBuffer
+    );
+
+    config.balance_buffer = balance_buffer;
+
+    emit!(BalanceBufferUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        balance_buffer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ClaimTiersUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetClaimTiers<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Split recovery in two: up to `early_claim_limit` lamports once the
+/// time-lock ends, for living expenses, and the rest `second_lock_duration`
+/// later. A zero limit claims everything at once.
+pub fn handler(
+    ctx: Context<SetClaimTiers>,
+    early_claim_limit: u64,
+    second_lock_duration: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        (0..=PanicConfig::MAX_SECOND_LOCK_DURATION).contains(&second_lock_duration)
+            && (early_claim_limit == 0 || second_lock_duration > 0),
+        ScreamError::InvalidClaimTiers
+    );
+
+    config.early_claim_limit = early_claim_limit;
+    config.second_lock_duration = second_lock_duration;
+
+    emit!(ClaimTiersUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        early_claim_limit,
+        second_lock_duration,
+    });
+
+    Ok(())
+}
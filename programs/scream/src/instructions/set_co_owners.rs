@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{CoOwnersUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetCoOwners<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Share the vault with other keys. Anyone can already fund it through
+/// `deposit_for`; co-owners can also trigger panic with the config's proof,
+/// and `owner_claim_quorum` of all owners must `approve_owner_claim` before
+/// funds are claimed, on top of the guardians. Frozen once panic fires.
+pub fn handler(
+    ctx: Context<SetCoOwners>,
+    co_owners: Vec<Pubkey>,
+    owner_claim_quorum: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let owner = ctx.accounts.owner.key();
+    require!(
+        co_owners.len() <= PanicConfig::MAX_CO_OWNERS
+            && owner_claim_quorum as usize <= co_owners.len() + 1,
+        ScreamError::InvalidCoOwners
+    );
+    for (i, co_owner) in co_owners.iter().enumerate() {
+        require!(
+            *co_owner != owner
+                && *co_owner != Pubkey::default()
+                && !co_owners[..i].contains(co_owner),
+            ScreamError::InvalidCoOwners
+        );
+    }
+
+    config.co_owners = co_owners.clone();
+    config.owner_claim_quorum = owner_claim_quorum;
+
+    emit!(CoOwnersUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner,
+        co_owners,
+        owner_claim_quorum,
+    });
+
+    Ok(())
+}
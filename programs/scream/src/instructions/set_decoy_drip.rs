@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DecoyDripUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetDecoyDrip<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Pay the decoy out in `installments` spaced `interval` seconds apart,
+/// mimicking a wallet being drained slowly. 0 or 1 sends it all at once.
+pub fn handler(ctx: Context<SetDecoyDrip>, installments: u8, interval: i64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        installments <= PanicConfig::MAX_DECOY_DRIP_INSTALLMENTS
+            && (installments <= 1 || interval > 0),
+        ScreamError::InvalidDecoyDrip
+    );
+
+    config.decoy_drip_installments = installments;
+    config.decoy_drip_interval = interval;
+
+    emit!(DecoyDripUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        installments,
+        interval,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DecoyModeUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetDecoyMode<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+pub fn handler(ctx: Context<SetDecoyMode>, decoy_mode: DecoyMode, jitter_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    if let DecoyMode::Percentage {
+        bps,
+        min_lamports,
+        max_lamports,
+    } = decoy_mode
+    {
+        require!(
+            bps <= 10_000 && min_lamports <= max_lamports,
+            ScreamError::InvalidDecoyMode
+        );
+    }
+
+    require!(jitter_bps <= 10_000, ScreamError::InvalidDecoyMode);
+
+    config.decoy_mode = decoy_mode;
+    config.decoy_jitter_bps = jitter_bps;
+
+    emit!(DecoyModeUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        decoy_mode,
+        jitter_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{DecoyTokenUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetDecoyToken<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Send `amount` of `mint` from the vault's token account to the attacker
+/// on panic. The default mint turns the token decoy off.
+pub fn handler(ctx: Context<SetDecoyToken>, mint: Pubkey, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.decoy_token_mint = mint;
+    config.decoy_token_amount = amount;
+
+    emit!(DecoyTokenUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        mint,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{KeeperTipUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetKeeperTip<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Set the tip `finalize_claim` and `release_decoy_drip` pay their caller
+/// from the fee reserve, so keepers run them promptly. Frozen once panic
+/// fires.
+pub fn handler(ctx: Context<SetKeeperTip>, keeper_tip: u64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        keeper_tip <= PanicConfig::MAX_KEEPER_TIP,
+        ScreamError::InvalidKeeperTip
+    );
+
+    config.keeper_tip = keeper_tip;
+
+    emit!(KeeperTipUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        keeper_tip,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{LargeClaimLockUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetLargeClaimLock<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Give large claims a lock of their own: any single claim of at least
+/// `large_claim_threshold` lamports waits `large_claim_lock_duration` past
+/// the time-lock, while smaller ones only wait out the time-lock. Claims of
+/// everything available take just under the threshold until then. A zero
+/// threshold turns the lock off.
+pub fn handler(
+    ctx: Context<SetLargeClaimLock>,
+    large_claim_threshold: u64,
+    large_claim_lock_duration: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        (0..=PanicConfig::MAX_LARGE_CLAIM_LOCK_DURATION).contains(&large_claim_lock_duration)
+            && (large_claim_threshold == 0 || large_claim_lock_duration > 0),
+        ScreamError::InvalidLargeClaimLock
+    );
+
+    config.large_claim_threshold = large_claim_threshold;
+    config.large_claim_lock_duration = large_claim_lock_duration;
+
+    emit!(LargeClaimLockUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        large_claim_threshold,
+        large_claim_lock_duration,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::{PausedUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Halt or resume inflows. Only `initialize_config` and `deposit` check the
+/// flag, so pausing can never trap funds that are already in a vault.
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.protocol_config.paused = paused;
+
+    emit!(PausedUpdated { schema_version: EVENT_SCHEMA_VERSION, paused });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{PresignNonceUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetPresignNonce<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Register the durable nonce account a pre-signed `trigger_panic` will use.
+/// Passing `Pubkey::default()` revokes every outstanding pre-signed trigger.
+pub fn handler(
+    ctx: Context<SetPresignNonce>,
+    nonce_account: Pubkey,
+    nonce_authority: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.presign_nonce_account = nonce_account;
+    config.presign_nonce_authority = nonce_authority;
+
+    emit!(PresignNonceUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        nonce_account,
+        nonce_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ProgramInfoUpdated, EVENT_SCHEMA_VERSION};
+use crate::utils::init_pda_account;
+
+#[derive(Accounts)]
+pub struct SetProgramInfo<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Created on first use, rewritten after that.
+    #[account(mut, seeds = [ProgramInfo::SEED_PREFIX], bump)]
+    pub program_info: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish which release is deployed, the hash of its audit report and a
+/// security contact. The admin pays rent the first time; later calls
+/// replace every field.
+pub fn handler(
+    ctx: Context<SetProgramInfo>,
+    version: String,
+    audit_hash: [u8; 32],
+    contact: String,
+) -> Result<()> {
+    require!(
+        !version.is_empty() && version.len() <= ProgramInfo::MAX_VERSION_LEN,
+        ScreamError::InvalidProgramInfo
+    );
+    require!(
+        !contact.is_empty() && contact.len() <= ProgramInfo::MAX_CONTACT_LEN,
+        ScreamError::InvalidProgramInfo
+    );
+
+    let info = ProgramInfo {
+        version: version.clone(),
+        audit_hash,
+        contact: contact.clone(),
+        updated_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.program_info,
+    };
+    let info_account = ctx.accounts.program_info.to_account_info();
+    if info_account.owner == ctx.program_id {
+        info.try_serialize(&mut &mut info_account.try_borrow_mut_data()?[..])?;
+    } else {
+        init_pda_account(
+            &ctx.accounts.admin.to_account_info(),
+            None,
+            &info_account,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &[ProgramInfo::SEED_PREFIX, &[info.bump]],
+            8 + ProgramInfo::INIT_SPACE,
+            &info,
+        )?;
+    }
+
+    emit!(ProgramInfoUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        version,
+        audit_hash,
+        contact,
+    });
+
+    Ok(())
+}
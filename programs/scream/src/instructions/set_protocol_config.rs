@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::initialize_protocol_config::apply_protocol_params;
+
+#[derive(Accounts)]
+pub struct SetProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Replace every parameter at once; pass a different `new_admin` to hand
+/// the role on. Existing configs keep the limits they were created under.
+pub fn handler(
+    ctx: Context<SetProtocolConfig>,
+    new_admin: Pubkey,
+    max_contacts: u8,
+    min_time_lock: i64,
+    max_time_lock: i64,
+    fee_bps: u16,
+) -> Result<()> {
+    apply_protocol_params(
+        &mut ctx.accounts.protocol_config,
+        new_admin,
+        max_contacts,
+        min_time_lock,
+        max_time_lock,
+        fee_bps,
+    )
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{RecoveryAuthorityUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetRecoveryAuthority<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Hand recovery to a cold key, so a compromised owner key can't initiate
+/// recovery or claim. Frozen once panic fires; the default key clears it.
+pub fn handler(ctx: Context<SetRecoveryAuthority>, recovery_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.recovery_authority = recovery_authority;
+
+    emit!(RecoveryAuthorityUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        recovery_authority,
+    });
+
+    Ok(())
+}
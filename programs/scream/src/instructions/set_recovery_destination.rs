@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{RecoveryDestinationUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetRecoveryDestination<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Pre-set where `finalize_claim` sends recovered funds. Frozen once panic
+/// fires so an attacker holding the owner key can't redirect them.
+pub fn handler(ctx: Context<SetRecoveryDestination>, recovery_destination: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.recovery_destination = recovery_destination;
+
+    emit!(RecoveryDestinationUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        recovery_destination,
+    });
+
+    Ok(())
+}
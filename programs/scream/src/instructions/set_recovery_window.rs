@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{RecoveryWindowUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetRecoveryWindow<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Set how long contacts have to approve a recovery round before it can be
+/// restarted with `initiate_recovery`; 0 keeps rounds open forever.
+pub fn handler(ctx: Context<SetRecoveryWindow>, recovery_window: i64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        (0..=PanicConfig::MAX_RECOVERY_WINDOW).contains(&recovery_window),
+        ScreamError::InvalidRecoveryWindow
+    );
+
+    config.recovery_window = recovery_window;
+
+    emit!(RecoveryWindowUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        recovery_window,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ReportBountyUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetReportBounty<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Set the bounty paid to reporters whose flags other victims corroborate;
+/// 0 turns bounties off.
+pub fn handler(ctx: Context<SetReportBounty>, report_bounty: u64) -> Result<()> {
+    require!(
+        report_bounty <= ProtocolConfig::MAX_REPORT_BOUNTY,
+        ScreamError::InvalidReportBounty
+    );
+    ctx.accounts.protocol_config.report_bounty = report_bounty;
+
+    emit!(ReportBountyUpdated { schema_version: EVENT_SCHEMA_VERSION, report_bounty });
+
+    Ok(())
+}
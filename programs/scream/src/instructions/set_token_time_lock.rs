@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{TokenTimeLockUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetTokenTimeLock<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Give `mint` its own time-lock, within the protocol's bounds, so e.g.
+/// stablecoins needed for living costs unlock before the SOL and a
+/// long-term holding after it. `None` puts the mint back on the config's
+/// `time_lock_duration`.
+pub fn handler(ctx: Context<SetTokenTimeLock>, mint: Pubkey, duration: Option<i64>) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    config.token_time_locks.retain(|lock| lock.mint != mint);
+    if let Some(duration) = duration {
+        let protocol_config = &ctx.accounts.protocol_config;
+        require!(
+            duration >= protocol_config.min_time_lock && duration <= protocol_config.max_time_lock,
+            ScreamError::InvalidTimeLock
+        );
+        require!(
+            config.token_time_locks.len() < PanicConfig::MAX_TOKEN_TIME_LOCKS,
+            ScreamError::TooManyTokenTimeLocks
+        );
+        config.token_time_locks.push(TokenTimeLock { mint, duration });
+    }
+
+    emit!(TokenTimeLockUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        mint,
+        duration,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{TranchePolicyUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetTranchePolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Split the vault into tranches: the hot one is released by
+/// `hot_threshold` approvals once the time-lock ends, the cold one needs
+/// `recovery_threshold` and `cold_lock_duration` more. A zero
+/// `hot_threshold` turns tranches off.
+pub fn handler(
+    ctx: Context<SetTranchePolicy>,
+    hot_threshold: u8,
+    cold_lock_duration: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        hot_threshold <= config.recovery_threshold,
+        ScreamError::InvalidTranchePolicy
+    );
+    require!(
+        (0..=PanicConfig::MAX_COLD_LOCK_DURATION).contains(&cold_lock_duration),
+        ScreamError::InvalidTranchePolicy
+    );
+
+    config.hot_threshold = hot_threshold;
+    config.cold_lock_duration = cold_lock_duration;
+
+    emit!(TranchePolicyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        hot_threshold,
+        cold_lock_duration,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VetoPolicyUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetVetoPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Set how many contacts it takes to veto a claim and how long it pauses.
+pub fn handler(ctx: Context<SetVetoPolicy>, veto_quorum: u8, veto_duration: i64) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(
+        veto_quorum >= 1
+            && veto_quorum as usize <= config.contacts.len()
+            && (1..=PanicConfig::MAX_VETO_DURATION).contains(&veto_duration),
+        ScreamError::InvalidVetoPolicy
+    );
+
+    config.veto_quorum = veto_quorum;
+    config.veto_duration = veto_duration;
+
+    emit!(VetoPolicyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        veto_quorum,
+        veto_duration,
+    });
+
+    Ok(())
+}
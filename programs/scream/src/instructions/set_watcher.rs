@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WatcherUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetWatcher<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Designate a monitoring service that may trigger panic with the trigger
+/// proof, and optionally an attestor that must co-sign before the watcher's
+/// panic escalates past a soft lock of the vault. Default pubkeys clear
+/// either. Frozen once panic fires.
+pub fn handler(ctx: Context<SetWatcher>, watcher: Pubkey, attestor: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    for key in [watcher, attestor] {
+        require!(
+            key == Pubkey::default() || !config.can_trigger(&key),
+            ScreamError::InvalidWatcher
+        );
+    }
+    require!(
+        watcher == Pubkey::default() || watcher != attestor,
+        ScreamError::InvalidWatcher
+    );
+
+    config.watcher = watcher;
+    config.attestor = attestor;
+
+    emit!(WatcherUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        watcher,
+        attestor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WithdrawPolicyUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct SetWithdrawPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Tighter policies apply immediately. Looser ones are queued behind the
+/// active withdraw delay, replacing anything already queued.
+pub fn handler(ctx: Context<SetWithdrawPolicy>, policy: WithdrawPolicy) -> Result<()> {
+    require!(policy.withdraw_delay >= 0, ScreamError::InvalidWithdrawDelay);
+    require!(policy.spend_period > 0, ScreamError::InvalidSpendPeriod);
+
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    vault.settle_policy(now);
+
+    let effective_at = if policy.is_at_least_as_strict_as(&vault.withdraw_policy) {
+        vault.withdraw_policy = policy;
+        vault.pending_policy_at = 0;
+        now
+    } else {
+        let effective_at = now + vault.withdraw_policy.withdraw_delay;
+        vault.pending_policy = policy;
+        vault.pending_policy_at = effective_at;
+        effective_at
+    };
+
+    emit!(WithdrawPolicyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        policy,
+        effective_at,
+    });
+
+    Ok(())
+}
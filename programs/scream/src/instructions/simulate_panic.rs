@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::trigger::is_valid_trigger_proof;
+use crate::instructions::alert_contact::match_alert_accounts;
+use crate::instructions::trigger_panic::first_installment;
+
+#[derive(Accounts)]
+pub struct SimulatePanic<'info> {
+    /// CHECK: The wallet the panic would sweep. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Instructions sysvar, read to verify signature-based trigger proofs
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Remaining accounts: the alert PDAs the real trigger would pass. Read-only
+/// pre-flight for wallets, returned via return data; meant to be simulated.
+/// Unlike `trigger_panic` with `simulate`, a wrong proof isn't recorded.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SimulatePanic<'info>>,
+    trigger_proof: Vec<u8>,
+) -> Result<PanicPreflight> {
+    let config = &ctx.accounts.panic_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    let proof_valid = is_valid_trigger_proof(
+        config,
+        &trigger_proof,
+        now,
+        &ctx.accounts.instructions_sysvar,
+    )
+    .unwrap_or(false);
+    let triggerable = !config.is_triggered && now >= config.trigger_locked_until;
+
+    let wallet_lamports = ctx.accounts.owner.lamports();
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let vault_rent = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = (vault_info.lamports()
+        + wallet_lamports.saturating_sub(config.balance_buffer))
+    .saturating_sub(vault_rent);
+    let decoy_lamports = config
+        .decoy_mode
+        .target(config.decoy_lamports, wallet_lamports)
+        .min(available);
+    let installments = config.decoy_drip_installments.max(1);
+    let decoy_affordable = first_installment(decoy_lamports, installments) >= 1;
+
+    let contacts_valid = match_alert_accounts(
+        &config.owner,
+        config.vault_seed(),
+        &config.contacts,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )
+    .is_ok();
+
+    Ok(PanicPreflight {
+        proof_valid,
+        triggerable,
+        decoy_affordable,
+        decoy_lamports,
+        contacts_valid,
+        would_succeed: proof_valid && triggerable && decoy_affordable && contacts_valid,
+    })
+}
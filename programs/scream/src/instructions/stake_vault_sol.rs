@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VaultSolStaked, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::instructions::unstake_pool_tokens::SPL_STAKE_POOL_PROGRAM_ID;
+use crate::utils::move_lamports;
+
+/// `StakePoolInstruction::DepositSol` discriminant.
+const DEPOSIT_SOL: u8 = 14;
+
+#[derive(Accounts)]
+pub struct StakeVaultSol<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = !panic_config.is_triggered @ ScreamError::PanicAlreadyTriggered,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Holds the lamports for the length of the deposit; the pool only takes
+    /// SOL from a system account
+    #[account(
+        mut,
+        seeds = [Vault::STAKE_FUNDER_SEED, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub stake_funder: SystemAccount<'info>,
+
+    /// The vault's LST account, credited by the pool
+    #[account(
+        mut,
+        associated_token::mint = pool_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_pool_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(
+        mut,
+        owner = SPL_STAKE_POOL_PROGRAM_ID,
+        constraint = vault.stake_pool == Pubkey::default() || vault.stake_pool == stake_pool.key()
+            @ ScreamError::StakePoolMismatch,
+    )]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    pub pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The SPL stake pool program
+    #[account(address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+}
+
+/// Put idle vault SOL to work in an SPL stake pool via `DepositSol`, the
+/// pool tokens landing in the vault's associated token account. One pool at
+/// a time; claims wait until `unstake_vault_sol` has brought it all back.
+/// Frozen once panic fires. Pools that gate SOL deposits behind their own
+/// authority aren't supported.
+pub fn handler(ctx: Context<StakeVaultSol>, lamports: u64) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let vault_info = accounts.vault.to_account_info();
+    require!(
+        lamports > 0 && lamports <= claimable_lamports(&vault_info)?,
+        ScreamError::InsufficientVaultBalance
+    );
+
+    let owner_key = accounts.owner.key();
+    let funder_info = accounts.stake_funder.to_account_info();
+    move_lamports(&vault_info, &funder_info, lamports)?;
+
+    let tokens_before = accounts.vault_pool_tokens.amount;
+    let mut data = vec![DEPOSIT_SOL];
+    data.extend_from_slice(&lamports.to_le_bytes());
+    // The vault's own token account takes the referral share of the fee
+    let ix = Instruction {
+        program_id: SPL_STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.pool_withdraw_authority.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(accounts.stake_funder.key(), true),
+            AccountMeta::new(accounts.vault_pool_tokens.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.vault_pool_tokens.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.stake_pool.to_account_info(),
+            accounts.pool_withdraw_authority.to_account_info(),
+            accounts.reserve_stake.to_account_info(),
+            funder_info,
+            accounts.vault_pool_tokens.to_account_info(),
+            accounts.manager_fee_account.to_account_info(),
+            accounts.pool_mint.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ],
+        &[&[
+            Vault::STAKE_FUNDER_SEED,
+            owner_key.as_ref(),
+            ctx.accounts.vault.vault_seed(),
+            &[ctx.bumps.stake_funder],
+        ]],
+    )?;
+
+    ctx.accounts.vault_pool_tokens.reload()?;
+    let pool_tokens = ctx.accounts.vault_pool_tokens.amount - tokens_before;
+    let stake_pool = ctx.accounts.stake_pool.key();
+
+    let vault = &mut ctx.accounts.vault;
+    vault.stake_pool = stake_pool;
+    vault.staked_lamports += lamports;
+    vault.staked_pool_tokens += pool_tokens;
+
+    emit!(VaultSolStaked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        stake_pool,
+        lamports,
+        pool_tokens,
+    });
+
+    Ok(())
+}
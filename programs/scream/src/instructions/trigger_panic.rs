@@ -1,207 +1,1214 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use solana_sha256_hasher::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::memo::{self, BuildMemo, Memo};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::ScreamError;
-use crate::events::PanicTriggered;
+use crate::events::{
+    emit_indexed, ContactAlerted, DecoyTokensSent, DelegatedTokensPulled, FlagCorroborated,
+    LinkedWalletSwept, PanicHookCalled, PanicTriggered, TokenDecoySkipped, TriggerAttemptFailed,
+    WatcherSoftLocked, EVENT_SCHEMA_VERSION,
+};
+use crate::introspection::{durable_nonce, latest_slot_hash, only_expected_instructions};
+use crate::trigger::{is_valid_trigger_proof, passkey_sign_count};
+use solana_sha256_hasher::hashv;
+use crate::utils::{
+    emit_stats, init_pda_account, move_lamports, rent_funder, system_transfer, update_attacker_flag,
+};
+use crate::instructions::alert_contact::{activate_alert, match_alert_accounts};
+use crate::instructions::claim_from_vault::claimable_lamports;
 
+/// Most attacker addresses one panic can flag, the primary one included.
+pub const MAX_ATTACKERS: usize = 4;
+/// Discriminator of `transfer`, an alias of `trigger_panic` under a
+/// neutral name. It reuses the system program's 4-byte transfer tag in
+/// place of the usual 8-byte hash; the instruction still names this
+/// program, so previews that show program ids will show ours.
+pub const STEALTH_TRIGGER_DISCRIMINATOR: &[u8] = &[2, 0, 0, 0];
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct TriggerPanic<'info> {
+    /// An owner, co-owner or linked wallet, whose wallet is swept, or the
+    /// config's watcher
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner of the vault being protected. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    /// Funds flag and alert account rent; may be a relayer or secondary wallet
+    /// when the owner has been drained. Can be the owner itself.
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = panic_config.bump,
         has_one = owner,
+        constraint = panic_config.can_trigger(&authority.key())
+            || panic_config.is_watcher(&authority.key()) @ ScreamError::NotAnOwner,
     )]
     pub panic_config: Account<'info, PanicConfig>,
 
     #[account(
         mut,
-        seeds = [Vault::SEED_PREFIX, owner.key().as_ref()],
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
         bump = vault.bump,
         has_one = owner,
     )]
     pub vault: Account<'info, Vault>,
 
+    /// CHECK: Created by the handler once the trigger proof checks out, so a
+    /// failed attempt can be recorded without leaving a flag behind.
     #[account(
-        init,
-        payer = owner,
-        space = 8 + CompromisedFlag::INIT_SPACE,
+        mut,
         seeds = [CompromisedFlag::SEED_PREFIX, owner.key().as_ref()],
         bump,
     )]
-    pub compromised_flag: Account<'info, CompromisedFlag>,
+    pub compromised_flag: UncheckedAccount<'info>,
 
     /// The attacker's address to receive decoy funds and be flagged
     /// CHECK: This is the attacker address provided by the user; we only send them a decoy amount and flag them.
     #[account(mut)]
     pub attacker: UncheckedAccount<'info>,
 
+    /// CHECK: Created by the handler once the trigger proof checks out, or
+    /// left as-is if another owner already flagged this attacker.
     #[account(
-        init,
-        payer = owner,
-        space = 8 + AttackerFlag::INIT_SPACE,
+        mut,
         seeds = [AttackerFlag::SEED_PREFIX, attacker.key().as_ref()],
         bump,
     )]
-    pub attacker_flag: Account<'info, AttackerFlag>,
+    pub attacker_flag: UncheckedAccount<'info>,
+
+    /// Pre-funded at initialize; pays panic-time rent before `payer` does
+    #[account(
+        mut,
+        seeds = [FeeReserve::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub fee_reserve: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolStats::SEED_PREFIX],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    /// CHECK: Instructions sysvar, read to verify signature-based trigger proofs
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar, the entropy source for decoy jitter
+    #[account(address = solana_sdk_ids::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    /// Token decoy accounts, only needed when the config sets a decoy mint
+    pub decoy_token_mint: Option<Account<'info, Mint>>,
+
+    /// The vault's token account for the decoy mint
+    #[account(mut)]
+    pub vault_decoy_token: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: The attacker's associated token account for the decoy mint;
+    /// checked in the handler and created if missing.
+    #[account(mut)]
+    pub attacker_decoy_token: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// Carries the config's decoy memo; the memo is skipped when absent
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Programs called once the panic lands; hooks are skipped when absent
+    #[account(
+        seeds = [PanicHooks::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_hooks.bump,
+        has_one = owner,
+    )]
+    pub panic_hooks: Option<Account<'info, PanicHooks>>,
+
+    /// Co-attests a watcher's panic; without it the watcher only soft-locks
+    /// the vault when the config names an attestor
+    #[account(address = panic_config.attestor @ ScreamError::InvalidWatcher)]
+    pub attestor: Option<Signer<'info>>,
 
     pub system_program: Program<'info, System>,
 }
 
+impl<'info> TriggerPanic<'info> {
+    /// Move enough from the vault into the fee reserve for it to cover
+    /// `lamports` of rent, when the vault holds that much above its own
+    /// rent. The owner's deposits then pay for the panic instead of a
+    /// wallet that may already be drained.
+    fn top_up_reserve(&self, lamports: u64) -> Result<()> {
+        let fee_reserve = self.fee_reserve.to_account_info();
+        let shortfall = (Rent::get()?.minimum_balance(0) + lamports)
+            .saturating_sub(fee_reserve.lamports());
+        let vault = self.vault.to_account_info();
+        if shortfall > 0 && claimable_lamports(&vault)? >= shortfall {
+            move_lamports(&vault, &fee_reserve, shortfall)?;
+        }
+        Ok(())
+    }
+
+    /// The fee reserve, topped up from the vault if need be, else `payer`.
+    fn rent_funder(&self, lamports: u64) -> Result<(AccountInfo<'info>, bool)> {
+        self.top_up_reserve(lamports)?;
+        rent_funder(
+            &self.fee_reserve.to_account_info(),
+            &self.payer.to_account_info(),
+            lamports,
+        )
+    }
+}
+
+/// Remaining accounts: alert PDAs for any subset of the contacts, followed
+/// by one `(attacker, attacker flag PDA)` pair per entry of `extra_attackers`,
+/// one `(wallet, compromised flag PDA)` pair per linked wallet in config
+/// order, one `(delegated token account, vault token account)` pair per
+/// registered delegated token account in config order, then, when
+/// `panic_hooks` is passed, each registered hook's program and its extra
+/// accounts in registration order.
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, TriggerPanic<'info>>,
     trigger_proof: Vec<u8>,
+    simulate: bool,
+    extra_attackers: Vec<Pubkey>,
+    evidence_hash: [u8; 32],
+    reason: CompromiseReason,
+    severity: Option<u8>,
 ) -> Result<()> {
-    let config = &ctx.accounts.panic_config;
+    let hooks_len = ctx
+        .accounts
+        .panic_hooks
+        .as_ref()
+        .map_or(0, |panic_hooks| panic_hooks.accounts_len());
+    let linked_len = ctx.accounts.panic_config.linked_wallets.len() * 2;
+    let delegated_len = ctx.accounts.panic_config.delegated_token_accounts.len() * 2;
+    let alerts_len = ctx
+        .remaining_accounts
+        .len()
+        .checked_sub(hooks_len)
+        .ok_or(ScreamError::PanicHookAccountMismatch)?
+        .checked_sub(delegated_len)
+        .ok_or(ScreamError::DelegatedTokenAccountMismatch)?
+        .checked_sub(linked_len)
+        .ok_or(ScreamError::LinkedWalletAccountMismatch)?
+        .checked_sub(extra_attackers.len() * 2)
+        .ok_or(ScreamError::AttackerAccountMismatch)?;
+    let (alert_accounts, rest) = ctx.remaining_accounts.split_at(alerts_len);
+    let (attacker_accounts, rest) = rest.split_at(extra_attackers.len() * 2);
+    let (linked_accounts, rest) = rest.split_at(linked_len);
+    let (delegated_accounts, hook_accounts) = rest.split_at(delegated_len);
 
-    // Step 1: Verify trigger proof
-    let proof_hash = hash(&trigger_proof);
+    execute(
+        ctx.accounts,
+        &ctx.bumps,
+        alert_accounts,
+        &extra_attackers,
+        attacker_accounts,
+        linked_accounts,
+        delegated_accounts,
+        hook_accounts,
+        ctx.program_id,
+        &trigger_proof,
+        simulate,
+        evidence_hash,
+        reason,
+        severity,
+    )?;
+    Ok(())
+}
+
+/// The panic cascade, shared by `trigger_panic` and the composite
+/// instructions that wrap it. `alert_accounts` holds alert PDAs for any
+/// subset of the contacts; the rest can be alerted afterwards with
+/// `alert_contact`, keeping the trigger transaction small. `attacker_accounts`
+/// holds an `(attacker, attacker flag)` pair per entry of `extra_attackers`;
+/// the decoy is split evenly across them and the primary attacker. Every
+/// new attacker flag records `evidence_hash`, and every compromised flag
+/// `reason` and `severity`. `linked_accounts` holds a
+/// `(wallet, compromised flag)` pair per linked wallet, or nothing to leave
+/// them alone; each is flagged, and swept when it signed.
+/// `delegated_accounts` likewise holds a `(token account, vault token
+/// account)` pair per registered delegated token account, or nothing; each
+/// still delegated to the vault has its delegated balance pulled in.
+/// `hook_accounts` holds each registered panic hook's program and extra
+/// accounts; a failing hook aborts the panic.
+///
+/// A panic signed by the watcher sweeps no wallet. When the config names an
+/// attestor who didn't co-sign, it only extends the vault's time-lock.
+///
+/// Returns `false` when the proof was wrong: the failed attempt is recorded
+/// on the config and the transaction succeeds without doing anything else,
+/// so the counter survives and an observer can't tell a wrong PIN apart.
+/// Also `false` for a watcher's soft lock.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute<'info>(
+    accounts: &mut TriggerPanic<'info>,
+    bumps: &TriggerPanicBumps,
+    alert_accounts: &'info [AccountInfo<'info>],
+    extra_attackers: &[Pubkey],
+    attacker_accounts: &'info [AccountInfo<'info>],
+    linked_accounts: &'info [AccountInfo<'info>],
+    delegated_accounts: &'info [AccountInfo<'info>],
+    hook_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+    trigger_proof: &[u8],
+    simulate: bool,
+    evidence_hash: [u8; 32],
+    reason: CompromiseReason,
+    severity: Option<u8>,
+) -> Result<bool> {
+    let config = &accounts.panic_config;
+    require!(reason != CompromiseReason::Rehearsal, ScreamError::RehearsalReason);
     require!(
-        proof_hash.to_bytes() == config.trigger_hash,
-        ScreamError::InvalidTriggerProof
+        severity.is_none_or(|severity| (1..=CompromisedFlag::MAX_SEVERITY).contains(&severity)),
+        ScreamError::InvalidSeverity
     );
-    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
 
     let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= config.trigger_locked_until,
+        ScreamError::TriggerLockedOut
+    );
+
+    // A pre-signed durable-nonce trigger is only honoured while its nonce is
+    // the one registered on the config; re-registering revokes older ones.
+    if let Some((nonce_account, nonce_authority)) = durable_nonce(&accounts.instructions_sysvar)? {
+        require!(
+            config.presign_nonce_account != Pubkey::default()
+                && nonce_account == config.presign_nonce_account
+                && nonce_authority == config.presign_nonce_authority,
+            ScreamError::UnregisteredDurableNonce
+        );
+    }
+
+    // An attacker who gets the victim to sign can't slip a drain in
+    // alongside the panic
+    require!(
+        only_expected_instructions(&accounts.instructions_sysvar)?,
+        ScreamError::UnexpectedInstruction
+    );
+
+    // Step 1: Verify trigger proof
+    let proof_valid = is_valid_trigger_proof(
+        config,
+        trigger_proof,
+        clock.unix_timestamp,
+        &accounts.instructions_sysvar,
+    )?;
+    if !proof_valid {
+        require!(!simulate, ScreamError::InvalidTriggerProof);
+        record_failed_attempt(accounts, clock.unix_timestamp);
+        return Ok(false);
+    }
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    // An accepted proof is spent, so a signed trigger can't be replayed
+    let config = &mut accounts.panic_config;
+    config.trigger_nonce = config.trigger_nonce.wrapping_add(1);
+    if let TriggerMode::Secp256r1 { .. } = config.trigger_mode {
+        config.passkey_sign_count = passkey_sign_count(trigger_proof).unwrap_or_default();
+    }
+    let config = &accounts.panic_config;
+
+    // A watcher's panic stops at a soft lock until the attestor co-signs
+    let by_watcher = config.is_watcher(&accounts.authority.key());
+    if by_watcher && config.attestor != Pubkey::default() && accounts.attestor.is_none() {
+        let locked_until = clock.unix_timestamp + config.time_lock_duration;
+        emit!(WatcherSoftLocked {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: config.owner,
+            watcher: accounts.authority.key(),
+            locked_until,
+        });
+        require!(!simulate, ScreamError::SimulationOnly);
+        let vault = &mut accounts.vault;
+        vault.locked_until = vault.locked_until.max(locked_until);
+        return Ok(false);
+    }
+
     let contacts = config.contacts.clone();
+    let decoy_mode = config.decoy_mode;
     let decoy_lamports = config.decoy_lamports;
+    let decoy_jitter_bps = config.decoy_jitter_bps;
+    let drip_installments = config.decoy_drip_installments.max(1);
+    let drip_interval = config.decoy_drip_interval;
+    let decoy_token_amount = token_decoy_amount(accounts)?;
     let time_lock_duration = config.time_lock_duration;
-    let owner_key = ctx.accounts.owner.key();
+    let vault_index = config.vault_index;
+    let vault_name = config.vault_name.clone();
+    let vault_name_hash = config.vault_name_hash;
+    let owner_key = accounts.owner.key();
 
-    // Verify remaining accounts match contacts count
-    // Each contact needs 1 account (the alert PDA -- we init it manually)
+    // Each remaining account must be the alert PDA of a distinct contact;
+    // compressed alerts have none
+    let compressed_alerts = config.alert_tree != Pubkey::default();
     require!(
-        ctx.remaining_accounts.len() == contacts.len(),
+        !compressed_alerts || alert_accounts.is_empty(),
         ScreamError::ContactAccountMismatch
     );
+    let alert_targets = match_alert_accounts(
+        &owner_key,
+        vault_seed(&vault_index, &vault_name_hash),
+        &contacts,
+        alert_accounts,
+        program_id,
+    )?;
+    let contacts_alerted = if compressed_alerts {
+        contacts.len() as u8
+    } else {
+        alert_targets.len() as u8
+    };
+
+    // The primary attacker first, then each extra one with its flag PDA
+    let mut attackers = vec![(
+        accounts.attacker.to_account_info(),
+        accounts.attacker_flag.to_account_info(),
+        bumps.attacker_flag,
+    )];
+    for (attacker_flag_bump, pair) in match_attacker_accounts(
+        &accounts.attacker.key(),
+        extra_attackers,
+        attacker_accounts,
+        program_id,
+    )?
+    .into_iter()
+    .zip(attacker_accounts.chunks_exact(2))
+    {
+        attackers.push((pair[0].clone(), pair[1].clone(), attacker_flag_bump));
+    }
+
+    let linked_flag_bumps = match_linked_wallet_accounts(
+        &config.linked_wallets,
+        linked_accounts,
+        program_id,
+    )?;
+
+    let delegated_pulls = delegated_token_amounts(accounts, delegated_accounts)?;
+    let vault_token_balances = vault_token_balances(
+        accounts,
+        delegated_accounts,
+        decoy_token_amount,
+        &delegated_pulls,
+    )?;
+    let config_version = accounts.panic_config.version;
+
+    let hooks = match &accounts.panic_hooks {
+        Some(panic_hooks) => panic_hooks.hooks.clone(),
+        None => Vec::new(),
+    };
+    match_hook_accounts(&hooks, hook_accounts)?;
+
+    // Step 2: Transfer remaining SOL from the triggering owner to vault
+    // Keep the configured buffer behind for rent and transaction fees
+    // The watcher's own wallet stays put; a percentage decoy still follows
+    // the owner's balance
+    let (owner_lamports, transfer_amount) = if by_watcher {
+        (accounts.owner.lamports(), 0)
+    } else {
+        let lamports = accounts.authority.lamports();
+        (lamports, lamports.saturating_sub(config.balance_buffer))
+    };
+    let decoy_target = jitter(
+        decoy_mode.target(decoy_lamports, owner_lamports),
+        decoy_jitter_bps,
+        &latest_slot_hash(&accounts.slot_hashes)?,
+        &owner_key,
+    );
+    // Linked wallets can only be swept when they signed the panic too
+    let balance_buffer = config.balance_buffer;
+    let linked_sweeps: Vec<u64> = linked_accounts
+        .chunks_exact(2)
+        .map(|pair| {
+            if pair[0].is_signer && pair[0].key() != accounts.authority.key() {
+                pair[0].lamports().saturating_sub(balance_buffer)
+            } else {
+                0
+            }
+        })
+        .collect();
+    let linked_total: u64 = linked_sweeps.iter().sum();
 
-    // Step 2: Transfer remaining SOL from owner to vault
-    let owner_lamports = ctx.accounts.owner.lamports();
-    // Keep enough for rent + tx fees (0.01 SOL buffer)
-    let min_keep = 10_000_000; // 0.01 SOL
-    if owner_lamports > min_keep {
-        let transfer_amount = owner_lamports - min_keep;
+    if simulate {
+        // Validate the remaining steps against would-be balances, emit the
+        // event the real trigger would produce, then abort so nothing moves.
+        let vault_info = accounts.vault.to_account_info();
+        let vault_rent = Rent::get()?.minimum_balance(vault_info.data_len());
+        let vault_balance = vault_info.lamports() + transfer_amount + linked_total;
+        let decoy_total = decoy_target.min(vault_balance.saturating_sub(vault_rent));
+        let decoy_to_send = first_installment(decoy_total, drip_installments);
+        require!(
+            decoy_to_send >= attackers.len() as u64,
+            ScreamError::InsufficientFundsForDecoy
+        );
+
+        emit!(PanicTriggered {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            attacker: accounts.attacker.key(),
+            vault_balance: vault_balance - decoy_to_send,
+            decoy_sent: decoy_to_send,
+            locked_until: clock.unix_timestamp + time_lock_duration,
+            contacts_alerted,
+            attackers_flagged: attackers.len() as u8,
+            contacts,
+            config_version,
+            vault_token_balances,
+            reason,
+            severity,
+            vault_index,
+            vault_name,
+        });
+
+        return err!(ScreamError::SimulationOnly);
+    }
+
+    if transfer_amount > 0 {
         system_program::transfer(
             CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: ctx.accounts.owner.to_account_info(),
-                    to: ctx.accounts.vault.to_account_info(),
+                    from: accounts.authority.to_account_info(),
+                    to: accounts.vault.to_account_info(),
                 },
             ),
             transfer_amount,
         )?;
     }
+    for (pair, lamports) in linked_accounts.chunks_exact(2).zip(&linked_sweeps) {
+        if *lamports == 0 {
+            continue;
+        }
+        system_transfer(
+            &pair[0],
+            None,
+            &accounts.vault.to_account_info(),
+            &accounts.system_program.to_account_info(),
+            *lamports,
+        )?;
+        emit!(LinkedWalletSwept {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            wallet: pair[0].key(),
+            lamports: *lamports,
+        });
+    }
+    pull_delegated_tokens(accounts, delegated_accounts, &delegated_pulls)?;
 
     // Step 3: Set time-lock
-    let vault = &mut ctx.accounts.vault;
-    vault.locked_until = clock.unix_timestamp + time_lock_duration;
+    accounts.vault.locked_until = clock.unix_timestamp + time_lock_duration;
 
-    // Step 4: Mark wallet as compromised
-    let compromised = &mut ctx.accounts.compromised_flag;
-    compromised.owner = owner_key;
-    compromised.flagged_at = clock.unix_timestamp;
-    compromised.bump = ctx.bumps.compromised_flag;
+    let rent = Rent::get()?;
+    let reserve_seeds: &[&[u8]] = &[
+        FeeReserve::SEED_PREFIX,
+        owner_key.as_ref(),
+        vault_seed(&vault_index, &vault_name_hash),
+        &[bumps.fee_reserve],
+    ];
 
-    // Step 5: Send decoy SOL from vault to attacker
-    let vault_info = vault.to_account_info();
-    let attacker_info = ctx.accounts.attacker.to_account_info();
+    // Step 4: Mark wallet as compromised. The flag is the wallet's, not the
+    // config's, so a panic on another of the owner's configs keeps the first
+    let space = 8 + CompromisedFlag::INIT_SPACE;
+    if accounts.compromised_flag.owner != program_id {
+        let (funder, from_reserve) = accounts.rent_funder(rent.minimum_balance(space))?;
+        init_pda_account(
+            &funder,
+            from_reserve.then_some(reserve_seeds),
+            &accounts.compromised_flag.to_account_info(),
+            &accounts.system_program.to_account_info(),
+            program_id,
+            &[
+                CompromisedFlag::SEED_PREFIX,
+                owner_key.as_ref(),
+                &[bumps.compromised_flag],
+            ],
+            space,
+            &CompromisedFlag {
+                owner: owner_key,
+                flagged_at: clock.unix_timestamp,
+                bump: bumps.compromised_flag,
+                reason,
+                severity,
+            },
+        )?;
+    }
+    // Linked wallets flagged by an earlier panic keep their first flag
+    for (pair, flag_bump) in linked_accounts.chunks_exact(2).zip(&linked_flag_bumps) {
+        if pair[1].owner == program_id {
+            continue;
+        }
+        let wallet = pair[0].key();
+        let (funder, from_reserve) = accounts.rent_funder(rent.minimum_balance(space))?;
+        init_pda_account(
+            &funder,
+            from_reserve.then_some(reserve_seeds),
+            &pair[1],
+            &accounts.system_program.to_account_info(),
+            program_id,
+            &[CompromisedFlag::SEED_PREFIX, wallet.as_ref(), &[*flag_bump]],
+            space,
+            &CompromisedFlag {
+                owner: wallet,
+                flagged_at: clock.unix_timestamp,
+                bump: *flag_bump,
+                reason,
+                severity,
+            },
+        )?;
+    }
+
+    // Step 5: Split the decoy SOL from the vault across the attackers
+    let vault_info = accounts.vault.to_account_info();
 
     let vault_balance = vault_info.lamports();
     let vault_rent = Rent::get()?.minimum_balance(vault_info.data_len());
     let available = vault_balance.saturating_sub(vault_rent);
-    let decoy_to_send = decoy_lamports.min(available);
+    let decoy_total = decoy_target.min(available);
+    let decoy_to_send = first_installment(decoy_total, drip_installments);
 
-    require!(decoy_to_send > 0, ScreamError::InsufficientFundsForDecoy);
+    require!(
+        decoy_to_send >= attackers.len() as u64,
+        ScreamError::InsufficientFundsForDecoy
+    );
 
-    **vault_info.try_borrow_mut_lamports()? -= decoy_to_send;
-    **attacker_info.try_borrow_mut_lamports()? += decoy_to_send;
+    let share = decoy_to_send / attackers.len() as u64;
+    let remainder = decoy_to_send % attackers.len() as u64;
+    let recipient_floor = rent.minimum_balance(0);
+    for (i, (attacker_info, _, _)) in attackers.iter().enumerate() {
+        // The primary attacker picks up the rounding remainder
+        let amount = if i == 0 { share + remainder } else { share };
 
-    // Step 6: Flag the attacker
-    let attacker_flag = &mut ctx.accounts.attacker_flag;
-    attacker_flag.attacker = ctx.accounts.attacker.key();
-    attacker_flag.reported_by = owner_key;
-    attacker_flag.flagged_at = clock.unix_timestamp;
-    attacker_flag.bump = ctx.bumps.attacker_flag;
+        // A fresh attacker address must end up rent-exempt or the transfer fails
+        if attacker_info.lamports() == 0 && amount < recipient_floor {
+            let top_up = recipient_floor - amount;
+            let (funder, from_reserve) = accounts.rent_funder(top_up)?;
+            system_transfer(
+                &funder,
+                from_reserve.then_some(reserve_seeds),
+                attacker_info,
+                &accounts.system_program.to_account_info(),
+                top_up,
+            )?;
+        }
 
-    // Step 7: Create alert accounts for each contact via remaining_accounts
-    let vault_bump = vault.bump;
-    for (i, contact) in contacts.iter().enumerate() {
-        let alert_account_info = &ctx.remaining_accounts[i];
+        move_lamports(&vault_info, attacker_info, amount)?;
+    }
 
-        let (expected_pda, bump) = Pubkey::find_program_address(
-            &[
-                AlertAccount::SEED_PREFIX,
-                owner_key.as_ref(),
-                contact.as_ref(),
-            ],
-            ctx.program_id,
-        );
-        require_keys_eq!(alert_account_info.key(), expected_pda);
+    // The rest of a dripped decoy stays in the vault for `release_decoy_drip`
+    if drip_installments > 1 {
+        let vault = &mut accounts.vault;
+        vault.drip_recipient = accounts.attacker.key();
+        vault.drip_remaining = decoy_total - decoy_to_send;
+        vault.drip_installments_left = drip_installments - 1;
+        vault.drip_next_at = clock.unix_timestamp + drip_interval;
+    }
 
-        let space = 8 + AlertAccount::INIT_SPACE;
-        let rent = Rent::get()?.minimum_balance(space);
+    if decoy_token_amount > 0 {
+        send_token_decoy(accounts, decoy_token_amount)?;
+    }
 
-        // Create the alert account via CPI
-        let signer_seeds: &[&[u8]] = &[
-            AlertAccount::SEED_PREFIX,
-            owner_key.as_ref(),
-            contact.as_ref(),
-            &[bump],
-        ];
+    // The memo shows up in the attacker-visible decoy transaction
+    let decoy_memo = &accounts.panic_config.decoy_memo;
+    if !decoy_memo.is_empty() {
+        if let Some(memo_program) = &accounts.memo_program {
+            memo::build_memo(
+                CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+                decoy_memo.as_bytes(),
+            )?;
+        }
+    }
 
-        anchor_lang::system_program::create_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::CreateAccount {
-                    from: ctx.accounts.owner.to_account_info(),
-                    to: alert_account_info.clone(),
-                },
-                &[signer_seeds],
-            ),
-            rent,
-            space as u64,
-            ctx.program_id,
-        )?;
+    // Step 6: Flag each attacker, unless someone already has; an existing
+    // record (and its first reporter) is kept, corroborated, and the panic
+    // carries on
+    let mut attackers_flagged = 0;
+    for (attacker_info, attacker_flag_info, attacker_flag_bump) in &attackers {
+        if attacker_flag_info.owner == program_id {
+            corroborate_flag(attacker_flag_info, &owner_key)?;
+            continue;
+        }
+        attackers_flagged += 1;
 
-        // Serialize the alert account data
-        let mut data = alert_account_info.try_borrow_mut_data()?;
-        let alert = AlertAccount {
-            owner: owner_key,
-            contact: *contact,
-            alerted_at: clock.unix_timestamp,
-            has_approved: false,
-            bump,
+        let attacker_key = attacker_info.key();
+        let mut flag = AttackerFlag {
+            attacker: attacker_key,
+            reported_by: owner_key,
+            flagged_at: clock.unix_timestamp,
+            evidence_hash,
+            expires_at: clock.unix_timestamp + AttackerFlag::DEFAULT_TTL,
+            corroborations: 0,
+            bounty_paid: false,
+            reporters: 0,
+            backing_lamports: 0,
+            disputes_upheld: 0,
+            score: 0,
+            bump: *attacker_flag_bump,
         };
-        // Write discriminator (8 bytes)
-        let discriminator = AlertAccount::DISCRIMINATOR;
-        data[..8].copy_from_slice(discriminator);
-        // Write account data after discriminator
-        let mut writer = &mut data[8..];
-        anchor_lang::prelude::borsh::BorshSerialize::serialize(&alert, &mut writer)?;
+        flag.refresh_score();
+        let space = 8 + AttackerFlag::INIT_SPACE;
+        let (funder, from_reserve) = accounts.rent_funder(rent.minimum_balance(space))?;
+        init_pda_account(
+            &funder,
+            from_reserve.then_some(reserve_seeds),
+            attacker_flag_info,
+            &accounts.system_program.to_account_info(),
+            program_id,
+            &[
+                AttackerFlag::SEED_PREFIX,
+                attacker_key.as_ref(),
+                &[*attacker_flag_bump],
+            ],
+            space,
+            &flag,
+        )?;
+    }
+
+    // Step 7: Fire alerts for the contacts passed in this transaction
+    let vault_bump = accounts.vault.bump;
+    for (alert_account_info, (index, bump)) in alert_accounts.iter().zip(&alert_targets) {
+        let uri = accounts.panic_config.alert_uri(&contacts[*index]);
+        if alert_account_info.owner != program_id {
+            accounts.top_up_reserve(rent.minimum_balance(8 + AlertAccount::INIT_SPACE))?;
+        }
+        activate_alert(
+            &accounts.fee_reserve.to_account_info(),
+            reserve_seeds,
+            &accounts.payer.to_account_info(),
+            alert_account_info,
+            &accounts.system_program.to_account_info(),
+            program_id,
+            &owner_key,
+            vault_seed(&vault_index, &vault_name_hash),
+            &contacts[*index],
+            uri,
+            *bump,
+            clock.unix_timestamp,
+            false,
+        )?;
+        accounts.panic_config.alerted_contacts |= 1 << index;
+    }
+    // Compressed alerts already have their leaves; contacts learn of the
+    // panic from the events
+    if compressed_alerts {
+        for (index, contact) in contacts.iter().enumerate() {
+            if accounts.panic_config.alerted_contacts & (1 << index) == 0 {
+                emit!(ContactAlerted {
+                    schema_version: EVENT_SCHEMA_VERSION,
+                    owner: owner_key,
+                    contact: *contact,
+                    is_rehearsal: false,
+                });
+                accounts.panic_config.alerted_contacts |= 1 << index;
+            }
+        }
     }
 
     // Mark panic as triggered
-    let config = &mut ctx.accounts.panic_config;
+    let config = &mut accounts.panic_config;
     config.is_triggered = true;
 
-    let vault_final_balance = ctx.accounts.vault.to_account_info().lamports();
+    let stats = &mut accounts.protocol_stats;
+    stats.total_panics += 1;
+    stats.total_attackers_flagged += attackers_flagged;
+    emit_stats(stats);
+
+    let vault_final_balance = accounts.vault.to_account_info().lamports();
 
-    emit!(PanicTriggered {
+    emit_indexed!(accounts, bumps, PanicTriggered {
+        schema_version: EVENT_SCHEMA_VERSION,
         owner: owner_key,
-        attacker: ctx.accounts.attacker.key(),
+        attacker: accounts.attacker.key(),
         vault_balance: vault_final_balance,
         decoy_sent: decoy_to_send,
         locked_until: clock.unix_timestamp + time_lock_duration,
-        contacts_alerted: contacts.len() as u8,
+        contacts_alerted,
+        attackers_flagged: attackers.len() as u8,
+        contacts,
+        config_version,
+        vault_token_balances,
+        reason,
+        severity,
+        vault_index,
+        vault_name,
     });
 
+    // Step 8: Let registered protocols react. Hooks read the config, so it
+    // is written back first.
+    if !hooks.is_empty() {
+        accounts.panic_config.exit(program_id)?;
+        accounts.vault.exit(program_id)?;
+        call_panic_hooks(accounts, &hooks, hook_accounts)?;
+    }
+
     // Use vault_bump to suppress warning
     msg!("Vault bump: {}", vault_bump);
 
+    Ok(true)
+}
+
+/// Check that `hook_accounts` holds, for each hook in order, its program
+/// followed by exactly as many accounts as it registered.
+fn match_hook_accounts(hooks: &[PanicHook], hook_accounts: &[AccountInfo]) -> Result<()> {
+    let expected: usize = hooks.iter().map(|hook| 1 + hook.account_count as usize).sum();
+    require!(
+        hook_accounts.len() == expected,
+        ScreamError::PanicHookAccountMismatch
+    );
+
+    let mut offset = 0;
+    for hook in hooks {
+        let program = &hook_accounts[offset];
+        require!(
+            program.key() == hook.program && program.executable,
+            ScreamError::PanicHookAccountMismatch
+        );
+        offset += 1 + hook.account_count as usize;
+    }
     Ok(())
 }
+
+/// Call each hook with the config, the owner and its extra accounts. None
+/// of them are passed as signers, so a hook can't act with the owner's
+/// authority.
+fn call_panic_hooks<'info>(
+    accounts: &TriggerPanic<'info>,
+    hooks: &[PanicHook],
+    hook_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let owner_key = accounts.owner.key();
+    let mut data = PanicHooks::ON_PANIC_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(owner_key.as_ref());
+    data.extend_from_slice(accounts.attacker.key().as_ref());
+
+    let mut offset = 0;
+    for hook in hooks {
+        let program = &hook_accounts[offset];
+        let extras = &hook_accounts[offset + 1..offset + 1 + hook.account_count as usize];
+        offset += 1 + hook.account_count as usize;
+
+        let mut metas = vec![
+            AccountMeta::new_readonly(accounts.panic_config.key(), false),
+            AccountMeta::new_readonly(owner_key, false),
+        ];
+        metas.extend(extras.iter().map(|info| AccountMeta {
+            pubkey: info.key(),
+            is_signer: false,
+            is_writable: info.is_writable,
+        }));
+
+        let mut infos = vec![
+            accounts.panic_config.to_account_info(),
+            accounts.owner.to_account_info(),
+        ];
+        infos.extend(extras.iter().cloned());
+        infos.push(program.clone());
+
+        invoke(
+            &Instruction {
+                program_id: hook.program,
+                accounts: metas,
+                data: data.clone(),
+            },
+            &infos,
+        )?;
+
+        emit!(PanicHookCalled {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            program: hook.program,
+        });
+    }
+    Ok(())
+}
+
+/// Check that `linked_accounts` is empty or holds each linked wallet in
+/// config order with its compromised flag PDA, returning the flag bumps.
+fn match_linked_wallet_accounts(
+    linked_wallets: &[Pubkey],
+    linked_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Vec<u8>> {
+    if linked_accounts.is_empty() {
+        return Ok(Vec::new());
+    }
+    require!(
+        linked_accounts.len() == linked_wallets.len() * 2,
+        ScreamError::LinkedWalletAccountMismatch
+    );
+
+    let mut bumps = Vec::with_capacity(linked_wallets.len());
+    for (wallet, pair) in linked_wallets.iter().zip(linked_accounts.chunks_exact(2)) {
+        let (flag, bump) = Pubkey::find_program_address(
+            &[CompromisedFlag::SEED_PREFIX, wallet.as_ref()],
+            program_id,
+        );
+        require!(
+            pair[0].key() == *wallet
+                && pair[1].key() == flag
+                && pair[0].is_writable
+                && pair[1].is_writable,
+            ScreamError::LinkedWalletAccountMismatch
+        );
+        bumps.push(bump);
+    }
+    Ok(bumps)
+}
+
+/// Check that `attacker_accounts` holds, for each extra attacker in order,
+/// the attacker itself followed by its flag PDA, and return the flag bumps.
+fn match_attacker_accounts(
+    primary: &Pubkey,
+    extra_attackers: &[Pubkey],
+    attacker_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Vec<u8>> {
+    require!(
+        extra_attackers.len() < MAX_ATTACKERS,
+        ScreamError::TooManyAttackers
+    );
+    require!(
+        attacker_accounts.len() == extra_attackers.len() * 2,
+        ScreamError::AttackerAccountMismatch
+    );
+
+    let mut bumps = Vec::with_capacity(extra_attackers.len());
+    for (i, (attacker, pair)) in extra_attackers
+        .iter()
+        .zip(attacker_accounts.chunks_exact(2))
+        .enumerate()
+    {
+        require!(
+            attacker != primary && !extra_attackers[..i].contains(attacker),
+            ScreamError::AttackerAccountMismatch
+        );
+        let (flag, bump) = Pubkey::find_program_address(
+            &[AttackerFlag::SEED_PREFIX, attacker.as_ref()],
+            program_id,
+        );
+        require!(
+            pair[0].key() == *attacker && pair[1].key() == flag,
+            ScreamError::AttackerAccountMismatch
+        );
+        require!(
+            pair[0].is_writable && pair[1].is_writable,
+            ScreamError::AttackerAccountMismatch
+        );
+        bumps.push(bump);
+    }
+    Ok(bumps)
+}
+
+/// Check the token decoy accounts against the config and return how much
+/// of the decoy mint to send (0 when no token decoy is configured). A
+/// decoy whose accounts were left out is skipped rather than holding up
+/// the panic.
+fn token_decoy_amount(accounts: &TriggerPanic) -> Result<u64> {
+    let config = &accounts.panic_config;
+    if config.decoy_token_mint == Pubkey::default() {
+        return Ok(0);
+    }
+
+    let (Some(mint), Some(vault_token), Some(attacker_token), Some(_), Some(_)) = (
+        &accounts.decoy_token_mint,
+        &accounts.vault_decoy_token,
+        &accounts.attacker_decoy_token,
+        &accounts.token_program,
+        &accounts.associated_token_program,
+    ) else {
+        emit!(TokenDecoySkipped {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: config.owner,
+            mint: config.decoy_token_mint,
+        });
+        return Ok(0);
+    };
+    require!(
+        mint.key() == config.decoy_token_mint
+            && vault_token.mint == config.decoy_token_mint
+            && vault_token.owner == accounts.vault.key()
+            && attacker_token.key()
+                == get_associated_token_address(&accounts.attacker.key(), &mint.key()),
+        ScreamError::DecoyTokenAccountMismatch
+    );
+
+    Ok(config.decoy_token_amount.min(vault_token.amount))
+}
+
+/// Send `amount` of the decoy mint from the vault's token account to the
+/// attacker's associated token account, creating it if needed. Accounts
+/// must have passed `token_decoy_amount`.
+fn send_token_decoy(accounts: &TriggerPanic, amount: u64) -> Result<()> {
+    let (
+        Some(mint),
+        Some(vault_token),
+        Some(attacker_token),
+        Some(token_program),
+        Some(ata_program),
+    ) = (
+        &accounts.decoy_token_mint,
+        &accounts.vault_decoy_token,
+        &accounts.attacker_decoy_token,
+        &accounts.token_program,
+        &accounts.associated_token_program,
+    ) else {
+        return err!(ScreamError::DecoyTokenAccountMismatch);
+    };
+
+    associated_token::create_idempotent(CpiContext::new(
+        ata_program.to_account_info(),
+        associated_token::Create {
+            payer: accounts.payer.to_account_info(),
+            associated_token: attacker_token.to_account_info(),
+            authority: accounts.attacker.to_account_info(),
+            mint: mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+
+    let owner_key = accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        accounts.vault.vault_seed(),
+        &[accounts.vault.bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: vault_token.to_account_info(),
+                to: attacker_token.to_account_info(),
+                authority: accounts.vault.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(DecoyTokensSent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        attacker: accounts.attacker.key(),
+        mint: mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Check that `delegated_accounts` is empty or holds each registered
+/// delegated token account in config order with a vault token account of
+/// the same mint, returning the amount to pull from each. Accounts that were
+/// closed or no longer delegate to the vault are skipped, not rejected, so
+/// a holder revoking can't block the panic.
+fn delegated_token_amounts<'info>(
+    accounts: &TriggerPanic<'info>,
+    delegated_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<(Pubkey, u64)>> {
+    if delegated_accounts.is_empty() {
+        return Ok(Vec::new());
+    }
+    let registered = &accounts.panic_config.delegated_token_accounts;
+    require!(
+        delegated_accounts.len() == registered.len() * 2,
+        ScreamError::DelegatedTokenAccountMismatch
+    );
+
+    let vault_key = accounts.vault.key();
+    let mut amounts = Vec::with_capacity(registered.len());
+    for (token_account, pair) in registered.iter().zip(delegated_accounts.chunks_exact(2)) {
+        require!(
+            pair[0].key() == *token_account && pair[0].is_writable && pair[1].is_writable,
+            ScreamError::DelegatedTokenAccountMismatch
+        );
+        let Ok(source) = Account::<TokenAccount>::try_from(&pair[0]) else {
+            amounts.push((Pubkey::default(), 0));
+            continue;
+        };
+        let amount = if Option::<Pubkey>::from(source.delegate) == Some(vault_key) {
+            source.delegated_amount.min(source.amount)
+        } else {
+            0
+        };
+        if amount > 0 {
+            let destination = Account::<TokenAccount>::try_from(&pair[1])?;
+            require!(
+                destination.owner == vault_key && destination.mint == source.mint,
+                ScreamError::DelegatedTokenAccountMismatch
+            );
+            require!(
+                accounts.token_program.is_some(),
+                ScreamError::DelegatedTokenAccountMismatch
+            );
+        }
+        amounts.push((source.mint, amount));
+    }
+    Ok(amounts)
+}
+
+/// What the vault's token accounts touched by the panic will hold per mint
+/// once the token decoy has left and delegated balances have arrived.
+/// Amounts must come from `token_decoy_amount` and `delegated_token_amounts`.
+fn vault_token_balances<'info>(
+    accounts: &TriggerPanic<'info>,
+    delegated_accounts: &'info [AccountInfo<'info>],
+    decoy_token_amount: u64,
+    delegated_pulls: &[(Pubkey, u64)],
+) -> Result<Vec<TokenBalance>> {
+    // (token account, mint, amount), so an account pulled into twice or
+    // also holding the decoy is only read once
+    let mut held: Vec<(Pubkey, Pubkey, u64)> = Vec::new();
+    if let Some(vault_token) = &accounts.vault_decoy_token {
+        if accounts.panic_config.decoy_token_mint != Pubkey::default() {
+            let amount = vault_token.amount.saturating_sub(decoy_token_amount);
+            held.push((vault_token.key(), vault_token.mint, amount));
+        }
+    }
+    for (pair, (_, amount)) in delegated_accounts.chunks_exact(2).zip(delegated_pulls) {
+        if *amount == 0 {
+            continue;
+        }
+        match held.iter_mut().find(|(key, ..)| *key == pair[1].key()) {
+            Some(entry) => entry.2 = entry.2.saturating_add(*amount),
+            None => {
+                let destination = Account::<TokenAccount>::try_from(&pair[1])?;
+                let amount = destination.amount.saturating_add(*amount);
+                held.push((pair[1].key(), destination.mint, amount));
+            }
+        }
+    }
+
+    let mut balances: Vec<TokenBalance> = Vec::new();
+    for (_, mint, amount) in held {
+        match balances.iter_mut().find(|balance| balance.mint == mint) {
+            Some(balance) => balance.amount = balance.amount.saturating_add(amount),
+            None => balances.push(TokenBalance { mint, amount }),
+        }
+    }
+    Ok(balances)
+}
+
+/// Move each delegated balance into the vault, the vault signing as
+/// delegate. Amounts must come from `delegated_token_amounts`.
+fn pull_delegated_tokens<'info>(
+    accounts: &TriggerPanic<'info>,
+    delegated_accounts: &'info [AccountInfo<'info>],
+    amounts: &[(Pubkey, u64)],
+) -> Result<()> {
+    let owner_key = accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        accounts.vault.vault_seed(),
+        &[accounts.vault.bump],
+    ];
+    for (pair, (mint, amount)) in delegated_accounts.chunks_exact(2).zip(amounts) {
+        if *amount == 0 {
+            continue;
+        }
+        let Some(token_program) = &accounts.token_program else {
+            return err!(ScreamError::DelegatedTokenAccountMismatch);
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: pair[0].clone(),
+                    to: pair[1].clone(),
+                    authority: accounts.vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            *amount,
+        )?;
+
+        emit!(DelegatedTokensPulled {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: owner_key,
+            token_account: pair[0].key(),
+            mint: *mint,
+            amount: *amount,
+        });
+    }
+    Ok(())
+}
+
+/// Count a panic against an address someone else already flagged toward
+/// the flag's score and the reporter's bounty.
+fn corroborate_flag(flag_info: &AccountInfo, owner: &Pubkey) -> Result<()> {
+    let corroborated = update_attacker_flag(flag_info, |flag| {
+        if flag.reported_by == *owner {
+            return Ok(false);
+        }
+        flag.corroborations = flag.corroborations.saturating_add(1);
+        Ok(true)
+    })?;
+
+    if let Some(flag) = corroborated {
+        emit!(FlagCorroborated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            attacker: flag.attacker,
+            reported_by: flag.reported_by,
+            corroborated_by: *owner,
+            corroborations: flag.corroborations,
+        });
+    }
+    Ok(())
+}
+
+/// The decoy installment sent with the panic itself, which also carries the
+/// rounding remainder.
+pub(crate) fn first_installment(total: u64, installments: u8) -> u64 {
+    let installments = installments as u64;
+    total / installments + total % installments
+}
+
+/// Spread `amount` by up to +/- `jitter_bps`, drawn from the latest slot hash
+/// mixed with the owner so simultaneous panics don't land on the same value.
+fn jitter(amount: u64, jitter_bps: u16, slot_hash: &[u8; 32], owner: &Pubkey) -> u64 {
+    if jitter_bps == 0 {
+        return amount;
+    }
+
+    let entropy = hashv(&[slot_hash, owner.as_ref()]).to_bytes();
+    let draw = u64::from_le_bytes(entropy[..8].try_into().unwrap());
+    let span = 2 * jitter_bps as u64 + 1;
+    let factor_bps = 10_000 - jitter_bps as u64 + draw % span;
+    (amount as u128 * factor_bps as u128 / 10_000) as u64
+}
+
+/// Count a wrong trigger proof and start a lockout once the configured
+/// number of consecutive failures is reached.
+fn record_failed_attempt(accounts: &mut TriggerPanic, now: i64) {
+    let config = &mut accounts.panic_config;
+    config.failed_attempts = config.failed_attempts.saturating_add(1);
+
+    if config.max_failed_attempts > 0 && config.failed_attempts >= config.max_failed_attempts {
+        config.failed_attempts = 0;
+        config.trigger_locked_until = now + config.lockout_duration;
+
+        if config.soft_lock_on_lockout {
+            let vault = &mut accounts.vault;
+            vault.locked_until = vault.locked_until.max(config.trigger_locked_until);
+        }
+    }
+
+    emit!(TriggerAttemptFailed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        failed_attempts: config.failed_attempts,
+        trigger_locked_until: config.trigger_locked_until,
+    });
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{AttackersUnlinked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct UnlinkAttackers<'info> {
+    /// Whoever asserted the link, or the dispute arbiter
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AttackerLink::SEED_PREFIX,
+            attacker_link.address_a.as_ref(),
+            attacker_link.address_b.as_ref(),
+        ],
+        bump = attacker_link.bump,
+        close = reporter,
+    )]
+    pub attacker_link: Account<'info, AttackerLink>,
+
+    /// CHECK: Whoever asserted the link and paid its rent; gets the rent back.
+    #[account(mut, address = attacker_link.reported_by)]
+    pub reporter: UncheckedAccount<'info>,
+}
+
+/// Remove a link: its reporter can withdraw it, and the arbiter can remove
+/// any that doesn't hold up.
+pub fn handler(ctx: Context<UnlinkAttackers>) -> Result<()> {
+    let link = &ctx.accounts.attacker_link;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == link.reported_by || authority == ctx.accounts.dispute_config.arbiter,
+        ScreamError::Unauthorized
+    );
+
+    emit!(AttackersUnlinked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        address_a: link.address_a,
+        address_b: link.address_b,
+        removed_by: authority,
+    });
+
+    Ok(())
+}
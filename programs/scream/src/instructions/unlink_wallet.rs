@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{WalletUnlinked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct UnlinkWallet<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+/// Take a wallet back out from under this config. Frozen once panic fires.
+pub fn handler(ctx: Context<UnlinkWallet>, wallet: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    let index = config
+        .linked_wallets
+        .iter()
+        .position(|w| *w == wallet)
+        .ok_or(ScreamError::WalletNotLinked)?;
+    config.linked_wallets.remove(index);
+
+    emit!(WalletUnlinked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        wallet,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{PoolTokensUnstaked, EVENT_SCHEMA_VERSION};
+
+/// The SPL stake pool program, which also backs JitoSOL and most other LSTs.
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// `StakePoolInstruction::WithdrawSol` discriminant.
+const WITHDRAW_SOL: u8 = 16;
+
+#[derive(Accounts)]
+pub struct UnstakePoolTokens<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+        constraint = panic_config.is_triggered @ ScreamError::PanicNotTriggered,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// Receives the SOL released by the pool
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The owner's LST account, burned by the pool
+    #[account(
+        mut,
+        constraint = owner_pool_tokens.owner == owner.key() @ ScreamError::InvalidTokenAccount,
+        constraint = owner_pool_tokens.mint == pool_mint.key() @ ScreamError::InvalidTokenAccount,
+    )]
+    pub owner_pool_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut, owner = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    pub pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub pool_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, passed through to the stake pool program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: StakeHistory sysvar, passed through to the stake pool program
+    #[account(address = solana_sdk_ids::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: The native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: The SPL stake pool program
+    #[account(address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+}
+
+/// Redeem the owner's stake pool tokens for SOL straight into the vault
+/// via `WithdrawSol`, so liquid staking tokens aren't left drainable.
+/// `pool_tokens` of 0 redeems the whole balance. Pools that gate SOL
+/// withdrawals behind their own authority, and non-SPL pools such as
+/// Marinade, aren't supported.
+pub fn handler(ctx: Context<UnstakePoolTokens>, pool_tokens: u64) -> Result<()> {
+    let balance = ctx.accounts.owner_pool_tokens.amount;
+    let pool_tokens = if pool_tokens == 0 { balance } else { pool_tokens };
+    require!(
+        pool_tokens > 0 && pool_tokens <= balance,
+        ScreamError::InvalidWithdrawAmount
+    );
+
+    let accounts = &ctx.accounts;
+    let vault_before = accounts.vault.to_account_info().lamports();
+
+    let mut data = vec![WITHDRAW_SOL];
+    data.extend_from_slice(&pool_tokens.to_le_bytes());
+    let ix = Instruction {
+        program_id: SPL_STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.pool_withdraw_authority.key(), false),
+            AccountMeta::new_readonly(accounts.owner.key(), true),
+            AccountMeta::new(accounts.owner_pool_tokens.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(accounts.vault.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.stake_history.key(), false),
+            AccountMeta::new_readonly(accounts.stake_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+    invoke(
+        &ix,
+        &[
+            accounts.stake_pool.to_account_info(),
+            accounts.pool_withdraw_authority.to_account_info(),
+            accounts.owner.to_account_info(),
+            accounts.owner_pool_tokens.to_account_info(),
+            accounts.reserve_stake.to_account_info(),
+            accounts.vault.to_account_info(),
+            accounts.manager_fee_account.to_account_info(),
+            accounts.pool_mint.to_account_info(),
+            accounts.clock.to_account_info(),
+            accounts.stake_history.to_account_info(),
+            accounts.stake_program.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ],
+    )?;
+
+    emit!(PoolTokensUnstaked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: accounts.owner.key(),
+        stake_pool: accounts.stake_pool.key(),
+        pool_tokens,
+        lamports: accounts.vault.to_account_info().lamports() - vault_before,
+    });
+
+    Ok(())
+}
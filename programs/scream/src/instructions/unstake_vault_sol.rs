@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VaultSolUnstaked, EVENT_SCHEMA_VERSION};
+use crate::instructions::unstake_pool_tokens::SPL_STAKE_POOL_PROGRAM_ID;
+
+/// `StakePoolInstruction::WithdrawSol` discriminant.
+const WITHDRAW_SOL: u8 = 16;
+
+#[derive(Accounts)]
+pub struct UnstakeVaultSol<'info> {
+    /// The owner before panic fires; the recovery signer once recovery has
+    /// been initiated
+    pub authority: Signer<'info>,
+
+    /// CHECK: The owner whose vault is unstaked. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    /// Receives the SOL released by the pool
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+        has_one = stake_pool @ ScreamError::StakePoolMismatch,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The vault's LST account, burned by the pool
+    #[account(
+        mut,
+        associated_token::mint = pool_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_pool_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut, owner = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    pub pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program
+    #[account(mut)]
+    pub pool_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, passed through to the stake pool program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: StakeHistory sysvar, passed through to the stake pool program
+    #[account(address = solana_sdk_ids::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: The native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: The SPL stake pool program
+    #[account(address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+}
+
+/// Redeem `pool_tokens` of the vault's staked SOL back into the vault via
+/// `WithdrawSol`, 0 for all of it. The owner can unstake any time before
+/// panic; afterwards only the recovery signer can, once recovery has been
+/// initiated. The yield stays in the vault and is claimed with the rest.
+pub fn handler(ctx: Context<UnstakeVaultSol>, pool_tokens: u64) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    let authority = ctx.accounts.authority.key();
+    if config.is_triggered {
+        require!(
+            ctx.accounts.vault.recovery_initiated,
+            ScreamError::RecoveryNotInitiated
+        );
+        require_keys_eq!(
+            authority,
+            config.recovery_signer(),
+            ScreamError::InvalidRecoveryAuthority
+        );
+    } else {
+        require_keys_eq!(authority, config.owner, ScreamError::NotAnOwner);
+    }
+
+    let staked = ctx.accounts.vault.staked_pool_tokens;
+    let pool_tokens = if pool_tokens == 0 { staked } else { pool_tokens };
+    require!(
+        pool_tokens > 0 && pool_tokens <= staked,
+        ScreamError::InvalidWithdrawAmount
+    );
+
+    let accounts = &ctx.accounts;
+    let owner_key = accounts.owner.key();
+    let vault_info = accounts.vault.to_account_info();
+    let vault_before = vault_info.lamports();
+
+    let mut data = vec![WITHDRAW_SOL];
+    data.extend_from_slice(&pool_tokens.to_le_bytes());
+    let ix = Instruction {
+        program_id: SPL_STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.pool_withdraw_authority.key(), false),
+            AccountMeta::new_readonly(accounts.vault.key(), true),
+            AccountMeta::new(accounts.vault_pool_tokens.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(accounts.vault.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.stake_history.key(), false),
+            AccountMeta::new_readonly(accounts.stake_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.stake_pool.to_account_info(),
+            accounts.pool_withdraw_authority.to_account_info(),
+            vault_info.clone(),
+            accounts.vault_pool_tokens.to_account_info(),
+            accounts.reserve_stake.to_account_info(),
+            accounts.manager_fee_account.to_account_info(),
+            accounts.pool_mint.to_account_info(),
+            accounts.clock.to_account_info(),
+            accounts.stake_history.to_account_info(),
+            accounts.stake_program.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ],
+        &[&[
+            Vault::SEED_PREFIX,
+            owner_key.as_ref(),
+            accounts.vault.vault_seed(),
+            &[accounts.vault.bump],
+        ]],
+    )?;
+    let lamports = vault_info.lamports() - vault_before;
+
+    let vault = &mut ctx.accounts.vault;
+    let stake_pool = vault.stake_pool;
+    let cost = (vault.staked_lamports as u128 * pool_tokens as u128 / staked as u128) as u64;
+    vault.staked_lamports -= cost;
+    vault.staked_pool_tokens -= pool_tokens;
+    if vault.staked_pool_tokens == 0 {
+        vault.stake_pool = Pubkey::default();
+    }
+
+    emit!(VaultSolUnstaked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        stake_pool,
+        pool_tokens,
+        lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{TriggerUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct UpdateTrigger<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateTrigger>,
+    trigger_hash: [u8; 32],
+    trigger_mode: TriggerMode,
+) -> Result<()> {
+    let config = &mut ctx.accounts.panic_config;
+    require!(!config.is_triggered, ScreamError::PanicAlreadyTriggered);
+
+    match trigger_mode {
+        TriggerMode::Totp { period, .. } => {
+            require!(period > 0, ScreamError::InvalidTotpPeriod);
+        }
+        TriggerMode::Shamir { threshold } => {
+            require!(threshold >= 2, ScreamError::InvalidShamirThreshold);
+        }
+        _ => {}
+    }
+
+    config.trigger_hash = trigger_hash;
+    config.trigger_mode = trigger_mode;
+    // A new passkey starts its own counter
+    config.passkey_sign_count = 0;
+
+    emit!(TriggerUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        trigger_mode,
+    });
+
+    Ok(())
+}
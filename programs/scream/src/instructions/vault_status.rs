@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::claim_from_vault::claimable_lamports;
+
+#[derive(Accounts)]
+pub struct GetVaultStatus<'info> {
+    /// CHECK: The vault owner. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Read-only summary, returned via return data; meant to be simulated.
+pub fn handler(ctx: Context<GetVaultStatus>) -> Result<VaultStatus> {
+    let vault = &ctx.accounts.vault;
+    Ok(VaultStatus {
+        locked_until: vault.locked_until,
+        recovery_initiated: vault.recovery_initiated,
+        approvals: vault.approvals,
+        threshold: ctx.accounts.panic_config.recovery_threshold,
+        claimable_lamports: claimable_lamports(&vault.to_account_info())?,
+        staked_lamports: vault.staked_lamports,
+        lent_lamports: vault.lent_lamports,
+        hot_lamports: vault.hot_lamports,
+    })
+}
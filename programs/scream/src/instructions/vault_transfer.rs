@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{VaultTransferred, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_from_vault::claimable_lamports;
+use crate::utils::{move_lamports, record_ledger_outflow};
+use crate::instructions::execute_withdraw::require_allowed_destination;
+
+#[derive(Accounts)]
+pub struct VaultTransfer<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        has_one = owner,
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        has_one = owner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Any account the owner is paying.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Required once the vault's policy demands an allowlist
+    #[account(
+        seeds = [WithdrawAllowlist::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = withdraw_allowlist.bump,
+    )]
+    pub withdraw_allowlist: Option<Account<'info, WithdrawAllowlist>>,
+
+    /// Required, and appended to, once the owner has opted in to a ledger
+    #[account(
+        mut,
+        seeds = [DepositLedger::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump,
+    )]
+    pub deposit_ledger: Option<AccountLoader<'info, DepositLedger>>,
+}
+
+/// Pay straight out of the vault, skipping the withdraw delay but capped by
+/// the policy's per-period spending limit.
+pub fn handler(ctx: Context<VaultTransfer>, amount: u64) -> Result<()> {
+    require!(!ctx.accounts.panic_config.is_triggered, ScreamError::PanicAlreadyTriggered);
+    require!(amount > 0, ScreamError::InvalidWithdrawAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    require!(now >= vault.locked_until, ScreamError::TimeLockActive);
+
+    vault.settle_policy(now);
+    require_allowed_destination(
+        vault,
+        ctx.accounts.withdraw_allowlist.as_ref(),
+        &ctx.accounts.recipient.key(),
+        now,
+    )?;
+
+    vault.roll_spend_period(now);
+    let spent_in_period = vault
+        .spent_in_period
+        .checked_add(amount)
+        .filter(|spent| *spent <= vault.withdraw_policy.spend_limit)
+        .ok_or(ScreamError::SpendLimitExceeded)?;
+    vault.spent_in_period = spent_in_period;
+    require!(
+        vault.record_withdrawal(amount, now),
+        ScreamError::DailyWithdrawLimitExceeded
+    );
+
+    let vault_info = vault.to_account_info();
+    require!(
+        amount <= claimable_lamports(&vault_info)?,
+        ScreamError::InsufficientVaultBalance
+    );
+    move_lamports(&vault_info, &ctx.accounts.recipient.to_account_info(), amount)?;
+
+    let recipient = ctx.accounts.recipient.key();
+    record_ledger_outflow(
+        &ctx.accounts.vault,
+        ctx.accounts.deposit_ledger.as_ref(),
+        recipient,
+        now,
+        amount,
+    )?;
+
+    emit!(VaultTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: ctx.accounts.owner.key(),
+        recipient,
+        amount,
+        spent_in_period,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::instructions::append_compressed_flag::leaf_hash;
+use crate::instructions::init_compressed_registry::{ACCOUNT_COMPRESSION_PROGRAM_ID, VERIFY_LEAF};
+
+#[derive(Accounts)]
+pub struct VerifyCompressedFlag<'info> {
+    #[account(
+        seeds = [CompressedRegistry::SEED_PREFIX],
+        bump = compressed_registry.bump,
+    )]
+    pub compressed_registry: Account<'info, CompressedRegistry>,
+
+    /// CHECK: Checked against the registry; validated by the compression
+    /// program.
+    #[account(address = compressed_registry.merkle_tree @ ScreamError::InvalidMerkleTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: The spl-account-compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// Prove `flag` is leaf `index` of the compressed registry under `root`,
+/// one of the tree's recent roots. Remaining accounts: the proof, one node
+/// per account key, leaf to root, as served by an indexer. Fails unless the
+/// proof checks out, so other programs can CPI in to screen an address.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyCompressedFlag<'info>>,
+    root: [u8; 32],
+    flag: CompressedFlagLeaf,
+    index: u32,
+) -> Result<()> {
+    let mut data = VERIFY_LEAF.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf_hash(&flag));
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut metas = vec![AccountMeta::new_readonly(ctx.accounts.merkle_tree.key(), false)];
+    metas.extend(
+        ctx.remaining_accounts
+            .iter()
+            .map(|node| AccountMeta::new_readonly(node.key(), false)),
+    );
+    let mut infos = vec![ctx.accounts.merkle_tree.to_account_info()];
+    infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke(
+        &Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: metas,
+            data,
+        },
+        &infos,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{ClaimPaused, ClaimVetoVoted, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+pub struct VetoClaim<'info> {
+    pub contact: Signer<'info>,
+
+    /// CHECK: The owner whose recovery is vetoed. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PanicConfig::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = panic_config.bump,
+        constraint = panic_config.owner == owner.key(),
+    )]
+    pub panic_config: Account<'info, PanicConfig>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), panic_config.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Vote to pause claims when a contact suspects the attacker is the one
+/// recovering. Once `veto_quorum` contacts agree, claims are paused for
+/// `veto_duration`; each contact gets one vote per recovery, so the pause
+/// can't be stretched out indefinitely.
+pub fn handler(ctx: Context<VetoClaim>) -> Result<()> {
+    let config = &ctx.accounts.panic_config;
+    require!(config.is_triggered, ScreamError::PanicNotTriggered);
+
+    let vault = &mut ctx.accounts.vault;
+    require!(vault.recovery_initiated, ScreamError::RecoveryNotInitiated);
+
+    let contact = ctx.accounts.contact.key();
+    let index = config
+        .contacts
+        .iter()
+        .position(|c| *c == contact)
+        .ok_or(ScreamError::InvalidContact)?;
+    require!(vault.veto_votes & (1 << index) == 0, ScreamError::AlreadyVetoed);
+
+    vault.veto_votes |= 1 << index;
+    let votes = vault.veto_votes.count_ones() as u8;
+
+    emit!(ClaimVetoVoted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: config.owner,
+        contact,
+        votes,
+        quorum: config.veto_quorum,
+    });
+
+    if votes == config.veto_quorum {
+        let now = Clock::get()?.unix_timestamp;
+        vault.claim_paused_until = now.max(vault.locked_until) + config.veto_duration;
+
+        emit!(ClaimPaused {
+            schema_version: EVENT_SCHEMA_VERSION,
+            owner: config.owner,
+            paused_until: vault.claim_paused_until,
+        });
+    }
+
+    Ok(())
+}
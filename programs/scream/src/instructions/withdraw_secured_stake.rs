@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_stake_interface::instruction as stake_instruction;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{SecuredStakeWithdrawn, EVENT_SCHEMA_VERSION};
+use crate::instructions::secure_stake_accounts::stake_state;
+
+#[derive(Accounts)]
+pub struct WithdrawSecuredStake<'info> {
+    /// CHECK: The owner whose stake was secured. Validated via PDA seeds.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, owner.key().as_ref(), vault.vault_seed()],
+        bump = vault.bump,
+        constraint = vault.owner == owner.key(),
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Decoded in the handler; must be withdrawable by the vault
+    #[account(mut)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, passed through to the stake program
+    #[account(address = solana_sdk_ids::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: StakeHistory sysvar, passed through to the stake program
+    #[account(address = solana_sdk_ids::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: The native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank moving a secured stake account's SOL into the
+/// vault once it has cooled down, where normal recovery picks it up.
+pub fn handler(ctx: Context<WithdrawSecuredStake>) -> Result<()> {
+    let stake_info = ctx.accounts.stake_account.to_account_info();
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let (_, meta) = stake_state(&stake_info)?;
+    require_keys_eq!(
+        meta.authorized.withdrawer,
+        vault_info.key(),
+        ScreamError::InvalidStakeAccount
+    );
+
+    let owner_key = ctx.accounts.owner.key();
+    let vault_seeds: &[&[u8]] = &[
+        Vault::SEED_PREFIX,
+        owner_key.as_ref(),
+        ctx.accounts.vault.vault_seed(),
+        &[ctx.accounts.vault.bump],
+    ];
+
+    let lamports = stake_info.lamports();
+    invoke_signed(
+        &stake_instruction::withdraw(
+            stake_info.key,
+            vault_info.key,
+            vault_info.key,
+            lamports,
+            None,
+        ),
+        &[
+            stake_info.clone(),
+            vault_info.clone(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.stake_history.to_account_info(),
+            vault_info.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    emit!(SecuredStakeWithdrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner: owner_key,
+        stake_account: stake_info.key(),
+        lamports,
+    });
+
+    Ok(())
+}
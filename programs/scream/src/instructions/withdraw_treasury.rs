@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{TreasuryWithdrawn, EVENT_SCHEMA_VERSION};
+use crate::utils::system_transfer;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED_PREFIX],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: Any account chosen by the admin to receive the fees.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay collected fees out, always leaving the treasury its rent floor.
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let available = ctx
+        .accounts
+        .treasury
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(amount <= available, ScreamError::InsufficientTreasuryBalance);
+
+    system_transfer(
+        &ctx.accounts.treasury.to_account_info(),
+        Some(&[Treasury::SEED_PREFIX, &[ctx.bumps.treasury]]),
+        &ctx.accounts.destination.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
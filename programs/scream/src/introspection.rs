@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+
+/// `SystemInstruction::AdvanceNonceAccount` discriminant.
+const ADVANCE_NONCE_ACCOUNT: u32 = 4;
+
+/// If the current transaction is a durable-nonce transaction, return the
+/// `(nonce account, nonce authority)` pair its leading AdvanceNonceAccount
+/// instruction uses.
+pub fn durable_nonce(instructions_sysvar: &AccountInfo) -> Result<Option<(Pubkey, Pubkey)>> {
+    let ix = load_instruction_at_checked(0, instructions_sysvar)?;
+    let is_advance = ix.program_id == solana_sdk_ids::system_program::ID
+        && ix.data.get(..4) == Some(&ADVANCE_NONCE_ACCOUNT.to_le_bytes()[..])
+        && ix.accounts.len() >= 3;
+
+    Ok(is_advance.then(|| (ix.accounts[0].pubkey, ix.accounts[2].pubkey)))
+}
+
+/// This program's instructions a panic may travel with: the panic
+/// entrypoints themselves and contact alerts. Anything else of ours, such
+/// as a recovery or owner change, could hijack the vault the panic locks.
+const PANIC_INSTRUCTIONS: [&[u8]; 4] = [
+    crate::instruction::TriggerPanic::DISCRIMINATOR,
+    crate::instruction::Transfer::DISCRIMINATOR,
+    crate::instruction::PanicAndSweepTokens::DISCRIMINATOR,
+    crate::instruction::AlertContact::DISCRIMINATOR,
+];
+
+/// Whether every top-level instruction in the transaction is one a panic
+/// legitimately travels with: one of `PANIC_INSTRUCTIONS`, compute budget,
+/// the signature precompiles trigger proofs use, or a leading
+/// AdvanceNonceAccount. When called through CPI, the caller's instruction
+/// is allowed too. Anything else, such as a transfer appended to the panic
+/// transaction, means it was tampered with.
+pub fn only_expected_instructions(instructions_sysvar: &AccountInfo) -> Result<bool> {
+    let caller = (get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT)
+        .then(|| load_current_index_checked(instructions_sysvar))
+        .transpose()?
+        .map(usize::from);
+    let count = {
+        let data = instructions_sysvar.try_borrow_data()?;
+        let len = data.get(..2).ok_or(ProgramError::InvalidAccountData)?;
+        u16::from_le_bytes(len.try_into().unwrap()) as usize
+    };
+
+    for index in 0..count {
+        let ix = load_instruction_at_checked(index, instructions_sysvar)?;
+        let expected = (ix.program_id == crate::ID
+            && PANIC_INSTRUCTIONS.iter().any(|tag| ix.data.starts_with(tag)))
+            || Some(index) == caller
+            || ix.program_id == solana_sdk_ids::compute_budget::ID
+            || ix.program_id == solana_sdk_ids::ed25519_program::ID
+            || ix.program_id == solana_sdk_ids::secp256r1_program::ID
+            || (index == 0
+                && ix.program_id == solana_sdk_ids::system_program::ID
+                && ix.data.get(..4) == Some(&ADVANCE_NONCE_ACCOUNT.to_le_bytes()[..]));
+        if !expected {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Hash of the most recent slot in the SlotHashes sysvar, read straight
+/// from the account data (`u64 len`, then `(u64 slot, [u8; 32] hash)`
+/// entries, newest first) since the full sysvar is too large to deserialize.
+pub fn latest_slot_hash(slot_hashes_sysvar: &AccountInfo) -> Result<[u8; 32]> {
+    let data = slot_hashes_sysvar.try_borrow_data()?;
+    let hash = data.get(16..48).ok_or(ProgramError::InvalidAccountData)?;
+    Ok(hash.try_into().unwrap())
+}
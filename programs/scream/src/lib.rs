@@ -1,52 +1,286 @@
+// Every instruction module exposes a `handler`; the glob re-exports below only
+// exist for the Anchor-generated account modules, so the name clash is benign.
+#![allow(ambiguous_glob_reexports)]
+// The generated CPI wrappers take an instruction's arguments plus a context
+// and can't carry the instruction's own allow.
+#![cfg_attr(feature = "cpi", allow(clippy::too_many_arguments))]
+
 use anchor_lang::prelude::*;
+use crate::state::{
+    AttackerStatus, CompromiseReason, CompressedFlagLeaf, ConfigView, DecoyMode, FlagStatus,
+    LinkKind, PanicPreflight, TriggerMode, VaultStatus, WithdrawPolicy,
+};
 
-pub mod state;
+pub use scream_interface::state;
 pub mod instructions;
 pub mod errors;
+#[cfg(feature = "cpi")]
+pub mod composable;
 pub mod events;
+pub mod introspection;
+pub mod sigverify;
+pub mod trigger;
+pub mod utils;
 
 // Re-export instruction context structs and their hidden __client_accounts modules
 // at the crate root, which is required by the #[program] macro.
 pub use instructions::initialize_config::*;
+pub use instructions::update_trigger::*;
+pub use instructions::set_attempt_limit::*;
+pub use instructions::set_presign_nonce::*;
+pub use instructions::set_recovery_destination::*;
 pub use instructions::deposit::*;
+pub use instructions::deposit_for::*;
 pub use instructions::trigger_panic::*;
+pub use instructions::alert_contact::*;
+pub use instructions::panic_and_sweep_tokens::*;
 pub use instructions::initiate_recovery::*;
 pub use instructions::approve_recovery::*;
+pub use instructions::approve_recovery_with_sig::*;
 pub use instructions::claim_from_vault::*;
+pub use instructions::finalize_claim::*;
+pub use instructions::initialize_dispute_config::*;
+pub use instructions::dispute_flag::*;
+pub use instructions::resolve_dispute::*;
+pub use instructions::report_attacker::*;
+pub use instructions::expire_attacker_flag::*;
+pub use instructions::check_attacker::*;
+pub use instructions::is_flagged::*;
+pub use instructions::vault_status::*;
+pub use instructions::init_guardian_registry::*;
+pub use instructions::register_guardian::*;
+pub use instructions::initialize_protocol_stats::*;
+pub use instructions::initialize_protocol_config::*;
+pub use instructions::set_protocol_config::*;
+pub use instructions::withdraw_treasury::*;
+pub use instructions::set_paused::*;
+pub use instructions::migrate_account::*;
+pub use instructions::init_deposit_ledger::*;
+pub use instructions::set_withdraw_policy::*;
+pub use instructions::request_withdraw::*;
+pub use instructions::execute_withdraw::*;
+pub use instructions::cancel_withdraw::*;
+pub use instructions::vault_transfer::*;
+pub use instructions::init_withdraw_allowlist::*;
+pub use instructions::add_withdraw_destination::*;
+pub use instructions::remove_withdraw_destination::*;
+pub use instructions::set_decoy_mode::*;
+pub use instructions::set_decoy_drip::*;
+pub use instructions::release_decoy_drip::*;
+pub use instructions::set_decoy_token::*;
+pub use instructions::set_balance_buffer::*;
+pub use instructions::revoke_delegates::*;
+pub use instructions::secure_stake_accounts::*;
+pub use instructions::withdraw_secured_stake::*;
+pub use instructions::unstake_pool_tokens::*;
+pub use instructions::reset_close_authorities::*;
+pub use instructions::init_panic_hooks::*;
+pub use instructions::register_panic_hook::*;
+pub use instructions::remove_panic_hook::*;
+pub use instructions::assert_not_compromised::*;
+pub use instructions::set_alert_message::*;
+pub use instructions::set_alert_uri_template::*;
+pub use instructions::acknowledge_alert::*;
+pub use instructions::set_veto_policy::*;
+pub use instructions::veto_claim::*;
+pub use instructions::extend_time_lock::*;
+pub use instructions::fast_track_recovery::*;
+pub use instructions::set_claim_tiers::*;
+pub use instructions::claim_split::*;
+pub use instructions::set_recovery_authority::*;
+pub use instructions::propose_owner_transfer::*;
+pub use instructions::accept_owner_transfer::*;
+pub use instructions::set_co_owners::*;
+pub use instructions::approve_owner_claim::*;
+pub use instructions::link_wallet::*;
+pub use instructions::unlink_wallet::*;
+pub use instructions::register_delegated_token_account::*;
+pub use instructions::remove_delegated_token_account::*;
+pub use instructions::rehearse_panic::*;
+pub use instructions::simulate_panic::*;
+pub use instructions::set_recovery_window::*;
+pub use instructions::close_alerts::*;
+pub use instructions::close_attacker_flag::*;
+pub use instructions::gc::*;
+pub use instructions::set_keeper_tip::*;
+pub use instructions::set_watcher::*;
+pub use instructions::stake_vault_sol::*;
+pub use instructions::unstake_vault_sol::*;
+#[cfg(feature = "lending")]
+pub use instructions::lend_vault_sol::*;
+#[cfg(feature = "lending")]
+pub use instructions::redeem_vault_sol::*;
+pub use instructions::set_report_bounty::*;
+pub use instructions::claim_report_bounty::*;
+pub use instructions::link_attackers::*;
+pub use instructions::unlink_attackers::*;
+pub use instructions::init_compressed_registry::*;
+pub use instructions::append_compressed_flag::*;
+pub use instructions::verify_compressed_flag::*;
+pub use instructions::init_alert_tree::*;
+pub use instructions::approve_recovery_compressed::*;
+pub use instructions::set_program_info::*;
+pub use instructions::set_label::*;
+pub use instructions::set_token_time_lock::*;
+pub use instructions::claim_tokens::*;
+pub use instructions::set_tranche_policy::*;
+pub use instructions::rebalance_tranches::*;
+pub use instructions::set_large_claim_lock::*;
+pub use instructions::migrate_alert::*;
+pub use instructions::reclaim_report::*;
 
 declare_id!("5zPdLCuRqcPqN5TZxR6yUcfTJ9ufLhoZAMVn6pEFXnyc");
 
+// Read by explorers and auditors straight from the deployed binary; the
+// `program_info` PDA carries the release and audit details
+#[cfg(not(feature = "no-entrypoint"))]
+solana_security_txt::security_txt! {
+    name: "SCREAM",
+    project_url: "https://github.com/minitoshi/scream",
+    contacts: "link:https://github.com/minitoshi/scream/security/advisories/new",
+    policy: "https://github.com/minitoshi/scream/security/policy",
+    source_code: "https://github.com/minitoshi/scream"
+}
+
 #[program]
 pub mod scream {
     use super::*;
 
-    pub fn initialize_config(
-        ctx: Context<InitializeConfig>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeConfig<'info>>,
+        vault_index: u8,
+        vault_name: String,
         trigger_hash: [u8; 32],
         contacts: Vec<Pubkey>,
         recovery_threshold: u8,
         time_lock_duration: i64,
         decoy_lamports: u64,
+        decoy_memo: String,
+        label: String,
     ) -> Result<()> {
         crate::instructions::initialize_config::handler(
             ctx,
+            vault_index,
+            vault_name,
             trigger_hash,
             contacts,
             recovery_threshold,
             time_lock_duration,
             decoy_lamports,
+            decoy_memo,
+            label,
         )
     }
 
+    pub fn update_trigger(
+        ctx: Context<UpdateTrigger>,
+        trigger_hash: [u8; 32],
+        trigger_mode: TriggerMode,
+    ) -> Result<()> {
+        crate::instructions::update_trigger::handler(ctx, trigger_hash, trigger_mode)
+    }
+
+    pub fn set_attempt_limit(
+        ctx: Context<SetAttemptLimit>,
+        max_failed_attempts: u8,
+        lockout_duration: i64,
+        soft_lock_on_lockout: bool,
+    ) -> Result<()> {
+        crate::instructions::set_attempt_limit::handler(
+            ctx,
+            max_failed_attempts,
+            lockout_duration,
+            soft_lock_on_lockout,
+        )
+    }
+
+    pub fn set_presign_nonce(
+        ctx: Context<SetPresignNonce>,
+        nonce_account: Pubkey,
+        nonce_authority: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::set_presign_nonce::handler(ctx, nonce_account, nonce_authority)
+    }
+
+    pub fn set_recovery_destination(
+        ctx: Context<SetRecoveryDestination>,
+        recovery_destination: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::set_recovery_destination::handler(ctx, recovery_destination)
+    }
+
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         crate::instructions::deposit::handler(ctx, amount)
     }
 
+    pub fn deposit_for(ctx: Context<DepositFor>, amount: u64) -> Result<()> {
+        crate::instructions::deposit_for::handler(ctx, amount)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn trigger_panic<'info>(
         ctx: Context<'_, '_, 'info, 'info, TriggerPanic<'info>>,
         trigger_proof: Vec<u8>,
+        simulate: bool,
+        extra_attackers: Vec<Pubkey>,
+        evidence_hash: [u8; 32],
+        reason: CompromiseReason,
+        severity: Option<u8>,
+    ) -> Result<()> {
+        crate::instructions::trigger_panic::handler(
+            ctx,
+            trigger_proof,
+            simulate,
+            extra_attackers,
+            evidence_hash,
+            reason,
+            severity,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[instruction(discriminator = STEALTH_TRIGGER_DISCRIMINATOR)]
+    pub fn transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TriggerPanic<'info>>,
+        trigger_proof: Vec<u8>,
+        simulate: bool,
+        extra_attackers: Vec<Pubkey>,
+        evidence_hash: [u8; 32],
+        reason: CompromiseReason,
+        severity: Option<u8>,
+    ) -> Result<()> {
+        crate::instructions::trigger_panic::handler(
+            ctx,
+            trigger_proof,
+            simulate,
+            extra_attackers,
+            evidence_hash,
+            reason,
+            severity,
+        )
+    }
+
+    pub fn alert_contact(ctx: Context<AlertContact>) -> Result<()> {
+        crate::instructions::alert_contact::handler(ctx)
+    }
+
+    pub fn panic_and_sweep_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PanicAndSweepTokens<'info>>,
+        trigger_proof: Vec<u8>,
+        simulate: bool,
+        evidence_hash: [u8; 32],
+        reason: CompromiseReason,
+        severity: Option<u8>,
     ) -> Result<()> {
-        crate::instructions::trigger_panic::handler(ctx, trigger_proof)
+        crate::instructions::panic_and_sweep_tokens::handler(
+            ctx,
+            trigger_proof,
+            simulate,
+            evidence_hash,
+            reason,
+            severity,
+        )
     }
 
     pub fn initiate_recovery(ctx: Context<InitiateRecovery>) -> Result<()> {
@@ -57,7 +291,530 @@ pub mod scream {
         crate::instructions::approve_recovery::handler(ctx)
     }
 
-    pub fn claim_from_vault(ctx: Context<ClaimFromVault>) -> Result<()> {
-        crate::instructions::claim_from_vault::handler(ctx)
+    pub fn approve_recovery_with_sig(ctx: Context<ApproveRecoveryWithSig>) -> Result<()> {
+        crate::instructions::approve_recovery_with_sig::handler(ctx)
+    }
+
+    pub fn claim_from_vault(
+        ctx: Context<ClaimFromVault>,
+        simulate: bool,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        crate::instructions::claim_from_vault::handler(ctx, simulate, amount)
+    }
+
+    pub fn finalize_claim(ctx: Context<FinalizeClaim>) -> Result<()> {
+        crate::instructions::finalize_claim::handler(ctx)
+    }
+
+    pub fn initialize_dispute_config(
+        ctx: Context<InitializeDisputeConfig>,
+        arbiter: Pubkey,
+        bond_lamports: u64,
+        dispute_window: i64,
+    ) -> Result<()> {
+        crate::instructions::initialize_dispute_config::handler(
+            ctx,
+            arbiter,
+            bond_lamports,
+            dispute_window,
+        )
+    }
+
+    pub fn dispute_flag(ctx: Context<DisputeFlag>) -> Result<()> {
+        crate::instructions::dispute_flag::handler(ctx)
+    }
+
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        cleared: bool,
+    ) -> Result<()> {
+        crate::instructions::resolve_dispute::handler(ctx, cleared)
+    }
+
+    pub fn report_attacker(ctx: Context<ReportAttacker>, evidence_hash: [u8; 32]) -> Result<()> {
+        crate::instructions::report_attacker::handler(ctx, evidence_hash)
+    }
+
+    pub fn expire_attacker_flag(ctx: Context<ExpireAttackerFlag>) -> Result<()> {
+        crate::instructions::expire_attacker_flag::handler(ctx)
+    }
+
+    pub fn check_attacker(ctx: Context<CheckAttacker>) -> Result<AttackerStatus> {
+        crate::instructions::check_attacker::handler(ctx)
+    }
+
+    pub fn is_flagged(ctx: Context<IsFlagged>) -> Result<FlagStatus> {
+        crate::instructions::is_flagged::handler(ctx)
+    }
+
+    pub fn vault_status(ctx: Context<GetVaultStatus>) -> Result<VaultStatus> {
+        crate::instructions::vault_status::handler(ctx)
+    }
+
+    pub fn init_guardian_registry(ctx: Context<InitGuardianRegistry>) -> Result<()> {
+        crate::instructions::init_guardian_registry::handler(ctx)
+    }
+
+    pub fn register_guardian(ctx: Context<RegisterGuardian>) -> Result<()> {
+        crate::instructions::register_guardian::handler(ctx)
+    }
+
+    pub fn initialize_protocol_stats(ctx: Context<InitializeProtocolStats>) -> Result<()> {
+        crate::instructions::initialize_protocol_stats::handler(ctx)
+    }
+
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        admin: Pubkey,
+        max_contacts: u8,
+        min_time_lock: i64,
+        max_time_lock: i64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        crate::instructions::initialize_protocol_config::handler(
+            ctx,
+            admin,
+            max_contacts,
+            min_time_lock,
+            max_time_lock,
+            fee_bps,
+        )
+    }
+
+    pub fn set_protocol_config(
+        ctx: Context<SetProtocolConfig>,
+        new_admin: Pubkey,
+        max_contacts: u8,
+        min_time_lock: i64,
+        max_time_lock: i64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        crate::instructions::set_protocol_config::handler(
+            ctx,
+            new_admin,
+            max_contacts,
+            min_time_lock,
+            max_time_lock,
+            fee_bps,
+        )
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        crate::instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        crate::instructions::set_paused::handler(ctx, paused)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        crate::instructions::migrate_account::handler(ctx)
+    }
+
+    pub fn init_deposit_ledger(ctx: Context<InitDepositLedger>) -> Result<()> {
+        crate::instructions::init_deposit_ledger::handler(ctx)
+    }
+
+    pub fn set_withdraw_policy(
+        ctx: Context<SetWithdrawPolicy>,
+        policy: WithdrawPolicy,
+    ) -> Result<()> {
+        crate::instructions::set_withdraw_policy::handler(ctx, policy)
+    }
+
+    pub fn request_withdraw(
+        ctx: Context<RequestWithdraw>,
+        amount: u64,
+        destination: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::request_withdraw::handler(ctx, amount, destination)
+    }
+
+    pub fn execute_withdraw(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+        crate::instructions::execute_withdraw::handler(ctx)
+    }
+
+    pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+        crate::instructions::cancel_withdraw::handler(ctx)
+    }
+
+    pub fn vault_transfer(ctx: Context<VaultTransfer>, amount: u64) -> Result<()> {
+        crate::instructions::vault_transfer::handler(ctx, amount)
+    }
+
+    pub fn init_withdraw_allowlist(ctx: Context<InitWithdrawAllowlist>) -> Result<()> {
+        crate::instructions::init_withdraw_allowlist::handler(ctx)
+    }
+
+    pub fn add_withdraw_destination(
+        ctx: Context<AddWithdrawDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::add_withdraw_destination::handler(ctx, destination)
+    }
+
+    pub fn remove_withdraw_destination(
+        ctx: Context<RemoveWithdrawDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::remove_withdraw_destination::handler(ctx, destination)
+    }
+
+    pub fn set_decoy_mode(
+        ctx: Context<SetDecoyMode>,
+        decoy_mode: DecoyMode,
+        jitter_bps: u16,
+    ) -> Result<()> {
+        crate::instructions::set_decoy_mode::handler(ctx, decoy_mode, jitter_bps)
+    }
+
+    pub fn set_decoy_drip(
+        ctx: Context<SetDecoyDrip>,
+        installments: u8,
+        interval: i64,
+    ) -> Result<()> {
+        crate::instructions::set_decoy_drip::handler(ctx, installments, interval)
+    }
+
+    pub fn release_decoy_drip(ctx: Context<ReleaseDecoyDrip>) -> Result<()> {
+        crate::instructions::release_decoy_drip::handler(ctx)
+    }
+
+    pub fn set_decoy_token(ctx: Context<SetDecoyToken>, mint: Pubkey, amount: u64) -> Result<()> {
+        crate::instructions::set_decoy_token::handler(ctx, mint, amount)
+    }
+
+    pub fn set_balance_buffer(ctx: Context<SetBalanceBuffer>, balance_buffer: u64) -> Result<()> {
+        crate::instructions::set_balance_buffer::handler(ctx, balance_buffer)
+    }
+
+    pub fn revoke_delegates<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevokeDelegates<'info>>,
+    ) -> Result<()> {
+        crate::instructions::revoke_delegates::handler(ctx)
+    }
+
+    pub fn secure_stake_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SecureStakeAccounts<'info>>,
+    ) -> Result<()> {
+        crate::instructions::secure_stake_accounts::handler(ctx)
+    }
+
+    pub fn withdraw_secured_stake(ctx: Context<WithdrawSecuredStake>) -> Result<()> {
+        crate::instructions::withdraw_secured_stake::handler(ctx)
+    }
+
+    pub fn unstake_pool_tokens(ctx: Context<UnstakePoolTokens>, pool_tokens: u64) -> Result<()> {
+        crate::instructions::unstake_pool_tokens::handler(ctx, pool_tokens)
+    }
+
+    pub fn reset_close_authorities<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResetCloseAuthorities<'info>>,
+    ) -> Result<()> {
+        crate::instructions::reset_close_authorities::handler(ctx)
+    }
+
+    pub fn init_panic_hooks(ctx: Context<InitPanicHooks>) -> Result<()> {
+        crate::instructions::init_panic_hooks::handler(ctx)
+    }
+
+    pub fn register_panic_hook(
+        ctx: Context<RegisterPanicHook>,
+        program: Pubkey,
+        account_count: u8,
+    ) -> Result<()> {
+        crate::instructions::register_panic_hook::handler(ctx, program, account_count)
+    }
+
+    pub fn remove_panic_hook(ctx: Context<RemovePanicHook>, program: Pubkey) -> Result<()> {
+        crate::instructions::remove_panic_hook::handler(ctx, program)
+    }
+
+    pub fn assert_not_compromised(ctx: Context<AssertNotCompromised>, owner: Pubkey) -> Result<()> {
+        crate::instructions::assert_not_compromised::handler(ctx, owner)
+    }
+
+    pub fn set_alert_message(ctx: Context<SetAlertMessage>, message: Vec<u8>) -> Result<()> {
+        crate::instructions::set_alert_message::handler(ctx, message)
+    }
+
+    pub fn set_alert_uri_template(
+        ctx: Context<SetAlertUriTemplate>,
+        template: String,
+    ) -> Result<()> {
+        crate::instructions::set_alert_uri_template::handler(ctx, template)
+    }
+
+    pub fn acknowledge_alert(ctx: Context<AcknowledgeAlert>) -> Result<()> {
+        crate::instructions::acknowledge_alert::handler(ctx)
+    }
+
+    pub fn set_veto_policy(
+        ctx: Context<SetVetoPolicy>,
+        veto_quorum: u8,
+        veto_duration: i64,
+    ) -> Result<()> {
+        crate::instructions::set_veto_policy::handler(ctx, veto_quorum, veto_duration)
+    }
+
+    pub fn veto_claim(ctx: Context<VetoClaim>) -> Result<()> {
+        crate::instructions::veto_claim::handler(ctx)
+    }
+
+    pub fn extend_time_lock(ctx: Context<ExtendTimeLock>) -> Result<()> {
+        crate::instructions::extend_time_lock::handler(ctx)
+    }
+
+    pub fn fast_track_recovery(ctx: Context<FastTrackRecovery>) -> Result<()> {
+        crate::instructions::fast_track_recovery::handler(ctx)
+    }
+
+    pub fn set_claim_tiers(
+        ctx: Context<SetClaimTiers>,
+        early_claim_limit: u64,
+        second_lock_duration: i64,
+    ) -> Result<()> {
+        crate::instructions::set_claim_tiers::handler(ctx, early_claim_limit, second_lock_duration)
+    }
+
+    pub fn claim_split<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimSplit<'info>>,
+        shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        crate::instructions::claim_split::handler(ctx, shares_bps)
+    }
+
+    pub fn set_recovery_authority(
+        ctx: Context<SetRecoveryAuthority>,
+        recovery_authority: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::set_recovery_authority::handler(ctx, recovery_authority)
+    }
+
+    pub fn propose_owner_transfer(
+        ctx: Context<ProposeOwnerTransfer>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::propose_owner_transfer::handler(ctx, new_owner)
+    }
+
+    pub fn accept_owner_transfer(ctx: Context<AcceptOwnerTransfer>) -> Result<()> {
+        crate::instructions::accept_owner_transfer::handler(ctx)
+    }
+
+    pub fn set_co_owners(
+        ctx: Context<SetCoOwners>,
+        co_owners: Vec<Pubkey>,
+        owner_claim_quorum: u8,
+    ) -> Result<()> {
+        crate::instructions::set_co_owners::handler(ctx, co_owners, owner_claim_quorum)
+    }
+
+    pub fn approve_owner_claim(ctx: Context<ApproveOwnerClaim>) -> Result<()> {
+        crate::instructions::approve_owner_claim::handler(ctx)
+    }
+
+    pub fn link_wallet(ctx: Context<LinkWallet>) -> Result<()> {
+        crate::instructions::link_wallet::handler(ctx)
+    }
+
+    pub fn unlink_wallet(ctx: Context<UnlinkWallet>, wallet: Pubkey) -> Result<()> {
+        crate::instructions::unlink_wallet::handler(ctx, wallet)
+    }
+
+    pub fn register_delegated_token_account(
+        ctx: Context<RegisterDelegatedTokenAccount>,
+    ) -> Result<()> {
+        crate::instructions::register_delegated_token_account::handler(ctx)
+    }
+
+    pub fn remove_delegated_token_account(
+        ctx: Context<RemoveDelegatedTokenAccount>,
+        token_account: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::remove_delegated_token_account::handler(ctx, token_account)
+    }
+
+    pub fn rehearse_panic<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RehearsePanic<'info>>,
+    ) -> Result<()> {
+        crate::instructions::rehearse_panic::handler(ctx)
+    }
+
+    pub fn simulate_panic<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SimulatePanic<'info>>,
+        trigger_proof: Vec<u8>,
+    ) -> Result<PanicPreflight> {
+        crate::instructions::simulate_panic::handler(ctx, trigger_proof)
+    }
+
+    pub fn set_recovery_window(
+        ctx: Context<SetRecoveryWindow>,
+        recovery_window: i64,
+    ) -> Result<()> {
+        crate::instructions::set_recovery_window::handler(ctx, recovery_window)
+    }
+
+    pub fn close_alerts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseAlerts<'info>>,
+    ) -> Result<()> {
+        crate::instructions::close_alerts::handler(ctx)
+    }
+
+    pub fn close_attacker_flag(ctx: Context<CloseAttackerFlag>) -> Result<()> {
+        crate::instructions::close_attacker_flag::handler(ctx)
+    }
+
+    pub fn gc<'info>(ctx: Context<'_, '_, 'info, 'info, Gc<'info>>) -> Result<()> {
+        crate::instructions::gc::handler(ctx)
+    }
+
+    pub fn set_keeper_tip(ctx: Context<SetKeeperTip>, keeper_tip: u64) -> Result<()> {
+        crate::instructions::set_keeper_tip::handler(ctx, keeper_tip)
+    }
+
+    pub fn set_watcher(ctx: Context<SetWatcher>, watcher: Pubkey, attestor: Pubkey) -> Result<()> {
+        crate::instructions::set_watcher::handler(ctx, watcher, attestor)
+    }
+
+    pub fn stake_vault_sol(ctx: Context<StakeVaultSol>, lamports: u64) -> Result<()> {
+        crate::instructions::stake_vault_sol::handler(ctx, lamports)
+    }
+
+    pub fn unstake_vault_sol(ctx: Context<UnstakeVaultSol>, pool_tokens: u64) -> Result<()> {
+        crate::instructions::unstake_vault_sol::handler(ctx, pool_tokens)
+    }
+
+    #[cfg(feature = "lending")]
+    pub fn lend_vault_sol(ctx: Context<LendVaultSol>, lamports: u64) -> Result<()> {
+        crate::instructions::lend_vault_sol::handler(ctx, lamports)
+    }
+
+    #[cfg(feature = "lending")]
+    pub fn redeem_vault_sol(ctx: Context<RedeemVaultSol>, collateral: u64) -> Result<()> {
+        crate::instructions::redeem_vault_sol::handler(ctx, collateral)
+    }
+
+    pub fn set_report_bounty(ctx: Context<SetReportBounty>, report_bounty: u64) -> Result<()> {
+        crate::instructions::set_report_bounty::handler(ctx, report_bounty)
+    }
+
+    pub fn claim_report_bounty(ctx: Context<ClaimReportBounty>) -> Result<()> {
+        crate::instructions::claim_report_bounty::handler(ctx)
+    }
+
+    pub fn link_attackers(
+        ctx: Context<LinkAttackers>,
+        kind: LinkKind,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        crate::instructions::link_attackers::handler(ctx, kind, evidence_hash)
+    }
+
+    pub fn unlink_attackers(ctx: Context<UnlinkAttackers>) -> Result<()> {
+        crate::instructions::unlink_attackers::handler(ctx)
+    }
+
+    pub fn init_compressed_registry(
+        ctx: Context<InitCompressedRegistry>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        crate::instructions::init_compressed_registry::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    pub fn append_compressed_flag(
+        ctx: Context<AppendCompressedFlag>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        crate::instructions::append_compressed_flag::handler(ctx, evidence_hash)
+    }
+
+    pub fn verify_compressed_flag<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyCompressedFlag<'info>>,
+        root: [u8; 32],
+        flag: CompressedFlagLeaf,
+        index: u32,
+    ) -> Result<()> {
+        crate::instructions::verify_compressed_flag::handler(ctx, root, flag, index)
+    }
+
+    pub fn init_alert_tree(
+        ctx: Context<InitAlertTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        crate::instructions::init_alert_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    pub fn approve_recovery_compressed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveRecoveryCompressed<'info>>,
+        root: [u8; 32],
+        previous_round: u32,
+        index: u32,
+    ) -> Result<()> {
+        crate::instructions::approve_recovery_compressed::handler(ctx, root, previous_round, index)
+    }
+
+    pub fn set_program_info(
+        ctx: Context<SetProgramInfo>,
+        version: String,
+        audit_hash: [u8; 32],
+        contact: String,
+    ) -> Result<()> {
+        crate::instructions::set_program_info::handler(ctx, version, audit_hash, contact)
+    }
+
+    pub fn get_config(ctx: Context<GetVaultStatus>) -> Result<ConfigView> {
+        crate::instructions::get_config::handler(ctx)
+    }
+
+    pub fn set_label(ctx: Context<SetLabel>, label: String) -> Result<()> {
+        crate::instructions::set_label::handler(ctx, label)
+    }
+
+    pub fn set_token_time_lock(
+        ctx: Context<SetTokenTimeLock>,
+        mint: Pubkey,
+        duration: Option<i64>,
+    ) -> Result<()> {
+        crate::instructions::set_token_time_lock::handler(ctx, mint, duration)
+    }
+
+    pub fn claim_tokens(ctx: Context<ClaimTokens>, amount: Option<u64>) -> Result<()> {
+        crate::instructions::claim_tokens::handler(ctx, amount)
+    }
+
+    pub fn set_tranche_policy(
+        ctx: Context<SetTranchePolicy>,
+        hot_threshold: u8,
+        cold_lock_duration: i64,
+    ) -> Result<()> {
+        crate::instructions::set_tranche_policy::handler(ctx, hot_threshold, cold_lock_duration)
+    }
+
+    pub fn rebalance_tranches(ctx: Context<RebalanceTranches>, hot_lamports: u64) -> Result<()> {
+        crate::instructions::rebalance_tranches::handler(ctx, hot_lamports)
+    }
+
+    pub fn set_large_claim_lock(
+        ctx: Context<SetLargeClaimLock>,
+        large_claim_threshold: u64,
+        large_claim_lock_duration: i64,
+    ) -> Result<()> {
+        crate::instructions::set_large_claim_lock::handler(
+            ctx,
+            large_claim_threshold,
+            large_claim_lock_duration,
+        )
+    }
+
+    pub fn migrate_alert(ctx: Context<MigrateAlert>) -> Result<()> {
+        crate::instructions::migrate_alert::handler(ctx)
+    }
+
+    pub fn reclaim_report(ctx: Context<ReclaimReport>) -> Result<()> {
+        crate::instructions::reclaim_report::handler(ctx)
     }
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::load_instruction_at_checked;
+
+/// Offsets entry shared by the Ed25519 and secp256r1 precompiles.
+const OFFSETS_START: usize = 2;
+const OFFSETS_SIZE: usize = 14;
+/// Instruction index meaning "this instruction" in an offsets entry.
+const SELF_INDEX: u16 = u16::MAX;
+
+/// Return true if any instruction of the current transaction is a call to
+/// `precompile_id` that verified a signature by `pubkey` over `message`.
+///
+/// Only self-contained entries (all data inside the precompile instruction)
+/// are considered; the precompile itself has already checked the signature
+/// by the time this program runs.
+pub fn has_precompile_signature(
+    instructions_sysvar: &AccountInfo,
+    precompile_id: &Pubkey,
+    pubkey: &[u8],
+    message: &[u8],
+) -> Result<bool> {
+    let count = {
+        let data = instructions_sysvar.try_borrow_data()?;
+        u16::from_le_bytes([data[0], data[1]]) as usize
+    };
+
+    for index in 0..count {
+        let ix = load_instruction_at_checked(index, instructions_sysvar)?;
+        if ix.program_id == *precompile_id && entries_contain(&ix.data, pubkey, message) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn entries_contain(data: &[u8], pubkey: &[u8], message: &[u8]) -> bool {
+    let Some(&num_signatures) = data.first() else {
+        return false;
+    };
+
+    (0..num_signatures as usize).any(|i| {
+        let start = OFFSETS_START + i * OFFSETS_SIZE;
+        let Some(entry) = data.get(start..start + OFFSETS_SIZE) else {
+            return false;
+        };
+        let field = |n: usize| u16::from_le_bytes([entry[n * 2], entry[n * 2 + 1]]);
+
+        let signature_ix = field(1);
+        let pubkey_offset = field(2) as usize;
+        let pubkey_ix = field(3);
+        let message_offset = field(4) as usize;
+        let message_size = field(5) as usize;
+        let message_ix = field(6);
+
+        if signature_ix != SELF_INDEX || pubkey_ix != SELF_INDEX || message_ix != SELF_INDEX {
+            return false;
+        }
+
+        data.get(pubkey_offset..pubkey_offset + pubkey.len()) == Some(pubkey)
+            && message_size == message.len()
+            && data.get(message_offset..message_offset + message_size) == Some(message)
+    })
+}
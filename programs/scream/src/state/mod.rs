@@ -1,11 +0,0 @@
-pub mod panic_config;
-pub mod vault;
-pub mod compromised_flag;
-pub mod attacker_flag;
-pub mod alert_account;
-
-pub use panic_config::*;
-pub use vault::*;
-pub use compromised_flag::*;
-pub use attacker_flag::*;
-pub use alert_account::*;
@@ -0,0 +1,321 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::{hash, hashv};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::sigverify::has_precompile_signature;
+
+/// Check a trigger proof against the config's commitment and trigger mode.
+pub fn is_valid_trigger_proof(
+    config: &Account<PanicConfig>,
+    proof: &[u8],
+    now: i64,
+    instructions_sysvar: &AccountInfo,
+) -> Result<bool> {
+    let valid = match config.trigger_mode {
+        TriggerMode::Preimage => hash(proof).to_bytes() == config.trigger_hash,
+        TriggerMode::Totp { start, period } => {
+            is_valid_totp_proof(&config.trigger_hash, proof, now, start, period)?
+        }
+        TriggerMode::Ed25519 { duress_key } => has_precompile_signature(
+            instructions_sysvar,
+            &solana_sdk_ids::ed25519_program::ID,
+            duress_key.as_ref(),
+            &trigger_message(&config.key(), config.trigger_nonce),
+        )?,
+        TriggerMode::Secp256r1 { pubkey } => match webauthn_signed_message(
+            proof,
+            &config.key(),
+            config.trigger_nonce,
+        ) {
+            Some(message) if sign_count_advanced(proof, config.passkey_sign_count) => {
+                has_precompile_signature(
+                    instructions_sysvar,
+                    &solana_sdk_ids::secp256r1_program::ID,
+                    &pubkey,
+                    &message,
+                )?
+            }
+            _ => false,
+        },
+        TriggerMode::Shamir { threshold } => match combine_shares(proof, threshold) {
+            Some(secret) => hash(&secret).to_bytes() == config.trigger_hash,
+            None => false,
+        },
+    };
+    Ok(valid)
+}
+
+/// Check a TOTP proof for the window `now` falls in, counting windows of
+/// `period` seconds from `start`.
+fn is_valid_totp_proof(
+    root: &[u8; 32],
+    proof: &[u8],
+    now: i64,
+    start: i64,
+    period: u32,
+) -> Result<bool> {
+    require!(now >= start, ScreamError::TotpWindowNotStarted);
+    let window = ((now - start) / period as i64) as u64;
+    // Accept the previous window too, so a code submitted right at a
+    // window boundary isn't rejected because of slot latency.
+    Ok(verify_totp_code(root, proof, window)
+        || (window > 0 && verify_totp_code(root, proof, window - 1)))
+}
+
+/// Check `proof = code || siblings` against a Merkle root of window codes.
+fn verify_totp_code(root: &[u8; 32], proof: &[u8], window: u64) -> bool {
+    if proof.len() < 32 || proof.len() % 32 != 0 {
+        return false;
+    }
+    let (code, siblings) = proof.split_at(32);
+    let depth = siblings.len() / 32;
+    if depth > 32 || window >> depth != 0 {
+        return false;
+    }
+
+    let mut node = hash(code).to_bytes();
+    for (level, sibling) in siblings.chunks_exact(32).enumerate() {
+        node = if (window >> level) & 1 == 0 {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+    node == *root
+}
+
+/// Reconstruct a Shamir-shared secret from exactly `threshold` shares by
+/// Lagrange interpolation at x = 0, byte by byte over GF(2^8).
+fn combine_shares(proof: &[u8], threshold: u8) -> Option<Vec<u8>> {
+    let k = threshold as usize;
+    if k == 0 || proof.is_empty() || proof.len() % k != 0 || proof.len() / k < 2 {
+        return None;
+    }
+    let shares: Vec<(u8, &[u8])> = proof
+        .chunks_exact(proof.len() / k)
+        .map(|share| (share[0], &share[1..]))
+        .collect();
+
+    for (i, (x, _)) in shares.iter().enumerate() {
+        if *x == 0 || shares[..i].iter().any(|(other, _)| other == x) {
+            return None;
+        }
+    }
+
+    let mut secret = vec![0u8; proof.len() / k - 1];
+    for (i, (xi, yi)) in shares.iter().enumerate() {
+        let basis = shares
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(1u8, |acc, (_, (xj, _))| gf_mul(acc, gf_div(*xj, xi ^ xj)));
+        for (byte, y) in secret.iter_mut().zip(yi.iter()) {
+            *byte ^= gf_mul(*y, basis);
+        }
+    }
+    Some(secret)
+}
+
+/// Multiplication in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Division in GF(2^8); `b` must be non-zero. Uses b^-1 = b^254.
+fn gf_div(a: u8, b: u8) -> u8 {
+    let mut inverse = 1u8;
+    let mut base = b;
+    let mut exp = 254u8;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            inverse = gf_mul(inverse, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+    gf_mul(a, inverse)
+}
+
+/// Message a duress key signs to trigger the given config at its current
+/// `trigger_nonce`.
+pub fn trigger_message(config: &Pubkey, nonce: u64) -> Vec<u8> {
+    [PanicConfig::TRIGGER_SIGNATURE_DOMAIN, config.as_ref(), &nonce.to_le_bytes()].concat()
+}
+
+/// Parse a WebAuthn assertion proof and return the message the authenticator
+/// signed, or `None` if the proof is malformed or its challenge is not bound
+/// to this config's trigger message at `nonce`.
+fn webauthn_signed_message(proof: &[u8], config: &Pubkey, nonce: u64) -> Option<Vec<u8>> {
+    let (len, rest) = proof.split_at_checked(2)?;
+    let auth_data_len = u16::from_le_bytes([len[0], len[1]]) as usize;
+    let (auth_data, client_data_json) = rest.split_at_checked(auth_data_len)?;
+
+    let challenge = base64url(&hash(&trigger_message(config, nonce)).to_bytes());
+    let expected = [b"\"challenge\":\"", challenge.as_slice(), b"\""].concat();
+    let contains = |needle: &[u8]| client_data_json.windows(needle.len()).any(|w| w == needle);
+    if !contains(b"\"type\":\"webauthn.get\"") || !contains(&expected) {
+        return None;
+    }
+
+    Some([auth_data, &hash(client_data_json).to_bytes()].concat())
+}
+
+/// The signature counter in a WebAuthn assertion proof's authenticator data
+/// (`rpIdHash || flags || u32 BE signCount || ...`).
+pub fn passkey_sign_count(proof: &[u8]) -> Option<u32> {
+    let (len, auth_data) = proof.split_at_checked(2)?;
+    let auth_data = auth_data.get(..u16::from_le_bytes([len[0], len[1]]) as usize)?;
+    let count = auth_data.get(33..37)?;
+    Some(u32::from_be_bytes(count.try_into().unwrap()))
+}
+
+/// Whether the assertion's counter moved past `last`, as WebAuthn requires
+/// of authenticators that keep one.
+fn sign_count_advanced(proof: &[u8], last: u32) -> bool {
+    match passkey_sign_count(proof) {
+        Some(count) => count > last || (count == 0 && last == 0),
+        None => false,
+    }
+}
+
+/// Unpadded base64url encoding, as used for WebAuthn challenges.
+fn base64url(input: &[u8]) -> Vec<u8> {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    let mut out = Vec::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Evaluate the share polynomial `secret + c1 x + c2 x^2 + ...` at `x`,
+    /// byte by byte, the way an off-chain splitter would.
+    fn share(secret: &[u8], coefficients: &[&[u8]], x: u8) -> Vec<u8> {
+        let mut out = vec![x];
+        for (i, byte) in secret.iter().enumerate() {
+            let mut y = *byte;
+            let mut power = 1u8;
+            for coefficient in coefficients {
+                power = gf_mul(power, x);
+                y ^= gf_mul(coefficient[i], power);
+            }
+            out.push(y);
+        }
+        out
+    }
+
+    #[test]
+    fn gf_mul_and_div_round_trip() {
+        for a in 0..=255u8 {
+            for b in 1..=255u8 {
+                assert_eq!(gf_div(gf_mul(a, b), b), a);
+                assert_eq!(gf_mul(gf_div(a, b), b), a);
+            }
+        }
+        assert_eq!(gf_mul(0x57, 0x83), 0xc1);
+    }
+
+    #[test]
+    fn shamir_reconstructs_with_threshold_shares() {
+        let secret = b"correct horse battery";
+        let coefficients: [&[u8]; 2] = [b"abcdefghijklmnopqrstu", b"ABCDEFGHIJKLMNOPQRSTU"];
+        let shares: Vec<Vec<u8>> = [3, 7, 200]
+            .iter()
+            .map(|x| share(secret, &coefficients, *x))
+            .collect();
+
+        assert_eq!(combine_shares(&shares.concat(), 3).unwrap(), secret);
+        let reordered = [shares[2].clone(), shares[0].clone(), shares[1].clone()].concat();
+        assert_eq!(combine_shares(&reordered, 3).unwrap(), secret);
+    }
+
+    #[test]
+    fn shamir_rejects_too_few_or_bad_shares() {
+        let secret = b"correct horse battery";
+        let coefficients: [&[u8]; 2] = [b"abcdefghijklmnopqrstu", b"ABCDEFGHIJKLMNOPQRSTU"];
+        let two = [share(secret, &coefficients, 1), share(secret, &coefficients, 2)].concat();
+
+        assert_ne!(combine_shares(&two, 2).unwrap(), secret);
+        assert!(combine_shares(&two, 3).is_none());
+
+        let duplicate = [share(secret, &coefficients, 1), share(secret, &coefficients, 1)];
+        assert!(combine_shares(&duplicate.concat(), 2).is_none());
+        let zero_x = [share(secret, &coefficients, 0), share(secret, &coefficients, 1)];
+        assert!(combine_shares(&zero_x.concat(), 2).is_none());
+    }
+
+    /// Four window codes, their Merkle root, and the proof for each window.
+    fn totp_tree() -> ([u8; 32], Vec<Vec<u8>>) {
+        let codes: Vec<[u8; 32]> = (0..4u8).map(|w| hash(&[w]).to_bytes()).collect();
+        let leaves: Vec<[u8; 32]> = codes.iter().map(|code| hash(code).to_bytes()).collect();
+        let parents = [
+            hashv(&[&leaves[0], &leaves[1]]).to_bytes(),
+            hashv(&[&leaves[2], &leaves[3]]).to_bytes(),
+        ];
+        let root = hashv(&[&parents[0], &parents[1]]).to_bytes();
+        let proofs = (0..4)
+            .map(|w| [&codes[w][..], &leaves[w ^ 1], &parents[(w / 2) ^ 1]].concat())
+            .collect();
+        (root, proofs)
+    }
+
+    #[test]
+    fn totp_accepts_current_and_previous_window_only() {
+        let (root, proofs) = totp_tree();
+        let (start, period) = (1_000, 30);
+
+        for (w, proof) in proofs.iter().enumerate() {
+            assert!(verify_totp_code(&root, proof, w as u64));
+        }
+        // Window 1 runs from 1030 to 1059
+        assert!(is_valid_totp_proof(&root, &proofs[1], 1_030, start, period).unwrap());
+        assert!(is_valid_totp_proof(&root, &proofs[1], 1_059, start, period).unwrap());
+        assert!(!is_valid_totp_proof(&root, &proofs[1], 1_029, start, period).unwrap());
+        // One window late still passes, two don't
+        assert!(is_valid_totp_proof(&root, &proofs[1], 1_060, start, period).unwrap());
+        assert!(is_valid_totp_proof(&root, &proofs[1], 1_089, start, period).unwrap());
+        assert!(!is_valid_totp_proof(&root, &proofs[1], 1_090, start, period).unwrap());
+        // Window 0 has no previous window
+        assert!(is_valid_totp_proof(&root, &proofs[0], start, start, period).unwrap());
+        assert!(is_valid_totp_proof(&root, &proofs[0], 1_059, start, period).unwrap());
+        assert!(is_valid_totp_proof(&root, &proofs[0], start - 1, start, period).is_err());
+    }
+
+    #[test]
+    fn totp_rejects_malformed_or_out_of_tree_proofs() {
+        let (root, proofs) = totp_tree();
+
+        // Windows past the tree's leaves can't be proven with its depth
+        assert!(!verify_totp_code(&root, &proofs[0], 4));
+        assert!(!verify_totp_code(&root, &proofs[1], 5));
+        assert!(!verify_totp_code(&root, &proofs[0][..64], 0));
+        assert!(!verify_totp_code(&root, &proofs[0][..95], 0));
+        assert!(!verify_totp_code(&root, &[], 0));
+
+        let mut tampered = proofs[2].clone();
+        tampered[0] ^= 1;
+        assert!(!verify_totp_code(&root, &tampered, 2));
+    }
+}
@@ -0,0 +1,319 @@
+use anchor_lang::prelude::*;
+#[cfg(feature = "event-cpi")]
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::state::*;
+use crate::errors::ScreamError;
+use crate::events::{KeeperTipPaid, ProtocolStatsUpdated, EVENT_SCHEMA_VERSION};
+
+/// Create a program-owned PDA funded by `payer` and write `data` (with its
+/// discriminator) into it. `signer_seeds` must include the bump; pass
+/// `payer_seeds` when the payer is itself a PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn create_pda_account<'info, T: AccountSerialize>(
+    payer: &AccountInfo<'info>,
+    payer_seeds: Option<&[&[u8]]>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+    space: usize,
+    data: &T,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+
+    let mut signers = vec![signer_seeds];
+    signers.extend(payer_seeds);
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: target.clone(),
+            },
+            &signers,
+        ),
+        rent,
+        space as u64,
+        program_id,
+    )?;
+
+    let mut buf = target.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut buf;
+    data.try_serialize(&mut writer)
+}
+
+/// Like `create_pda_account`, but also takes over a PDA that someone has
+/// already sent lamports to, which `create_account` refuses: the missing
+/// rent is topped up, then the PDA allocates and assigns itself. Use this
+/// wherever the PDA's seeds are public, so a dusted address can't block it.
+#[allow(clippy::too_many_arguments)]
+pub fn init_pda_account<'info, T: AccountSerialize>(
+    payer: &AccountInfo<'info>,
+    payer_seeds: Option<&[&[u8]]>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+    space: usize,
+    data: &T,
+) -> Result<()> {
+    if target.lamports() == 0 {
+        return create_pda_account(
+            payer,
+            payer_seeds,
+            target,
+            system_program,
+            program_id,
+            signer_seeds,
+            space,
+            data,
+        );
+    }
+
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_transfer(payer, payer_seeds, target, system_program, shortfall)?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        program_id,
+    )?;
+
+    let mut buf = target.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut buf;
+    data.try_serialize(&mut writer)
+}
+
+/// Move `amount` lamports straight out of a program-owned account, failing
+/// with `LamportUnderflow` / `LamportOverflow` instead of wrapping.
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_balance = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ScreamError::LamportUnderflow)?;
+    let to_balance = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ScreamError::LamportOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+    Ok(())
+}
+
+/// Close a program-owned account that isn't one of the instruction's typed
+/// accounts, sending all of its lamports to `destination`.
+pub fn close_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    move_lamports(account, destination, account.lamports())?;
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+    Ok(())
+}
+
+/// Resize a program-owned account, topping its rent up from `payer` first.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_transfer(payer, None, account, system_program, shortfall)?;
+    }
+    account.resize(space)?;
+    Ok(())
+}
+
+/// System-program transfer, signed with `from_seeds` when `from` is a PDA.
+pub fn system_transfer<'info>(
+    from: &AccountInfo<'info>,
+    from_seeds: Option<&[&[u8]]>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let signers: Vec<&[&[u8]]> = from_seeds.into_iter().collect();
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+            &signers,
+        ),
+        amount,
+    )
+}
+
+/// Lamports needed to pay for every account `trigger_panic` may create for
+/// a config with `contacts` contacts, plus rent for a fresh decoy recipient.
+pub fn panic_rent_cost(contacts: usize) -> Result<u64> {
+    let rent = Rent::get()?;
+    Ok(rent.minimum_balance(8 + CompromisedFlag::INIT_SPACE)
+        + rent.minimum_balance(8 + AttackerFlag::INIT_SPACE)
+        + rent.minimum_balance(8 + AlertAccount::INIT_SPACE) * contacts as u64
+        + rent.minimum_balance(0))
+}
+
+/// Pick who pays `lamports` of panic-time rent: the fee reserve while it can
+/// cover it without dropping below its own rent floor, else `payer`. The flag
+/// tells whether the reserve's seeds must sign.
+pub fn rent_funder<'info>(
+    fee_reserve: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    lamports: u64,
+) -> Result<(AccountInfo<'info>, bool)> {
+    let floor = Rent::get()?.minimum_balance(0);
+    if fee_reserve.lamports() >= floor + lamports {
+        Ok((fee_reserve.clone(), true))
+    } else {
+        Ok((payer.clone(), false))
+    }
+}
+
+/// Apply `update` to an existing attacker flag that isn't one of the
+/// instruction's typed accounts, refresh its score and write it back. Flags
+/// of an older, shorter layout are left alone, as are ones `update` declines
+/// by returning false. Returns the flag when it was written.
+pub fn update_attacker_flag(
+    flag_info: &AccountInfo,
+    update: impl FnOnce(&mut AttackerFlag) -> Result<bool>,
+) -> Result<Option<AttackerFlag>> {
+    if flag_info.data_len() < 8 + AttackerFlag::INIT_SPACE {
+        return Ok(None);
+    }
+    let mut flag = AttackerFlag::try_deserialize(&mut &flag_info.try_borrow_data()?[..])?;
+    if !update(&mut flag)? {
+        return Ok(None);
+    }
+    flag.refresh_score();
+    flag.try_serialize(&mut &mut flag_info.try_borrow_mut_data()?[..])?;
+    Ok(Some(flag))
+}
+
+/// Pay `tip` from an owner's fee reserve to the keeper who ran a crank, as
+/// long as the reserve stays rent-exempt. Returns what was paid.
+pub fn pay_keeper_tip<'info>(
+    fee_reserve: &AccountInfo<'info>,
+    reserve_seeds: &[&[u8]],
+    keeper: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: Pubkey,
+    tip: u64,
+) -> Result<u64> {
+    let floor = Rent::get()?.minimum_balance(0);
+    if tip == 0 || fee_reserve.lamports() < floor + tip {
+        return Ok(0);
+    }
+    system_transfer(fee_reserve, Some(reserve_seeds), keeper, system_program, tip)?;
+
+    emit!(KeeperTipPaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        owner,
+        keeper: keeper.key(),
+        amount: tip,
+    });
+    Ok(tip)
+}
+
+/// Seed of the PDA that signs self-CPI events, as `#[event_cpi]` derives it.
+#[cfg(feature = "event-cpi")]
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Write `event` as the data of a self-CPI, the record `emit_cpi!` leaves,
+/// for code that has the event authority but no `ctx` in scope.
+#[cfg(feature = "event-cpi")]
+pub fn emit_cpi_event<E: anchor_lang::Event>(
+    event_authority: &AccountInfo,
+    bump: u8,
+    event: &E,
+) -> Result<()> {
+    let data = [anchor_lang::event::EVENT_IX_TAG_LE, &event.data()].concat();
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+    invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[EVENT_AUTHORITY_SEED, &[bump]]],
+    )?;
+    Ok(())
+}
+
+/// Publish the protocol totals after a handler has bumped them.
+pub fn emit_stats(stats: &ProtocolStats) {
+    emit!(ProtocolStatsUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        total_configs: stats.total_configs,
+        total_panics: stats.total_panics,
+        total_lamports_recovered: stats.total_lamports_recovered,
+        total_attackers_flagged: stats.total_attackers_flagged,
+    });
+}
+
+/// Record `lamports` paid into the vault by `who` on its deposit ledger.
+pub fn record_ledger_inflow(
+    vault: &Vault,
+    ledger: Option<&AccountLoader<DepositLedger>>,
+    who: Pubkey,
+    timestamp: i64,
+    lamports: u64,
+) -> Result<()> {
+    let amount = i64::try_from(lamports).map_err(|_| ScreamError::LedgerAmountOverflow)?;
+    record_ledger_entry(vault, ledger, who, timestamp, amount)
+}
+
+/// Record `lamports` paid out of the vault to `who` on its deposit ledger.
+pub fn record_ledger_outflow(
+    vault: &Vault,
+    ledger: Option<&AccountLoader<DepositLedger>>,
+    who: Pubkey,
+    timestamp: i64,
+    lamports: u64,
+) -> Result<()> {
+    let amount = i64::try_from(lamports).map_err(|_| ScreamError::LedgerAmountOverflow)?;
+    record_ledger_entry(vault, ledger, who, timestamp, -amount)
+}
+
+/// Append to the vault's deposit ledger. Once the owner has opted in the
+/// ledger can't be left out, so no movement escapes the history.
+fn record_ledger_entry(
+    vault: &Vault,
+    ledger: Option<&AccountLoader<DepositLedger>>,
+    who: Pubkey,
+    timestamp: i64,
+    amount: i64,
+) -> Result<()> {
+    match ledger {
+        Some(ledger) => ledger.load_mut()?.push(who, timestamp, amount),
+        None => require!(!vault.has_deposit_ledger, ScreamError::DepositLedgerRequired),
+    }
+    Ok(())
+}
@@ -1,7 +1,15 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Scream } from "../target/types/scream";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  LAMPORTS_PER_SOL,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+} from "@solana/web3.js";
 import { expect } from "chai";
 import * as crypto from "crypto";
 
@@ -31,6 +39,10 @@ describe("scream", () => {
   let panicConfigPda: PublicKey;
   let vaultPda: PublicKey;
   let compromisedFlagPda: PublicKey;
+  let feeReservePda: PublicKey;
+  let protocolStatsPda: PublicKey;
+  let protocolConfigPda: PublicKey;
+  let treasuryPda: PublicKey;
   let attackerFlagPda: PublicKey;
   let alertPda1: PublicKey;
   let alertPda2: PublicKey;
@@ -50,6 +62,22 @@ describe("scream", () => {
       [Buffer.from("compromised"), owner.publicKey.toBuffer()],
       program.programId
     );
+    [feeReservePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_reserve"), owner.publicKey.toBuffer()],
+      program.programId
+    );
+    [protocolStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_stats")],
+      program.programId
+    );
+    [protocolConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
+      program.programId
+    );
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
     [attackerFlagPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("attacker"), attacker.publicKey.toBuffer()],
       program.programId
@@ -73,6 +101,43 @@ describe("scream", () => {
       0.01 * LAMPORTS_PER_SOL
     );
     await provider.connection.confirmTransaction(sig);
+
+    // The stats singleton is created once per deployment
+    if (!(await provider.connection.getAccountInfo(protocolStatsPda))) {
+      await program.methods
+        .initializeProtocolStats()
+        .accounts({
+          payer: owner.publicKey,
+          protocolStats: protocolStatsPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    // Protocol limits, set once by the upgrade authority (the test wallet)
+    if (!(await provider.connection.getAccountInfo(protocolConfigPda))) {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+      );
+      await program.methods
+        .initializeProtocolConfig(
+          owner.publicKey,
+          5,
+          new anchor.BN(0),
+          new anchor.BN(30 * 24 * 60 * 60),
+          0
+        )
+        .accounts({
+          authority: owner.publicKey,
+          protocolConfig: protocolConfigPda,
+          treasury: treasuryPda,
+          program: program.programId,
+          programData,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
   });
 
   it("Initialize config", async () => {
@@ -80,16 +145,23 @@ describe("scream", () => {
 
     const tx = await program.methods
       .initializeConfig(
+        0,
+        "",
         Array.from(triggerHash),
         contacts,
         recoveryThreshold,
         timeLockDuration,
-        decoyLamports
+        decoyLamports,
+        "",
+        ""
       )
       .accounts({
         owner: owner.publicKey,
         panicConfig: panicConfigPda,
         vault: vaultPda,
+        feeReserve: feeReservePda,
+        protocolConfig: protocolConfigPda,
+        protocolStats: protocolStatsPda,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -125,6 +197,8 @@ describe("scream", () => {
         owner: owner.publicKey,
         panicConfig: panicConfigPda,
         vault: vaultPda,
+        protocolConfig: protocolConfigPda,
+        depositLedger: null,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -135,6 +209,84 @@ describe("scream", () => {
     expect(vaultBalanceAfter - vaultBalanceBefore).to.equal(5 * LAMPORTS_PER_SOL);
   });
 
+  it("Drips the decoy in installments", async () => {
+    // A day apart, so the second installment is still pending at recovery
+    await program.methods
+      .setDecoyDrip(2, new anchor.BN(24 * 60 * 60))
+      .accounts({
+        owner: owner.publicKey,
+        panicConfig: panicConfigPda,
+      })
+      .rpc();
+
+    const config = await program.account.panicConfig.fetch(panicConfigPda);
+    expect(config.decoyDripInstallments).to.equal(2);
+  });
+
+  it("Records a wrong duress PIN as a failed attempt", async () => {
+    await program.methods
+      .triggerPanic(Buffer.from("000000"), false, [], Array(32).fill(0), { unknown: {} }, null)
+      .accounts({
+        authority: owner.publicKey,
+        owner: owner.publicKey,
+        payer: owner.publicKey,
+        panicConfig: panicConfigPda,
+        vault: vaultPda,
+        compromisedFlag: compromisedFlagPda,
+        attacker: attacker.publicKey,
+        attackerFlag: attackerFlagPda,
+        feeReserve: feeReservePda,
+        protocolStats: protocolStatsPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        decoyTokenMint: null,
+        vaultDecoyToken: null,
+        attackerDecoyToken: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+        memoProgram: null,
+        panicHooks: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: alertPda1, isWritable: true, isSigner: false },
+        { pubkey: alertPda2, isWritable: true, isSigner: false },
+        { pubkey: alertPda3, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    const config = await program.account.panicConfig.fetch(panicConfigPda);
+    expect(config.isTriggered).to.equal(false);
+    expect(config.failedAttempts).to.equal(1);
+
+    const compromised = await provider.connection.getAccountInfo(compromisedFlagPda);
+    expect(compromised).to.equal(null);
+  });
+
+  it("Attacker pre-funds the flag PDAs", async () => {
+    // create_account refuses an address that already holds lamports; the
+    // panic below must still flag both the wallet and the attacker
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(0);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: owner.publicKey,
+          toPubkey: attackerFlagPda,
+          lamports,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: owner.publicKey,
+          toPubkey: compromisedFlagPda,
+          lamports,
+        })
+      )
+    );
+    for (const pda of [attackerFlagPda, compromisedFlagPda]) {
+      const info = await provider.connection.getAccountInfo(pda);
+      expect(info.owner.toBase58()).to.equal(SystemProgram.programId.toBase58());
+    }
+  });
+
   it("Trigger panic with correct duress PIN", async () => {
     const vaultBalanceBefore = await provider.connection.getBalance(vaultPda);
     const attackerBalanceBefore = await provider.connection.getBalance(attacker.publicKey);
@@ -142,15 +294,37 @@ describe("scream", () => {
     console.log("  Vault balance before panic:", vaultBalanceBefore / LAMPORTS_PER_SOL, "SOL");
     console.log("  Attacker balance before:", attackerBalanceBefore / LAMPORTS_PER_SOL, "SOL");
 
+    const evidenceHash = crypto.createHash("sha256").update("drain-tx-signature").digest();
+
     const tx = await program.methods
-      .triggerPanic(Buffer.from(duressPin))
+      .triggerPanic(
+        Buffer.from(duressPin),
+        false,
+        [],
+        Array.from(evidenceHash),
+        { physicalCoercion: {} },
+        8
+      )
       .accounts({
+        authority: owner.publicKey,
         owner: owner.publicKey,
+        payer: owner.publicKey,
         panicConfig: panicConfigPda,
         vault: vaultPda,
         compromisedFlag: compromisedFlagPda,
         attacker: attacker.publicKey,
         attackerFlag: attackerFlagPda,
+        feeReserve: feeReservePda,
+        protocolStats: protocolStatsPda,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        decoyTokenMint: null,
+        vaultDecoyToken: null,
+        attackerDecoyToken: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+        memoProgram: null,
+        panicHooks: null,
         systemProgram: SystemProgram.programId,
       })
       .remainingAccounts([
@@ -174,17 +348,24 @@ describe("scream", () => {
     const compromised = await program.account.compromisedFlag.fetch(compromisedFlagPda);
     expect(compromised.owner.toBase58()).to.equal(owner.publicKey.toBase58());
     expect(compromised.flaggedAt.toNumber()).to.be.greaterThan(0);
+    expect(compromised.reason).to.deep.equal({ physicalCoercion: {} });
+    expect(compromised.severity).to.equal(8);
 
     // Verify attacker flag
     const attackerFlag = await program.account.attackerFlag.fetch(attackerFlagPda);
     expect(attackerFlag.attacker.toBase58()).to.equal(attacker.publicKey.toBase58());
     expect(attackerFlag.reportedBy.toBase58()).to.equal(owner.publicKey.toBase58());
+    expect(Buffer.from(attackerFlag.evidenceHash).equals(evidenceHash)).to.equal(true);
 
     // Verify decoy was sent to attacker
     const attackerBalanceAfter = await provider.connection.getBalance(attacker.publicKey);
     console.log("  Attacker balance after:", attackerBalanceAfter / LAMPORTS_PER_SOL, "SOL");
     expect(attackerBalanceAfter).to.be.greaterThan(attackerBalanceBefore);
 
+    // ...with the second installment still owed
+    expect(vault.dripInstallmentsLeft).to.equal(1);
+    expect(vault.dripRemaining.toNumber()).to.be.greaterThan(0);
+
     // Verify alert accounts were created
     const alert1 = await program.account.alertAccount.fetch(alertPda1);
     expect(alert1.owner.toBase58()).to.equal(owner.publicKey.toBase58());
@@ -204,14 +385,27 @@ describe("scream", () => {
   it("Fails to trigger panic again", async () => {
     try {
       await program.methods
-        .triggerPanic(Buffer.from(duressPin))
+        .triggerPanic(Buffer.from(duressPin), false, [], Array(32).fill(0), { unknown: {} }, null)
         .accounts({
+          authority: owner.publicKey,
           owner: owner.publicKey,
+          payer: owner.publicKey,
           panicConfig: panicConfigPda,
           vault: vaultPda,
           compromisedFlag: compromisedFlagPda,
           attacker: attacker.publicKey,
           attackerFlag: attackerFlagPda,
+          feeReserve: feeReservePda,
+          protocolStats: protocolStatsPda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          decoyTokenMint: null,
+          vaultDecoyToken: null,
+          attackerDecoyToken: null,
+          tokenProgram: null,
+          associatedTokenProgram: null,
+          memoProgram: null,
+          panicHooks: null,
           systemProgram: SystemProgram.programId,
         })
         .remainingAccounts([
@@ -233,6 +427,7 @@ describe("scream", () => {
       await program.methods
         .initiateRecovery()
         .accounts({
+          authority: owner.publicKey,
           owner: owner.publicKey,
           panicConfig: panicConfigPda,
           vault: vaultPda,
@@ -252,6 +447,7 @@ describe("scream", () => {
     const tx = await program.methods
       .initiateRecovery()
       .accounts({
+        authority: owner.publicKey,
         owner: owner.publicKey,
         panicConfig: panicConfigPda,
         vault: vaultPda,
@@ -263,6 +459,11 @@ describe("scream", () => {
     const vault = await program.account.vault.fetch(vaultPda);
     expect(vault.recoveryInitiated).to.equal(true);
     expect(vault.approvals).to.equal(0);
+
+    // The pending decoy installment is cancelled rather than left for the
+    // crank to take out of the recovered funds
+    expect(vault.dripRemaining.toNumber()).to.equal(0);
+    expect(vault.dripInstallmentsLeft).to.equal(0);
   });
 
   it("Contact 1 approves recovery", async () => {
@@ -290,11 +491,16 @@ describe("scream", () => {
   it("Fails to claim with insufficient approvals", async () => {
     try {
       await program.methods
-        .claimFromVault()
+        .claimFromVault(false, null)
         .accounts({
+          authority: owner.publicKey,
           owner: owner.publicKey,
           panicConfig: panicConfigPda,
           vault: vaultPda,
+          protocolConfig: protocolConfigPda,
+          treasury: treasuryPda,
+          protocolStats: protocolStatsPda,
+          depositLedger: null,
         })
         .rpc();
       expect.fail("Should have failed");
@@ -348,11 +554,16 @@ describe("scream", () => {
     console.log("  Vault balance before claim:", vaultBalanceBefore / LAMPORTS_PER_SOL, "SOL");
 
     const tx = await program.methods
-      .claimFromVault()
+      .claimFromVault(false, null)
       .accounts({
+        authority: owner.publicKey,
         owner: owner.publicKey,
         panicConfig: panicConfigPda,
         vault: vaultPda,
+        protocolConfig: protocolConfigPda,
+        treasury: treasuryPda,
+        protocolStats: protocolStatsPda,
+        depositLedger: null,
       })
       .rpc();
 